@@ -1,14 +1,285 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+//! Derive macros for the [inquire](https://docs.rs/inquire) crate, re-exported by
+//! it when its `derive` feature is enabled.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Derives `inquire::SelectableVariants` for enums with unit variants, listing
+/// them in declaration order for `Select::new_from_enum`.
+///
+/// Variants are displayed with their `Display` implementation, which the enum
+/// must provide, and can be customized with the `inquire` attribute:
+///
+/// - `#[inquire(skip)]` leaves the variant out of the list.
+/// - `#[inquire(label = "...")]` displays the label instead of the variant.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::{Display, Formatter};
+///
+/// use inquire::SelectableVariants;
+///
+/// #[derive(Debug, PartialEq, inquire_derive::SelectableVariants)]
+/// enum Conflict {
+///     Overwrite,
+///     Skip,
+///     #[inquire(label = "Abort the copy")]
+///     Abort,
+///     #[inquire(skip)]
+///     Unknown,
+/// }
+///
+/// impl Display for Conflict {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{self:?}")
+///     }
+/// }
+///
+/// assert_eq!(
+///     vec![Conflict::Overwrite, Conflict::Skip, Conflict::Abort],
+///     Conflict::variants()
+/// );
+/// assert_eq!("Abort the copy", Conflict::Abort.label());
+/// ```
+///
+/// Variants holding data are not supported:
+///
+/// ```compile_fail
+/// #[derive(inquire_derive::SelectableVariants)]
+/// enum Answer {
+///     Yes,
+///     Other(String),
+/// }
+/// ```
+#[proc_macro_derive(SelectableVariants, attributes(inquire))]
+pub fn derive_selectable_variants(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(output) => output,
+        Err((span, message)) => compile_error(span, &message),
+    }
+}
+
+type Error = (Span, String);
+
+struct Variant {
+    name: Ident,
+    skip: bool,
+    label: Option<Literal>,
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, Error> {
+    let mut tokens = input.into_iter().peekable();
+
+    // outer attributes and visibility come before the item keyword.
+    let keyword = loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "enum" => break ident,
+            Some(TokenTree::Ident(ident))
+                if ident.to_string() == "struct" || ident.to_string() == "union" =>
+            {
+                return Err((
+                    ident.span(),
+                    "SelectableVariants can only be derived for enums".into(),
+                ));
+            }
+            Some(_) => {}
+            None => return Err((Span::call_site(), "expected an enum".into())),
+        }
+    };
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name,
+        _ => return Err((keyword.span(), "expected the name of the enum".into())),
+    };
+
+    let body = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group,
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            return Err((
+                punct.span(),
+                "SelectableVariants can not be derived for generic enums".into(),
+            ));
+        }
+        _ => return Err((name.span(), "expected the variants of the enum".into())),
+    };
+
+    let variants = parse_variants(body.stream())?;
+
+    Ok(implementation(&name, &variants)
+        .parse()
+        .expect("generated implementation is valid Rust"))
+}
+
+fn parse_variants(body: TokenStream) -> Result<Vec<Variant>, Error> {
+    let mut variants = vec![];
+    let mut tokens = body.into_iter().peekable();
+
+    while tokens.peek().is_some() {
+        let mut skip = false;
+        let mut label = None;
+
+        let name = loop {
+            match tokens.next() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '#' => {
+                    if let Some(TokenTree::Group(attribute)) = tokens.next() {
+                        parse_attribute(attribute.stream(), &mut skip, &mut label)?;
+                    }
+                }
+                Some(TokenTree::Ident(name)) => break name,
+                Some(other) => return Err((other.span(), "expected a variant".into())),
+                None => return Ok(variants),
+            }
+        };
+
+        match tokens.next() {
+            None => {}
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+            Some(TokenTree::Group(group))
+                if matches!(group.delimiter(), Delimiter::Parenthesis | Delimiter::Brace) =>
+            {
+                return Err((
+                    name.span(),
+                    "SelectableVariants can only be derived for enums with unit variants".into(),
+                ));
+            }
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {
+                // the discriminant is skipped up to the comma, its own commas
+                // being nested in groups.
+                loop {
+                    match tokens.next() {
+                        None => break,
+                        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => break,
+                        Some(_) => {}
+                    }
+                }
+            }
+            Some(other) => return Err((other.span(), "expected `,` after the variant".into())),
+        }
+
+        variants.push(Variant { name, skip, label });
+    }
+
+    Ok(variants)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Parses the contents of an attribute of a variant, ignoring attributes of
+/// other macros.
+fn parse_attribute(
+    attribute: TokenStream,
+    skip: &mut bool,
+    label: &mut Option<Literal>,
+) -> Result<(), Error> {
+    let mut tokens = attribute.into_iter();
+
+    match tokens.next() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "inquire" => {}
+        _ => return Ok(()),
+    }
+
+    let arguments = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+        other => {
+            let span = other.map(|t| t.span()).unwrap_or_else(Span::call_site);
+            return Err((
+                span,
+                "expected `inquire(skip)` or `inquire(label = \"...\")`".into(),
+            ));
+        }
+    };
+
+    let mut tokens = arguments.stream().into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident.to_string() == "skip" => *skip = true,
+            TokenTree::Ident(ident) if ident.to_string() == "label" => {
+                match tokens.next() {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => {}
+                    _ => return Err((ident.span(), "expected `label = \"...\"`".into())),
+                }
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+                match tokens.next() {
+                    Some(TokenTree::Literal(literal)) if is_string_literal(&literal) => {
+                        *label = Some(literal);
+                    }
+                    _ => return Err((ident.span(), "expected a string literal as label".into())),
+                }
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {}
+            other => {
+                return Err((
+                    other.span(),
+                    format!("unknown inquire attribute `{other}`, expected `skip` or `label`"),
+                ));
+            }
+        }
     }
+
+    Ok(())
+}
+
+fn is_string_literal(literal: &Literal) -> bool {
+    let literal = literal.to_string();
+    literal.starts_with('"') || literal.starts_with("r\"") || literal.starts_with("r#")
+}
+
+fn implementation(name: &Ident, variants: &[Variant]) -> String {
+    let listed = variants
+        .iter()
+        .filter(|variant| !variant.skip)
+        .map(|variant| format!("Self::{}", variant.name))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let labeled = variants
+        .iter()
+        .filter_map(|variant| variant.label.as_ref().map(|label| (&variant.name, label)))
+        .map(|(variant, label)| format!("Self::{variant} => ::std::string::String::from({label}),"))
+        .collect::<Vec<String>>();
+
+    // a wildcard arm would be unreachable when every variant has a label.
+    let label = if labeled.is_empty() {
+        "::std::string::ToString::to_string(self)".to_owned()
+    } else if labeled.len() == variants.len() {
+        format!("match self {{ {} }}", labeled.join(" "))
+    } else {
+        format!(
+            "match self {{ {} _ => ::std::string::ToString::to_string(self), }}",
+            labeled.join(" ")
+        )
+    };
+
+    format!(
+        "impl ::inquire::SelectableVariants for {name} {{
+            fn variants() -> ::std::vec::Vec<Self> {{
+                ::std::vec![{listed}]
+            }}
+
+            fn label(&self) -> ::std::string::String {{
+                {label}
+            }}
+        }}"
+    )
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+
+    let mut message = Literal::string(message);
+    message.set_span(span);
+
+    let mut arguments = Group::new(Delimiter::Parenthesis, TokenTree::from(message).into());
+    arguments.set_span(span);
+
+    let mut semicolon = Punct::new(';', Spacing::Alone);
+    semicolon.set_span(span);
+
+    [
+        TokenTree::from(Ident::new("compile_error", span)),
+        TokenTree::from(bang),
+        TokenTree::from(arguments),
+        TokenTree::from(semicolon),
+    ]
+    .into_iter()
+    .collect()
 }
@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+use inquire::SelectableVariants;
+
+/// Kinds of Kubernetes objects.
+#[derive(Debug, PartialEq, inquire_derive::SelectableVariants)]
+pub enum Kind {
+    /// Deployments.
+    Deployment = 1,
+    #[inquire(label = "Config map")]
+    ConfigMap = 2,
+    #[allow(dead_code)]
+    #[inquire(skip)]
+    Unknown = 10,
+    #[inquire(label = "Secret \"opaque\"")]
+    Secret = 3,
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Debug, PartialEq, inquire_derive::SelectableVariants)]
+enum Labeled {
+    #[inquire(label = "First")]
+    A,
+    #[inquire(label = "Second")]
+    B,
+}
+
+impl Display for Labeled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Debug, PartialEq, inquire_derive::SelectableVariants)]
+enum Flag {
+    Read = (1 << 2),
+    Write = { 1 << 1 },
+    Execute = 1,
+}
+
+impl Display for Flag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[test]
+fn variants_are_listed_in_declaration_order() {
+    assert_eq!(
+        vec![Kind::Deployment, Kind::ConfigMap, Kind::Secret],
+        Kind::variants()
+    );
+}
+
+#[test]
+fn parenthesized_discriminants_are_not_payloads() {
+    assert_eq!(
+        vec![Flag::Read, Flag::Write, Flag::Execute],
+        Flag::variants()
+    );
+}
+
+#[test]
+fn variants_without_label_are_displayed() {
+    assert_eq!("Deployment", Kind::Deployment.label());
+    assert_eq!("Unknown", Kind::Unknown.label());
+}
+
+#[test]
+fn labels_replace_display() {
+    assert_eq!("Config map", Kind::ConfigMap.label());
+    assert_eq!("Secret \"opaque\"", Kind::Secret.label());
+    assert_eq!("First", Labeled::A.label());
+    assert_eq!("Second", Labeled::B.label());
+}
@@ -33,8 +33,7 @@ fn main() -> InquireResult<()> {
         .prompt()?;
 
     let mut accounts = get_accounts();
-    let accounts_mut = accounts.iter_mut().collect();
-    let account = Select::new("Account:", accounts_mut).prompt()?;
+    let account = Select::new("Account:", accounts.iter_mut()).prompt()?;
     account.balance -= amount;
 
     let _tags = MultiSelect::new("Tags:", get_tags()).prompt()?;
@@ -0,0 +1,24 @@
+use std::time::Instant;
+
+use inquire::Select;
+
+/// Select over half a million options, in order to check how responsive
+/// filtering is on large lists. Try it with `cargo run --release`.
+fn main() {
+    let start = Instant::now();
+    let options: Vec<String> = (0..500_000).map(|n| format!("option-{n}")).collect();
+    println!(
+        "Generated {} options in {:?}",
+        options.len(),
+        start.elapsed()
+    );
+
+    let ans = Select::new("Option:", options)
+        .with_help_message("type digits to narrow down the options")
+        .prompt();
+
+    match ans {
+        Ok(choice) => println!("You picked {choice}"),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
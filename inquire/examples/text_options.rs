@@ -1,4 +1,7 @@
-use inquire::{error::CustomUserError, length, required, ui::RenderConfig, Text};
+use inquire::{
+    autocompletion::SuggestionFilter, error::CustomUserError, length, required, ui::RenderConfig,
+    Text,
+};
 
 fn main() {
     let answer = Text::new("What's your name?")
@@ -16,10 +19,28 @@ fn main() {
         default: None,
         placeholder: Some("Good"),
         help_message: None,
+        help_message_on_empty_only: false,
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
+        multiline: false,
+        stdin_fallback: false,
+        strip_pasted_newlines: false,
+        char_filter: None,
+        input_transformer: None,
+        mask: None,
+        partial_mask: false,
+        max_length: None,
+        length_counter: false,
+        trim: false,
+        numeric: false,
+        allow_negative: false,
+        allow_decimal: false,
         autocompleter: None,
+        suggestions: None,
+        suggestion_filter: SuggestionFilter::default(),
+        inline_suggestion: false,
+        history: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
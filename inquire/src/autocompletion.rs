@@ -11,6 +11,8 @@
 //! Check the example files to see some usages, recommended are `expense_tracker.rs`
 //! and `complex_autocompletion.rs`.
 
+use std::ops::Range;
+
 use dyn_clone::DynClone;
 
 use crate::CustomUserError;
@@ -82,6 +84,135 @@ impl Autocomplete for NoAutoCompletion {
     }
 }
 
+/// Strategy used to match suggestions against the current text input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SuggestionFilter {
+    /// Suggestions starting with the input, with the same case.
+    Prefix,
+    /// Suggestions starting with the input, ignoring case.
+    #[default]
+    PrefixCaseInsensitive,
+    /// Suggestions containing the input anywhere, with the same case.
+    Contains,
+    /// Suggestions containing the input anywhere, ignoring case.
+    ContainsCaseInsensitive,
+}
+
+impl SuggestionFilter {
+    /// Returns the byte range of `suggestion` matched by `input`, or `None` if
+    /// the suggestion does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::autocompletion::SuggestionFilter;
+    ///
+    /// assert_eq!(Some(0..2), SuggestionFilter::PrefixCaseInsensitive.matched_range("Apple", "ap"));
+    /// assert_eq!(None, SuggestionFilter::Prefix.matched_range("Apple", "ap"));
+    /// assert_eq!(Some(3..5), SuggestionFilter::Contains.matched_range("Pineapple", "ea"));
+    /// ```
+    pub fn matched_range(&self, suggestion: &str, input: &str) -> Option<Range<usize>> {
+        let ignore_case = matches!(
+            self,
+            Self::PrefixCaseInsensitive | Self::ContainsCaseInsensitive
+        );
+
+        let match_at = |start: usize| -> Option<Range<usize>> {
+            let mut candidate = suggestion[start..].char_indices();
+            for c in input.chars() {
+                let (_, s) = candidate.next()?;
+                let same = c == s || (ignore_case && c.to_lowercase().eq(s.to_lowercase()));
+                if !same {
+                    return None;
+                }
+            }
+
+            let end = candidate
+                .next()
+                .map(|(idx, _)| start + idx)
+                .unwrap_or(suggestion.len());
+
+            Some(start..end)
+        };
+
+        match self {
+            Self::Prefix | Self::PrefixCaseInsensitive => match_at(0),
+            Self::Contains | Self::ContainsCaseInsensitive => suggestion
+                .char_indices()
+                .map(|(idx, _)| idx)
+                .chain(std::iter::once(suggestion.len()))
+                .find_map(match_at),
+        }
+    }
+}
+
+/// Autocompleter backed by a static list of suggestions, used by
+/// [`Text::with_suggestions`](crate::Text::with_suggestions).
+///
+/// Suggestions are filtered by whether they match the current text input according
+/// to a [SuggestionFilter], by default whether they start with it, ignoring case.
+/// When the autocompletion hotkey is pressed, the input is completed to the
+/// first matching suggestion, and pressing it again cycles through the
+/// remaining matches.
+#[derive(Clone, Debug, Default)]
+pub struct StaticAutoCompletion {
+    suggestions: Vec<String>,
+    matches: Vec<String>,
+    filter: SuggestionFilter,
+}
+
+impl StaticAutoCompletion {
+    /// Creates a [StaticAutoCompletion] with the provided list of suggestions.
+    pub fn new<S>(suggestions: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self {
+            suggestions: suggestions.iter().map(|s| s.as_ref().to_owned()).collect(),
+            matches: vec![],
+            filter: SuggestionFilter::default(),
+        }
+    }
+
+    /// Sets the strategy used to match suggestions against the input.
+    pub fn with_filter(mut self, filter: SuggestionFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+impl Autocomplete for StaticAutoCompletion {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        self.matches = self
+            .suggestions
+            .iter()
+            .filter(|s| self.filter.matched_range(s, input).is_some())
+            .cloned()
+            .collect();
+
+        Ok(self.matches.clone())
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        if let Some(suggestion) = highlighted_suggestion {
+            if suggestion != input {
+                return Ok(Replacement::Some(suggestion));
+            }
+        }
+
+        let next = match self.matches.iter().position(|s| s == input) {
+            Some(idx) => self.matches.get((idx + 1) % self.matches.len()),
+            None => self.matches.first(),
+        };
+
+        Ok(next.cloned())
+    }
+}
+
 impl<F> Autocomplete for F
 where
     F: Fn(&str) -> Result<Vec<String>, CustomUserError> + Clone,
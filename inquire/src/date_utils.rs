@@ -7,7 +7,7 @@ pub fn get_current_date() -> NaiveDate {
 }
 
 pub fn get_start_date(month: chrono::Month, year: i32) -> NaiveDate {
-    chrono::NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap()
+    NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap()
 }
 
 pub fn get_month(month: u32) -> chrono::Month {
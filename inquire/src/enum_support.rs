@@ -1,20 +1,53 @@
-use std::str::FromStr;
+/// Enums whose variants can be listed as the options of a [`Select`](crate::Select)
+/// prompt, created with [`Select::new_from_enum`](crate::Select::new_from_enum).
+///
+/// With the `derive` feature, it can be derived for enums with unit variants only.
+/// Variants are listed in declaration order, displayed with their `Display`
+/// implementation, and can be customized with the `inquire` attribute:
+///
+/// - `#[inquire(skip)]` leaves the variant out of the list.
+/// - `#[inquire(label = "...")]` displays the label instead of the variant.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::{Display, Formatter};
+///
+/// use inquire::SelectableVariants;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Conflict {
+///     Overwrite,
+///     Skip,
+///     Abort,
+/// }
+///
+/// impl Display for Conflict {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{self:?}")
+///     }
+/// }
+///
+/// impl SelectableVariants for Conflict {
+///     fn variants() -> Vec<Self> {
+///         vec![Self::Overwrite, Self::Skip, Self::Abort]
+///     }
+///
+///     fn label(&self) -> String {
+///         match self {
+///             Self::Abort => String::from("Abort the copy"),
+///             _ => self.to_string(),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(3, Conflict::variants().len());
+/// assert_eq!("Abort the copy", Conflict::Abort.label());
+/// ```
+pub trait SelectableVariants: Sized {
+    /// Returns the variants listed as options, in the order they are displayed.
+    fn variants() -> Vec<Self>;
 
-use crate::{
-    error::InquireResult,
-    list_option::{FromListOption, ListOption},
-};
-
-pub trait InquireEnumVariants {
-    fn get_variants() -> &'static [&'static str];
-}
-
-#[cfg(feature = "strum")]
-impl<T> InquireEnumVariants for T
-where
-    T: strum::VariantNames,
-{
-    fn get_variants() -> &'static [&'static str] {
-        T::VARIANTS
-    }
+    /// Returns the string displayed for the variant, in the list and in the answer.
+    fn label(&self) -> String;
 }
@@ -14,6 +14,14 @@ pub enum InquireError {
     /// on the terminal in order to listen to input events is not possible.
     NotTTY,
 
+    /// The terminal can't be put in raw mode, and the fallback of reading a line
+    /// with echo disabled is not possible either, e.g. because the standard input
+    /// is not a terminal.
+    ///
+    /// This error is returned only by [`Password`](crate::Password) prompts, which
+    /// otherwise fall back to reading the answer without echoing it.
+    NotInteractive,
+
     /// The given prompt configuration is not valid. A detailed error message
     /// is contained in the value string.
     InvalidConfiguration(String),
@@ -73,6 +81,9 @@ impl fmt::Display for InquireError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             InquireError::NotTTY => f.write_str("The input device is not a TTY"),
+            InquireError::NotInteractive => {
+                f.write_str("The input device does not support reading secrets")
+            }
             InquireError::InvalidConfiguration(s) => {
                 write!(f, "The prompt configuration is invalid: {}", s)
             }
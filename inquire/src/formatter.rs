@@ -46,6 +46,8 @@
 //! ? What's your name? My name is Mikael
 //! ```
 
+use std::fmt::Display;
+
 use crate::list_option::ListOption;
 
 /// Type alias for formatters that receive a string slice as the input,
@@ -101,6 +103,27 @@ pub type BoolFormatter<'a> = &'a dyn Fn(bool) -> String;
 /// ```
 pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 
+/// Type alias for formatters used in [Select](crate::Select) prompts that also
+/// receive the text of the filter input when the answer was submitted.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::FilteredOptionFormatter;
+///
+/// let formatter: FilteredOptionFormatter<str> = &|option, filter| match filter {
+///     "" => option.value.to_string(),
+///     filter => format!("{} (searched for {})", option.value, filter),
+/// };
+/// assert_eq!(String::from("web-1"), formatter(ListOption::new(0, "web-1"), ""));
+/// assert_eq!(
+///     String::from("web-1 (searched for we)"),
+///     formatter(ListOption::new(0, "web-1"), "we")
+/// );
+/// ```
+pub type FilteredOptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>, &str) -> String;
+
 /// Type alias for formatters used in [`MultiSelect`](crate::MultiSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -129,6 +152,23 @@ pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 /// ```
 pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 
+/// Type alias for formatters used in [`MultiSelect`](crate::MultiSelect) prompts that
+/// also receive the text of the filter input when the answer was submitted.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::FilteredMultiOptionFormatter;
+///
+/// let formatter: FilteredMultiOptionFormatter<str> =
+///     &|opts, filter| format!("{} options matching '{}'", opts.len(), filter);
+///
+/// let ans = vec![ListOption::new(0, "web-1"), ListOption::new(1, "web-2")];
+/// assert_eq!(String::from("2 options matching 'web'"), formatter(&ans, "web"));
+/// ```
+pub type FilteredMultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>], &str) -> String;
+
 /// Type alias for formatters used in [`CustomType`](crate::CustomType) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -149,7 +189,6 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
 #[cfg(feature = "date")]
-
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -226,3 +265,101 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
 /// );
 /// ```
 pub const DEFAULT_DATE_FORMATTER: DateFormatter<'_> = &|val| val.format("%B %-e, %Y").to_string();
+
+/// Formatter for [`MultiSelect`](crate::MultiSelect) prompts that summarizes
+/// the answer as the number of selected options, or `None` when nothing is selected.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::selected_count;
+/// use inquire::list_option::ListOption;
+///
+/// assert_eq!(String::from("None"), selected_count::<str>(&[]));
+/// assert_eq!(
+///     String::from("1 option selected"),
+///     selected_count(&[ListOption::new(0, "nginx")])
+/// );
+/// assert_eq!(
+///     String::from("2 options selected"),
+///     selected_count(&[ListOption::new(0, "nginx"), ListOption::new(4, "redis")])
+/// );
+/// ```
+///
+/// It can be set with [`MultiSelect::with_formatter`](crate::MultiSelect::with_formatter):
+///
+/// ```no_run
+/// use inquire::{formatter::selected_count, MultiSelect};
+///
+/// let ans = MultiSelect::new("Packages:", vec!["nginx", "redis", "postgres"])
+///     .with_formatter(&selected_count)
+///     .prompt();
+/// ```
+pub fn selected_count<T: ?Sized>(options: &[ListOption<&T>]) -> String {
+    match options.len() {
+        0 => String::from("None"),
+        1 => String::from("1 option selected"),
+        len => format!("{len} options selected"),
+    }
+}
+
+/// Creates a formatter for [`MultiSelect`](crate::MultiSelect) prompts that
+/// prints the string value of the first `shown` selected options, separated by
+/// commas, followed by the number of the remaining ones. Prints `None` when
+/// nothing is selected.
+///
+/// At least one option is shown.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::first_selected_and_count;
+/// use inquire::list_option::ListOption;
+///
+/// let formatter = first_selected_and_count::<str>(2);
+///
+/// let mut ans = vec![ListOption::new(0, "nginx")];
+/// assert_eq!(String::from("nginx"), formatter(&ans));
+///
+/// ans.push(ListOption::new(4, "redis"));
+/// assert_eq!(String::from("nginx, redis"), formatter(&ans));
+///
+/// ans.push(ListOption::new(5, "postgres"));
+/// ans.push(ListOption::new(7, "haproxy"));
+/// assert_eq!(String::from("nginx, redis and 2 more"), formatter(&ans));
+///
+/// assert_eq!(String::from("None"), formatter(&[]));
+/// ```
+///
+/// The formatter must outlive the prompt it is set to:
+///
+/// ```no_run
+/// use inquire::{formatter::first_selected_and_count, MultiSelect};
+///
+/// let formatter = first_selected_and_count(2);
+///
+/// let ans = MultiSelect::new("Packages:", vec!["nginx", "redis", "postgres"])
+///     .with_formatter(&formatter)
+///     .prompt();
+/// ```
+pub fn first_selected_and_count<T>(shown: usize) -> impl Fn(&[ListOption<&T>]) -> String
+where
+    T: Display + ?Sized,
+{
+    let shown = shown.max(1);
+
+    move |options| {
+        let first = options
+            .iter()
+            .take(shown)
+            .map(|opt| opt.value.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        match options.len() {
+            0 => String::from("None"),
+            len if len <= shown => first,
+            len => format!("{first} and {} more", len - shown),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! In-session history of answers, shared between consecutive text prompts.
+//!
+//! A [History] is owned by the caller and mutably borrowed by each prompt
+//! that should be able to recall previous answers. While editing, the user can
+//! press `up` and `down` to cycle through the stored entries, and submitting a
+//! non-empty answer appends it to the history.
+//!
+//! With the `history-file` feature enabled, a history can also be loaded from
+//! and saved to a file, one entry per line, so answers survive across runs.
+//! Backslashes and line breaks inside entries are escaped, so multi-line
+//! answers are kept as a single entry.
+
+#[cfg(feature = "history-file")]
+use std::{io, path::PathBuf};
+
+/// List of previous answers that can be recalled by [Text](crate::Text) prompts.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{History, Text};
+///
+/// let mut history = History::new();
+///
+/// let first = Text::new("Host:").with_history(&mut history).prompt();
+/// // on the second prompt, pressing `up` recalls the first answer
+/// let second = Text::new("Host:").with_history(&mut history).prompt();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    entries: Vec<String>,
+    max_entries: Option<usize>,
+    #[cfg(feature = "history-file")]
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// Creates an empty [History].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum amount of entries kept in the history, dropping the
+    /// oldest ones when the cap is exceeded.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self.truncate();
+        self
+    }
+
+    /// Appends an entry to the end of the history.
+    ///
+    /// Empty entries and entries equal to the latest one are ignored.
+    pub fn push<S>(&mut self, entry: S)
+    where
+        S: Into<String>,
+    {
+        self.record(&entry.into());
+    }
+
+    /// Returns the stored entries, from oldest to newest.
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Returns the number of stored entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the history has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Appends an entry, ignoring empty ones and consecutive duplicates.
+    pub(crate) fn record(&mut self, entry: &str) {
+        if entry.is_empty() || self.entries.last().map(String::as_str) == Some(entry) {
+            return;
+        }
+
+        self.entries.push(entry.to_owned());
+        self.truncate();
+    }
+
+    fn truncate(&mut self) {
+        if let Some(max) = self.max_entries {
+            let excess = self.entries.len().saturating_sub(max);
+            self.entries.drain(..excess);
+        }
+    }
+}
+
+#[cfg(feature = "history-file")]
+#[cfg_attr(docsrs, doc(cfg(feature = "history-file")))]
+impl History {
+    /// Loads the history from a file with one entry per line.
+    ///
+    /// The path is remembered so that [`save`](Self::save) writes back to it.
+    /// If the file does not exist, the history starts empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can not be read.
+    pub fn from_file<P>(path: P) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let mut history = Self::default();
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                for line in content.lines() {
+                    history.record(&unescape(line));
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        Ok(Self {
+            path: Some(path),
+            ..history
+        })
+    }
+
+    /// Saves the history to the file it was loaded from, one entry per line.
+    /// Backslashes and line breaks inside entries are escaped as `\\`, `\n`
+    /// and `\r`.
+    ///
+    /// Returns an error if the history was not created with [`from_file`](Self::from_file).
+    pub fn save(&self) -> io::Result<()> {
+        let path = self.path.as_ref().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "history has no associated file")
+        })?;
+
+        let mut content = String::new();
+        for entry in &self.entries {
+            content.push_str(&escape(entry));
+            content.push('\n');
+        }
+
+        std::fs::write(path, content)
+    }
+}
+
+#[cfg(feature = "history-file")]
+fn escape(entry: &str) -> String {
+    entry
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+#[cfg(feature = "history-file")]
+fn unescape(line: &str) -> String {
+    let mut entry = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            entry.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => entry.push('\n'),
+            Some('r') => entry.push('\r'),
+            Some('\\') | None => entry.push('\\'),
+            // unknown escapes are kept as they were written.
+            Some(other) => {
+                entry.push('\\');
+                entry.push(other);
+            }
+        }
+    }
+
+    entry
+}
+
+impl<S> From<Vec<S>> for History
+where
+    S: Into<String>,
+{
+    fn from(entries: Vec<S>) -> Self {
+        let mut history = Self::default();
+        for entry in entries {
+            history.record(&entry.into());
+        }
+        history
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::History;
+
+    #[test]
+    fn consecutive_duplicates_are_ignored() {
+        let mut history = History::new();
+
+        history.push("a");
+        history.push("a");
+        history.push("b");
+        history.push("");
+        history.push("a");
+
+        assert_eq!(vec!["a", "b", "a"], history.entries());
+    }
+
+    #[test]
+    fn max_entries_drops_oldest() {
+        let mut history = History::from(vec!["a", "b", "c"]).with_max_entries(2);
+        assert_eq!(vec!["b", "c"], history.entries());
+
+        history.push("d");
+        assert_eq!(vec!["c", "d"], history.entries());
+    }
+
+    #[cfg(feature = "history-file")]
+    #[test]
+    fn file_round_trip() {
+        let path = std::env::temp_dir().join(format!("inquire-history-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut history = History::from_file(&path).unwrap().with_max_entries(3);
+        assert!(history.is_empty());
+
+        for entry in ["one", "two", "two", "three", "four"] {
+            history.push(entry);
+        }
+        history.save().unwrap();
+
+        assert_eq!(
+            "two\nthree\nfour\n",
+            std::fs::read_to_string(&path).unwrap()
+        );
+
+        let history = History::from_file(&path).unwrap();
+        assert_eq!(vec!["two", "three", "four"], history.entries());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "history-file")]
+    #[test]
+    fn multi_line_entries_survive_a_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("inquire-history-multi-line-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut history = History::from_file(&path).unwrap();
+        history.push("first line\nsecond line");
+        history.push("C:\\new\\dir");
+        history.push("carriage\r\nreturn");
+        history.save().unwrap();
+
+        let loaded = History::from_file(&path).unwrap();
+        assert_eq!(history.entries(), loaded.entries());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "history-file")]
+    #[test]
+    fn unreadable_file_is_an_error() {
+        let path = std::env::temp_dir();
+        assert!(History::from_file(path).is_err());
+    }
+
+    #[cfg(feature = "history-file")]
+    #[test]
+    fn save_without_file_fails() {
+        assert!(History::new().save().is_err());
+    }
+}
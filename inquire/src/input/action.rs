@@ -16,6 +16,8 @@ pub enum InputAction {
     /// Writes a character to the content, according to the current cursor
     /// position.
     Write(char),
+    /// Clears the whole content and moves the cursor back to the start.
+    Clear,
 }
 
 impl InputAction {
@@ -43,23 +45,53 @@ impl InnerAction for InputAction {
                 return None;
             }
 
+            Key::Char('u', m) if m.contains(KeyModifiers::CONTROL) => Self::Clear,
+            Key::Char('w', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Word, LineDirection::Left)
+            }
+            Key::Char('k', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Line, LineDirection::Right)
+            }
+
             Key::Delete(m) if m.contains(KeyModifiers::CONTROL) => {
                 Self::Delete(Magnitude::Word, LineDirection::Right)
             }
             Key::Delete(_) => Self::Delete(Magnitude::Char, LineDirection::Right),
 
             Key::Home => Self::MoveCursor(Magnitude::Line, LineDirection::Left),
+            Key::Char('a', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::MoveCursor(Magnitude::Line, LineDirection::Left)
+            }
             Key::Left(m) if m.contains(KeyModifiers::CONTROL) => {
                 Self::MoveCursor(Magnitude::Word, LineDirection::Left)
             }
+            Key::Char('b', m) if m.contains(KeyModifiers::ALT) => {
+                Self::MoveCursor(Magnitude::Word, LineDirection::Left)
+            }
             Key::Left(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Left),
 
             Key::End => Self::MoveCursor(Magnitude::Line, LineDirection::Right),
+            Key::Char('e', m) if m.contains(KeyModifiers::CONTROL) => {
+                Self::MoveCursor(Magnitude::Line, LineDirection::Right)
+            }
             Key::Right(m) if m.contains(KeyModifiers::CONTROL) => {
                 Self::MoveCursor(Magnitude::Word, LineDirection::Right)
             }
+            Key::Char('f', m) if m.contains(KeyModifiers::ALT) => {
+                Self::MoveCursor(Magnitude::Word, LineDirection::Right)
+            }
             Key::Right(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
 
+            // pasted new lines and tabs should not break the single-line input
+            Key::Char('\n' | '\r' | '\t', _) => Self::Write(' '),
+            // unhandled control combinations should not leak into the input.
+            // AltGr is reported as ctrl+alt on Windows, so those are still written.
+            Key::Char(c, _) if c.is_control() => return None,
+            Key::Char(_, m)
+                if m.contains(KeyModifiers::CONTROL) && !m.contains(KeyModifiers::ALT) =>
+            {
+                return None
+            }
             Key::Char(c, _) => Self::Write(c),
             _ => return None,
         };
@@ -3,6 +3,7 @@ pub mod action;
 mod test;
 
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::InputAction;
 
@@ -23,12 +24,27 @@ fn is_alphanumeric(grapheme: &str) -> bool {
     grapheme.unicode_words().count() > 0
 }
 
+/// Portion of an input's content that fits in a given width, keeping the
+/// cursor visible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InputWindow<'a> {
+    /// Visible content.
+    pub content: &'a str,
+    /// Visible content to the left of the cursor.
+    pub pre_cursor: &'a str,
+    /// Whether there is hidden content to the left of the window.
+    pub hidden_left: bool,
+    /// Whether there is hidden content to the right of the window.
+    pub hidden_right: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct Input {
     content: String,
     placeholder: Option<String>,
     cursor: usize,
     length: usize,
+    max_length: Option<usize>,
 }
 
 impl Input {
@@ -38,6 +54,7 @@ impl Input {
             placeholder: None,
             cursor: 0,
             length: 0,
+            max_length: None,
         }
     }
 
@@ -54,6 +71,7 @@ impl Input {
             placeholder: None,
             length: len,
             cursor: len,
+            max_length: None,
         }
     }
 
@@ -62,6 +80,11 @@ impl Input {
         self
     }
 
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
     pub fn with_cursor(mut self, cursor: usize) -> Self {
         assert!(
             cursor <= self.length,
@@ -93,15 +116,58 @@ impl Input {
                 LineDirection::Right => self.forwards_delete(mag),
             },
             InputAction::Write(c) => self.insert(c),
+            InputAction::Clear => self.clear_content(),
         }
     }
 
+    /// Clears the content, overwriting it in memory first when the `zeroize`
+    /// feature is enabled.
     pub fn clear(&mut self) {
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut self.content);
+
         self.content.clear();
         self.cursor = 0;
         self.length = 0;
     }
 
+    /// Replaces the content, truncated to the maximum length, and moves the
+    /// cursor to its end. The placeholder and the maximum length are kept.
+    pub fn replace_content<S>(&mut self, content: S)
+    where
+        S: Into<String>,
+    {
+        let mut content: String = content.into();
+
+        if let Some(max_length) = self.max_length {
+            if let Some((end, _)) = content.grapheme_indices(true).nth(max_length) {
+                content.truncate(end);
+            }
+        }
+
+        self.clear();
+        self.length = content.graphemes(true).count();
+        self.cursor = self.length;
+        self.content = content;
+    }
+
+    /// Moves the content out of the input, leaving it empty.
+    pub fn take_content(&mut self) -> String {
+        self.cursor = 0;
+        self.length = 0;
+
+        std::mem::take(&mut self.content)
+    }
+
+    fn clear_content(&mut self) -> InputActionResult {
+        if self.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        self.clear();
+        InputActionResult::ContentChanged
+    }
+
     pub fn content(&self) -> &str {
         &self.content
     }
@@ -110,6 +176,10 @@ impl Input {
         self.length
     }
 
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
     pub fn cursor(&self) -> usize {
         self.cursor
     }
@@ -130,6 +200,66 @@ impl Input {
         }
     }
 
+    /// Returns the portion of the content that fits in `width` columns, sliding
+    /// around the cursor when the content is wider than that.
+    ///
+    /// When content is hidden, one column is reserved on each side for
+    /// indicators. Graphemes are never split, so double-width characters
+    /// either fit entirely or are hidden.
+    pub fn window(&self, width: usize) -> InputWindow<'_> {
+        if self.content.width() <= width {
+            return InputWindow {
+                content: &self.content,
+                pre_cursor: self.pre_cursor(),
+                hidden_left: false,
+                hidden_right: false,
+            };
+        }
+
+        let budget = width.saturating_sub(2);
+        let graphemes: Vec<(usize, &str)> = self.content.grapheme_indices(true).collect();
+        let byte_index = |idx: usize| {
+            graphemes
+                .get(idx)
+                .map(|(byte, _)| *byte)
+                .unwrap_or(self.content.len())
+        };
+
+        let mut start = self.cursor;
+        let mut end = self.cursor;
+        let mut used = 0;
+
+        // prioritize the content to the left of the cursor, as it is where the
+        // user is usually typing, then fill the remaining space to the right.
+        while start > 0 {
+            let w = graphemes[start - 1].1.width();
+            if used + w > budget {
+                break;
+            }
+            used += w;
+            start -= 1;
+        }
+
+        while end < graphemes.len() {
+            let w = graphemes[end].1.width();
+            if used + w > budget {
+                break;
+            }
+            used += w;
+            end += 1;
+        }
+
+        let (start_byte, cursor_byte, end_byte) =
+            (byte_index(start), byte_index(self.cursor), byte_index(end));
+
+        InputWindow {
+            content: &self.content[start_byte..end_byte],
+            pre_cursor: &self.content[start_byte..cursor_byte],
+            hidden_left: start > 0,
+            hidden_right: end < graphemes.len(),
+        }
+    }
+
     fn move_left(&mut self, mag: Magnitude) -> InputActionResult {
         if self.cursor == 0 {
             return InputActionResult::Clean;
@@ -203,6 +333,47 @@ impl Input {
     }
 
     fn insert(&mut self, c: char) -> InputActionResult {
+        match self.max_length {
+            Some(max_length) if self.length >= max_length => self.insert_bounded(c, max_length),
+            _ => self.insert_unbounded(c),
+        }
+    }
+
+    fn insert_bounded(&mut self, c: char, max_length: usize) -> InputActionResult {
+        let (cursor, length) = (self.cursor, self.length);
+        let at = if cursor >= length {
+            self.content.len()
+        } else {
+            self.byte_index(cursor)
+        };
+
+        self.insert_unbounded(c);
+
+        // the char might have been merged into an existing grapheme,
+        // e.g. a variation selector, which is fine to keep.
+        if self.length > max_length {
+            self.content.remove(at);
+            self.cursor = cursor;
+            self.length = length;
+
+            // removing the char shifts the bytes after it to the left, leaving a
+            // copy of the last ones in the spare capacity. Overwrite them in place.
+            #[cfg(feature = "zeroize")]
+            {
+                let len = self.content.len();
+                for _ in 0..c.len_utf8() {
+                    self.content.push('\0');
+                }
+                self.content.truncate(len);
+            }
+
+            return InputActionResult::Clean;
+        }
+
+        InputActionResult::ContentChanged
+    }
+
+    fn insert_unbounded(&mut self, c: char) -> InputActionResult {
         let at = self.cursor;
 
         if at >= self.length {
@@ -213,15 +384,8 @@ impl Input {
             return InputActionResult::ContentChanged;
         }
 
-        let mut result = String::new();
-        for (index, grapheme) in self.content[..].graphemes(true).enumerate() {
-            if index == at {
-                result.push(c);
-            }
-            result.push_str(grapheme);
-        }
-
-        self.content = result;
+        let at = self.byte_index(at);
+        self.content.insert(at, c);
         if self.update_length() {
             self.cursor = self.cursor.saturating_add(1);
         }
@@ -264,25 +428,28 @@ impl Input {
 
     fn delete_chars_at_right(&mut self, qty: usize) -> InputActionResult {
         let start = self.cursor;
-        let end = start.saturating_add(qty);
-
-        let mut new_content: String = String::new();
-        let mut length = 0;
-        let mut result = InputActionResult::Clean;
-
-        for (index, grapheme) in self.content[..].graphemes(true).enumerate() {
-            if index < start || index >= end {
-                length += 1;
-                new_content.push_str(grapheme);
-            } else {
-                result = InputActionResult::ContentChanged;
-            }
+        let end = start.saturating_add(qty).min(self.length);
+
+        if start >= end {
+            return InputActionResult::Clean;
         }
 
-        self.length = length;
-        self.content = new_content;
+        // removing the range in place keeps the content in a single buffer,
+        // instead of leaving copies of it behind in freed memory.
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.content.replace_range(range, "");
+        self.update_length();
+        self.cursor = self.cursor.min(self.length);
+
+        InputActionResult::ContentChanged
+    }
 
-        result
+    fn byte_index(&self, grapheme_index: usize) -> usize {
+        self.content
+            .grapheme_indices(true)
+            .nth(grapheme_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.content.len())
     }
 
     fn update_length(&mut self) -> bool {
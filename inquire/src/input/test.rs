@@ -96,3 +96,371 @@ fn regression_issue_5() {
         assert!(input.content.find(vs16).is_some());
     }
 }
+
+#[test]
+fn insert_in_the_middle() {
+    let mut input = Input::new_with("helo").with_cursor(3);
+
+    let result = input.handle(InputAction::Write('l'));
+
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("hello", input.content());
+    assert_eq!(5, input.length());
+    assert_eq!(4, input.cursor());
+    assert_eq!("hell", input.pre_cursor());
+}
+
+#[test]
+fn insert_between_multibyte_graphemes() {
+    let mut input = Input::new_with("🌍🚗").with_cursor(1);
+
+    input.handle(InputAction::Write('a'));
+
+    assert_eq!("🌍a🚗", input.content());
+    assert_eq!(3, input.length());
+    assert_eq!(2, input.cursor());
+    assert_eq!("🌍a", input.pre_cursor());
+}
+
+#[test]
+fn backspace_across_multibyte_graphemes() {
+    let content = "a🧘🏻‍♂️b♥️c";
+    let mut input = Input::new_with(content).with_cursor(4);
+    assert_eq!(5, input.length());
+
+    let result = input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("a🧘🏻‍♂️bc", input.content());
+    assert_eq!(3, input.cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    assert_eq!("abc", input.content());
+    assert_eq!(1, input.cursor());
+    assert_eq!(3, input.length());
+}
+
+#[test]
+fn cursor_movement_is_bounded() {
+    let mut input = Input::new_with("🍞🍞").with_cursor(0);
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!(0, input.cursor());
+
+    for _ in 0..2 {
+        let result = input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Right,
+        ));
+        assert_eq!(InputActionResult::PositionChanged, result);
+    }
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Right,
+    ));
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!(2, input.cursor());
+}
+
+#[test]
+fn forward_delete_removes_grapheme_under_cursor() {
+    let mut input = Input::new_with("a🧘🏻‍♂️b").with_cursor(1);
+
+    let result = input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Right));
+
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("ab", input.content());
+    assert_eq!(1, input.cursor());
+    assert_eq!(2, input.length());
+}
+
+#[test]
+fn forward_delete_at_end_is_noop() {
+    let mut input = Input::new_with("abc");
+
+    let result = input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Right));
+
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!("abc", input.content());
+    assert_eq!(3, input.cursor());
+}
+
+#[test]
+fn line_movement_on_empty_input_is_noop() {
+    let mut input = Input::new();
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Left,
+    ));
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!(0, input.cursor());
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Right,
+    ));
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn line_movement_jumps_to_edges() {
+    let mut input = Input::new_with("🌍 hello 🚗").with_cursor(3);
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Left,
+    ));
+    assert_eq!(InputActionResult::PositionChanged, result);
+    assert_eq!(0, input.cursor());
+
+    let result = input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Right,
+    ));
+    assert_eq!(InputActionResult::PositionChanged, result);
+    assert_eq!(9, input.cursor());
+}
+
+#[test]
+fn backwards_word_delete_skips_trailing_whitespace() {
+    let mut input = Input::new_with("hello brave  new   world   ");
+
+    let delete =
+        |input: &mut Input| input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+
+    assert_eq!(InputActionResult::ContentChanged, delete(&mut input));
+    assert_eq!("hello brave  new   ", input.content());
+    delete(&mut input);
+    assert_eq!("hello brave  ", input.content());
+    delete(&mut input);
+    assert_eq!("hello ", input.content());
+    delete(&mut input);
+    assert_eq!("", input.content());
+    assert_eq!(InputActionResult::Clean, delete(&mut input));
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn backwards_word_delete_keeps_suffix() {
+    let mut input = Input::new_with("foo, 🍞bar! baz").with_cursor(10);
+
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+
+    assert_eq!("foo, 🍞 baz", input.content());
+    assert_eq!(6, input.cursor());
+
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+
+    assert_eq!(" baz", input.content());
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn clear_resets_content_and_cursor() {
+    let mut input = Input::new_with("🌍 hello").with_cursor(2);
+
+    let result = input.handle(InputAction::Clear);
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("", input.content());
+    assert_eq!(0, input.cursor());
+    assert_eq!(0, input.length());
+
+    let result = input.handle(InputAction::Clear);
+    assert_eq!(InputActionResult::Clean, result);
+}
+
+#[test]
+fn move_next_word_with_multiple_spaces() {
+    let mut input = Input::new_with("one   two    three").with_cursor(0);
+
+    let mut next = || {
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Word,
+            LineDirection::Right,
+        ));
+        input.cursor()
+    };
+
+    assert_eq!(3, next());
+    assert_eq!(9, next());
+    assert_eq!(18, next());
+    assert_eq!(18, next());
+}
+
+#[test]
+fn move_words_in_mixed_script_content() {
+    let content = "hello, 世界 and Ωmega";
+    let mut input = Input::new_with(content);
+    assert_eq!(19, input.length());
+
+    let mut prev = || {
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Word,
+            LineDirection::Left,
+        ));
+        input.cursor()
+    };
+
+    assert_eq!(14, prev());
+    assert_eq!(10, prev());
+    assert_eq!(7, prev());
+    assert_eq!(0, prev());
+
+    let mut next = || {
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Word,
+            LineDirection::Right,
+        ));
+        input.cursor()
+    };
+
+    assert_eq!(5, next());
+    assert_eq!(9, next());
+    assert_eq!(13, next());
+    assert_eq!(19, next());
+}
+
+#[test]
+fn max_length_drops_extra_graphemes() {
+    let mut input = Input::new().with_max_length(Some(3));
+
+    for c in "ab🌍cd".chars() {
+        input.handle(InputAction::Write(c));
+    }
+
+    assert_eq!("ab🌍", input.content());
+    assert_eq!(3, input.length());
+    assert_eq!(3, input.cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    let result = input.handle(InputAction::Write('x'));
+
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!("ab🌍", input.content());
+    assert_eq!(2, input.cursor());
+}
+
+#[test]
+fn max_length_allows_combining_into_last_grapheme() {
+    let mut input = Input::new().with_max_length(Some(1));
+
+    input.handle(InputAction::Write('♥'));
+    let result = input.handle(InputAction::Write('\u{fe0f}'));
+
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("♥️", input.content());
+    assert_eq!(1, input.length());
+}
+
+#[test]
+fn replaced_content_is_truncated_to_max_length() {
+    let mut input = Input::new()
+        .with_max_length(Some(3))
+        .with_placeholder("name");
+
+    input.replace_content("ab🌍cd");
+
+    assert_eq!("ab🌍", input.content());
+    assert_eq!(3, input.length());
+    assert_eq!(3, input.cursor());
+    assert_eq!(Some("name"), input.placeholder());
+    assert_eq!(Some(3), input.max_length());
+}
+
+#[test]
+fn window_shows_everything_when_content_fits() {
+    let input = Input::new_with("hello").with_cursor(2);
+
+    let window = input.window(5);
+
+    assert_eq!("hello", window.content);
+    assert_eq!("he", window.pre_cursor);
+    assert!(!window.hidden_left);
+    assert!(!window.hidden_right);
+}
+
+#[test]
+fn window_follows_cursor_at_the_end() {
+    let input = Input::new_with("0123456789");
+
+    let window = input.window(6);
+
+    assert_eq!("6789", window.content);
+    assert_eq!("6789", window.pre_cursor);
+    assert!(window.hidden_left);
+    assert!(!window.hidden_right);
+}
+
+#[test]
+fn window_follows_cursor_at_the_start() {
+    let input = Input::new_with("0123456789").with_cursor(0);
+
+    let window = input.window(6);
+
+    assert_eq!("0123", window.content);
+    assert_eq!("", window.pre_cursor);
+    assert!(!window.hidden_left);
+    assert!(window.hidden_right);
+}
+
+#[test]
+fn window_in_the_middle_hides_both_sides() {
+    let input = Input::new_with("0123456789").with_cursor(5);
+
+    let window = input.window(5);
+
+    assert_eq!("234", window.content);
+    assert_eq!("234", window.pre_cursor);
+    assert!(window.hidden_left);
+    assert!(window.hidden_right);
+}
+
+#[test]
+fn window_does_not_split_wide_graphemes() {
+    let input = Input::new_with("a世界b").with_cursor(3);
+
+    let window = input.window(4);
+
+    assert_eq!("界", window.content);
+    assert_eq!("界", window.pre_cursor);
+    assert!(window.hidden_left);
+    assert!(window.hidden_right);
+}
+
+#[test]
+fn backspace_removes_grapheme_in_place() {
+    let mut input = Input::new_with("a🧘🏻‍♂️é🍞").with_cursor(2);
+    let capacity = input.content.capacity();
+
+    let result = input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("aé🍞", input.content());
+    assert_eq!(1, input.cursor());
+    assert_eq!(3, input.length());
+    assert_eq!(capacity, input.content.capacity());
+}
+
+#[test]
+fn insert_in_the_middle_keeps_grapheme_boundaries() {
+    let mut input = Input::new_with("🍞é").with_cursor(1);
+
+    input.handle(InputAction::Write('x'));
+
+    assert_eq!("🍞xé", input.content());
+    assert_eq!(2, input.cursor());
+    assert_eq!(3, input.length());
+}
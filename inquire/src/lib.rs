@@ -30,6 +30,8 @@
 //!
 //! \* Date-related features are available by enabling the `date` feature.
 //!
+//! \* Deriving `SelectableVariants` for enums is available by enabling the `derive` feature.
+//!
 //! # Simple Example
 //!
 //! ```rust no_run
@@ -70,8 +72,10 @@ pub mod autocompletion;
 mod config;
 #[cfg(feature = "date")]
 mod date_utils;
+mod enum_support;
 pub mod error;
 pub mod formatter;
+pub mod history;
 mod input;
 pub mod list_option;
 pub mod parser;
@@ -84,6 +88,10 @@ pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
 pub use crate::config::set_global_render_config;
+pub use crate::enum_support::SelectableVariants;
 pub use crate::error::{CustomUserError, InquireError};
+pub use crate::history::History;
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+#[cfg(feature = "derive")]
+pub use inquire_derive::SelectableVariants;
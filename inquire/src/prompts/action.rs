@@ -35,11 +35,13 @@ where
     where
         I: InnerAction<Config = C>,
     {
+        if let Some(action) = I::override_key(key, config) {
+            return Some(action);
+        }
+
         match key {
-            Key::Enter => Some(Action::Submit),
-            Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Submit),
-            Key::Escape => Some(Action::Cancel),
-            Key::Char('g', KeyModifiers::CONTROL) => Some(Action::Cancel),
+            Key::Enter | Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Submit),
+            Key::Escape | Key::Char('g', KeyModifiers::CONTROL) => Some(Action::Cancel),
             Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
             key => I::from_key(key, config).map(Action::Inner),
         }
@@ -63,4 +65,12 @@ where
     fn from_key(key: Key, config: &Self::Config) -> Option<Self>
     where
         Self: Sized;
+
+    /// Derives a top-level prompt action from a Key event, taking precedence
+    /// over the default bindings such as `enter` to submit.
+    ///
+    /// Returns `None` by default, meaning the default bindings apply.
+    fn override_key(_key: Key, _config: &Self::Config) -> Option<Action<Self>> {
+        None
+    }
 }
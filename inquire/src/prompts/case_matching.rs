@@ -0,0 +1,129 @@
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+
+#[cfg(feature = "fuzzy")]
+static INSENSITIVE_MATCHER: Lazy<SkimMatcherV2> =
+    Lazy::new(|| SkimMatcherV2::default().ignore_case());
+#[cfg(feature = "fuzzy")]
+static SENSITIVE_MATCHER: Lazy<SkimMatcherV2> =
+    Lazy::new(|| SkimMatcherV2::default().respect_case());
+
+/// How the filter input of list prompts is matched against the case of the
+/// options.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CaseMatching {
+    /// Options only match when the case of the input is the same.
+    Sensitive,
+
+    /// Case is ignored when matching options. This is the default.
+    #[default]
+    Insensitive,
+
+    /// Case is ignored, unless the input contains an uppercase letter.
+    Smart,
+}
+
+impl CaseMatching {
+    /// Returns whether the case of `input` is significant when matching options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::CaseMatching;
+    ///
+    /// assert!(!CaseMatching::Smart.is_case_sensitive("pod"));
+    /// assert!(CaseMatching::Smart.is_case_sensitive("Pod"));
+    /// assert!(CaseMatching::Sensitive.is_case_sensitive("pod"));
+    /// ```
+    pub fn is_case_sensitive(self, input: &str) -> bool {
+        match self {
+            Self::Sensitive => true,
+            Self::Insensitive => false,
+            Self::Smart => input.chars().any(char::is_uppercase),
+        }
+    }
+
+    /// Scores `value` against `input` with the built-in matching of list prompts:
+    /// fuzzy matching with the `fuzzy` feature, enabled by default, and substring
+    /// matching otherwise. Returns `None` when the value does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::CaseMatching;
+    ///
+    /// assert!(CaseMatching::Insensitive.score("pod", "Pod").is_some());
+    /// assert!(CaseMatching::Sensitive.score("pod", "Pod").is_none());
+    /// assert!(CaseMatching::Smart.score("Pod", "pod").is_none());
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub fn score(self, input: &str, value: &str) -> Option<i64> {
+        self.matcher(input).fuzzy_match(value, input)
+    }
+
+    /// Scores `value` against `input` with the built-in matching of list prompts:
+    /// fuzzy matching with the `fuzzy` feature, enabled by default, and substring
+    /// matching otherwise. Returns `None` when the value does not match.
+    #[cfg(not(feature = "fuzzy"))]
+    pub fn score(self, input: &str, value: &str) -> Option<i64> {
+        self.match_start(input, value).map(|_| 0)
+    }
+
+    /// Returns the indices of the characters of `value` matched by `input`, as
+    /// scored by [`CaseMatching::score`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::CaseMatching;
+    ///
+    /// assert_eq!(vec![0, 1], CaseMatching::Insensitive.highlight("sa", "San Diego"));
+    /// assert_eq!(Vec::<usize>::new(), CaseMatching::Sensitive.highlight("sa", "San Diego"));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub fn highlight(self, input: &str, value: &str) -> Vec<usize> {
+        self.matcher(input)
+            .fuzzy_indices(value, input)
+            .map(|(_score, indices)| indices)
+            .unwrap_or_default()
+    }
+
+    /// Returns the indices of the characters of `value` matched by `input`, as
+    /// scored by [`CaseMatching::score`].
+    #[cfg(not(feature = "fuzzy"))]
+    pub fn highlight(self, input: &str, value: &str) -> Vec<usize> {
+        let len = input.chars().count();
+        match (len, self.match_start(input, value)) {
+            (0, _) | (_, None) => vec![],
+            (_, Some(start)) => (start..start + len).collect(),
+        }
+    }
+
+    #[cfg(feature = "fuzzy")]
+    fn matcher(self, input: &str) -> &'static SkimMatcherV2 {
+        match self.is_case_sensitive(input) {
+            true => &SENSITIVE_MATCHER,
+            false => &INSENSITIVE_MATCHER,
+        }
+    }
+
+    /// Character index where `input` first appears in `value`.
+    #[cfg(not(feature = "fuzzy"))]
+    fn match_start(self, input: &str, value: &str) -> Option<usize> {
+        let sensitive = self.is_case_sensitive(input);
+        let input = input.chars().collect::<Vec<char>>();
+        let value = value.chars().collect::<Vec<char>>();
+        if input.is_empty() {
+            return Some(0);
+        }
+
+        value.windows(input.len()).position(|window| {
+            window
+                .iter()
+                .zip(&input)
+                .all(|(a, b)| a == b || (!sensitive && a.to_lowercase().eq(b.to_lowercase())))
+        })
+    }
+}
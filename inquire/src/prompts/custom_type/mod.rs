@@ -222,6 +222,23 @@ where
         self
     }
 
+    /// Adds the validators to the collection of validators in the order they are given,
+    /// taking ownership of them. Useful when the list is built at runtime.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed user's answer.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_owned_validators(
+        mut self,
+        validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    ) -> Self {
+        self.validators.extend(validators);
+        self
+    }
+
     /// Sets a custom error message displayed when a submission could not be parsed to a value.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
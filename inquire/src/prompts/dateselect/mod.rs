@@ -226,6 +226,20 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Adds the validators to the collection of validators in the order they are given,
+    /// taking ownership of them. Useful when the list is built at runtime.
+    /// You might want to use this feature in case you need to limit the user to specific
+    /// choices, such as not allowing weekends.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_owned_validators(mut self, validators: Vec<Box<dyn DateValidator>>) -> Self {
+        self.validators.extend(validators);
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
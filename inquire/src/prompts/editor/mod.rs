@@ -189,6 +189,20 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Adds the validators to the collection of validators in the order they are given,
+    /// taking ownership of them. Useful when the list is built at runtime.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the user's answer, such as defining a limit of characters.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_owned_validators(mut self, validators: Vec<Box<dyn StringValidator>>) -> Self {
+        self.validators.extend(validators);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
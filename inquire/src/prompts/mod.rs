@@ -1,4 +1,5 @@
 mod action;
+mod case_matching;
 mod confirm;
 mod custom_type;
 #[cfg(feature = "date")]
@@ -13,6 +14,7 @@ mod select;
 mod text;
 
 pub use action::*;
+pub use case_matching::*;
 pub use confirm::*;
 pub use custom_type::*;
 #[cfg(feature = "date")]
@@ -18,26 +18,50 @@ pub enum MultiSelectPromptAction {
     PageUp,
     /// Moves the cursor to the page below.
     PageDown,
+    /// Moves the cursor half a page above.
+    HalfPageUp,
+    /// Moves the cursor half a page below.
+    HalfPageDown,
     /// Moves the cursor to the start of the list.
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Activates the filter input when in vim mode.
+    StartFiltering,
     /// Toggles the selection of the current option.
     ToggleCurrentOption,
-    /// Selects all options.
+    /// Selects all options displayed by the filter, up to the maximum selection.
     SelectAll,
-    /// Deselects all options.
+    /// Deselects all options displayed by the filter.
     ClearSelections,
+    /// Flips the selection of all options displayed by the filter, selecting
+    /// the unselected ones up to the maximum selection.
+    InvertSelection,
+    /// Moves the cursor to the option clicked at the given position of the
+    /// terminal screen, toggling its selection when it was already highlighted.
+    Click {
+        /// Column of the click.
+        column: u16,
+        /// Row of the click.
+        row: u16,
+    },
 }
 
 impl InnerAction for MultiSelectPromptAction {
     type Config = MultiSelectConfig;
 
     fn from_key(key: Key, config: &MultiSelectConfig) -> Option<Self> {
-        if config.vim_mode {
+        let vim_normal_mode = config.vim_mode && !config.vim_filtering;
+
+        if vim_normal_mode {
             let action = match key {
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
                 Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('g', KeyModifiers::NONE) => Some(Self::MoveToStart),
+                Key::Char('G', KeyModifiers::NONE | KeyModifiers::SHIFT) => Some(Self::MoveToEnd),
+                Key::Char('u', KeyModifiers::CONTROL) => Some(Self::HalfPageUp),
+                Key::Char('d', KeyModifiers::CONTROL) => Some(Self::HalfPageDown),
+                Key::Char('/', KeyModifiers::NONE) => Some(Self::StartFiltering),
                 _ => None,
             };
 
@@ -55,10 +79,22 @@ impl InnerAction for MultiSelectPromptAction {
             Key::PageDown => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Click { column, row } if config.mouse => Self::Click { column, row },
+            Key::ScrollUp if config.mouse => Self::MoveUp,
+            Key::ScrollDown if config.mouse => Self::MoveDown,
+
             Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentOption,
-            Key::Right(KeyModifiers::NONE) => Self::SelectAll,
-            Key::Left(KeyModifiers::NONE) => Self::ClearSelections,
+            Key::Right(KeyModifiers::NONE) | Key::Char('a', KeyModifiers::CONTROL) => {
+                Self::SelectAll
+            }
+            Key::Left(KeyModifiers::NONE) | Key::Char('r', KeyModifiers::CONTROL) => {
+                Self::ClearSelections
+            }
+            // terminals send ctrl+i as a tab.
+            Key::Tab | Key::Char('i', KeyModifiers::CONTROL) => Self::InvertSelection,
             key => match InputAction::from_key(key, &()) {
+                // in vim mode, typing only filters after pressing '/'.
+                Some(InputAction::Write(_)) if vim_normal_mode => return None,
                 Some(action) => Self::FilterInput(action),
                 None => return None,
             },
@@ -5,21 +5,37 @@ use crate::MultiSelect;
 pub struct MultiSelectConfig {
     /// Whether to use vim-style keybindings.
     pub vim_mode: bool,
+    /// Whether the filter input was activated by pressing `/` in vim mode,
+    /// making the vim keybindings type into it instead.
+    pub vim_filtering: bool,
+    /// Whether moving past the first or last option wraps to the other end.
+    pub wrap_around: bool,
     /// Page size of the list of options.
     pub page_size: usize,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether mouse clicks and wheel scrolls are handled.
+    pub mouse: bool,
+    /// Whether the counter of selected options is displayed.
+    pub selected_counter: bool,
+    /// Whether the page indicator is displayed when the options are paged.
+    pub page_indicator: bool,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
     fn from(value: &MultiSelect<'_, T>) -> Self {
         Self {
             vim_mode: value.vim_mode,
+            vim_filtering: false,
+            wrap_around: value.wrap_around,
             page_size: value.page_size,
             keep_filter: value.keep_filter,
             reset_cursor: value.reset_cursor,
+            mouse: value.mouse,
+            selected_counter: value.selected_counter,
+            page_indicator: value.page_indicator,
         }
     }
 }
@@ -7,14 +7,14 @@ mod test;
 
 pub use action::*;
 
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
-    formatter::MultiOptionFormatter,
+    formatter::{FilteredMultiOptionFormatter, MultiOptionFormatter},
     list_option::ListOption,
-    prompts::prompt::Prompt,
+    prompts::{prompt::Prompt, CaseMatching},
     terminal::get_default_terminal,
     type_aliases::Scorer,
     ui::{Backend, MultiSelectBackend, RenderConfig},
@@ -23,39 +23,83 @@ use crate::{
 
 use self::prompt::MultiSelectPrompt;
 
-#[cfg(feature = "fuzzy")]
-use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
-#[cfg(feature = "fuzzy")]
-use once_cell::sync::Lazy;
-#[cfg(feature = "fuzzy")]
-static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+/// Order of the selected options returned by a [MultiSelect] prompt.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelectionOrder {
+    /// Selected options are returned in the order of the list of options.
+    /// This is the default.
+    #[default]
+    ListOrder,
+
+    /// Selected options are returned in the order they were selected by the
+    /// user, after the default selections. Unselecting an option and selecting
+    /// it again moves it to the end.
+    ToggleOrder,
+}
+
 /// Prompt suitable for when you need the user to select many options (including none if applicable) among a list of them.
 ///
-/// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
+/// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow or `ctrl+r`, select all options by pressing the right arrow or `ctrl+a` and invert the selection by pressing tab.
 ///
 /// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
 ///
-/// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
+/// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa), unless wrap-around navigation is disabled.
 ///
 /// Customizable options:
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
-/// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error. They can also be set by value with `with_default_values` or by predicate with `with_default_by`.
 /// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+/// - **Filter in answer**: Whether the text of the filter input is displayed along with the final answer. Formatters set with `with_filtered_formatter` also receive it.
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
-/// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
+/// - **Disabled options**: Options that are displayed but whose selection can not be changed, kept selected when among the defaults. An optional reason is displayed when the user tries to change them.
+/// - **Group headers**: Headers rendered above groups of options, which can be created with `new_grouped`. Pressing space on a header selects the options of the group, or unselects them when all are selected. The checkbox of a header shows whether none, some or all options of the group are selected.
+/// - **Selection limits**: Minimum and maximum number of selected options. Submitting fewer options than the minimum displays an error, and selecting options beyond the maximum is rejected right away, with an error displayed until the next key press.
+/// - **Selection order**: Whether the selected options are returned in the order of the list, the default, or in the order the user selected them.
+/// - **Selected counter**: Number of selected options in the whole list, displayed next to the filter input out of the maximum selection or the number of options, e.g. `[3/10 selected]`. Enabled by default.
+/// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. options that can not be selected together. They run in order after the selection limits, displaying the first error.
 ///   - No validators are on by default.
-/// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Page size**: Number of options displayed at once, 7 by default. When there are options above or below the page, the first and last displayed options are prefixed with scroll indicators, and the range of displayed options is shown below them, e.g. `items 8–14 of 33`, unless disabled with `with_page_indicator(false)`.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
-/// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+/// - **Mouse**: Options can be clicked and scrolled with the mouse when enabled with `with_mouse`.
+/// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. The cursor stays on the same option either way. Defaults to true.
 ///
 /// # Example
 ///
+/// Options of any type implementing `Display` are returned by value, in the order of the list, and formatters receive the selected options:
+/// ```no_run
+/// use std::fmt::{Display, Formatter};
+///
+/// use inquire::MultiSelect;
+///
+/// struct Feature {
+///     name: String,
+///     default_enabled: bool,
+/// }
+///
+/// impl Display for Feature {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{}", self.name)
+///     }
+/// }
+///
+/// let features = vec![
+///     Feature { name: "auth".into(), default_enabled: true },
+///     Feature { name: "metrics".into(), default_enabled: false },
+/// ];
+///
+/// let enabled: Vec<Feature> = MultiSelect::new("Features:", features)
+///     .with_default_by(|feature| feature.default_enabled)
+///     .with_formatter(&|selected| format!("{} features", selected.len()))
+///     .prompt()
+///     .unwrap();
+/// ```
+///
 /// For a full-featured example, check the [GitHub repository](https://github.com/mikaelmello/inquire/blob/main/examples/multiselect.rs).
 ///
 /// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
@@ -77,9 +121,15 @@ pub struct MultiSelect<'a, T> {
     pub page_size: usize,
 
     /// Whether vim mode is enabled. When enabled, the user can
-    /// navigate through the options using hjkl.
+    /// navigate through the options using j/k, g/G and ctrl+d/ctrl+u,
+    /// and typing only filters the options after pressing `/`.
     pub vim_mode: bool,
 
+    /// Whether moving up from the first option moves the cursor to the last
+    /// one, and moving down from the last option moves it to the first one.
+    /// Defaults to true.
+    pub wrap_around: bool,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
@@ -98,10 +148,57 @@ pub struct MultiSelect<'a, T> {
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
-    /// Validator to apply to the user input.
+    /// Function that formats the answer along with the text of the filter
+    /// input when it was submitted. When set, it replaces the formatter.
+    pub filtered_formatter: Option<FilteredMultiOptionFormatter<'a, T>>,
+
+    /// Whether the text of the filter input is displayed along with the
+    /// final answer, when not empty. Defaults to false.
+    pub show_filter_in_answer: bool,
+
+    /// Whether the options can be clicked and scrolled with the mouse.
+    /// Defaults to false.
+    pub mouse: bool,
+
+    /// Minimum number of options to be selected for the answer to be
+    /// submitted. Defaults to 0.
+    pub minimum_selection: usize,
+
+    /// Maximum number of options that can be selected. Selecting more options
+    /// is rejected right away. Defaults to no limit.
+    pub maximum_selection: Option<usize>,
+
+    /// Options that are displayed but whose selection can not be changed,
+    /// along with the reason displayed when the user tries to. They keep the
+    /// selection they start with, selected when among the defaults.
+    pub disabled_options: BTreeMap<usize, Option<&'a str>>,
+
+    /// Headers of groups of options, rendered above the option at their
+    /// index, which is the first of the group.
+    pub group_headers: BTreeMap<usize, &'a str>,
+
+    /// Order of the selected options returned as the answer, and received by
+    /// the formatter and validator. Defaults to the order of the list.
+    pub selection_order: SelectionOrder,
+
+    /// Whether the number of selected options is displayed next to the filter
+    /// input, out of the maximum selection or the number of options.
+    /// Defaults to true.
+    pub selected_counter: bool,
+
+    /// Whether the range of displayed options, such as `items 8–14 of 33`, is
+    /// displayed below the options when they do not fit in a single page.
+    /// Defaults to true.
+    pub page_indicator: bool,
+
+    /// Collection of validators to apply to the selected options when the
+    /// answer is submitted, after the minimum and maximum selections.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
     ///
-    /// In case of error, the message is displayed one line above the prompt.
-    pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<Box<dyn MultiOptionValidator<T>>>,
 
     /// RenderConfig to apply to the rendered interface.
     ///
@@ -150,7 +247,10 @@ where
     ///
     /// # Examples
     ///
-    /// ```
+    /// With the `fuzzy` feature:
+    ///
+    #[cfg_attr(feature = "fuzzy", doc = "```")]
+    #[cfg_attr(not(feature = "fuzzy"), doc = "```ignore")]
     /// use inquire::MultiSelect;
     ///
     /// let scorer = MultiSelect::<&str>::DEFAULT_SCORER;
@@ -168,20 +268,9 @@ where
     /// assert_eq!(None,     scorer("sa", &"Jacksonville",  "Jacksonville",  11));
     /// assert_eq!(Some(49), scorer("sa", &"San Jose",      "San Jose",      12));
     /// ```
-    #[cfg(feature = "fuzzy")]
-    pub const DEFAULT_SCORER: Scorer<'a, T> =
-        &|input, _option, string_value, _idx| -> Option<i64> {
-            DEFAULT_MATCHER.fuzzy_match(string_value, input)
-        };
-
-    #[cfg(not(feature = "fuzzy"))]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
-            let filter = input.to_lowercase();
-            match string_value.to_lowercase().contains(&filter) {
-                true => Some(0),
-                false => None,
-            }
+            CaseMatching::Insensitive.score(input, string_value)
         };
 
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
@@ -190,6 +279,9 @@ where
     /// Default value of vim mode, equal to the global default value [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
+    /// Default value of wrap-around navigation.
+    pub const DEFAULT_WRAP_AROUND: bool = true;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
@@ -200,29 +292,106 @@ where
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default value of displaying the filter input along with the answer.
+    pub const DEFAULT_SHOW_FILTER_IN_ANSWER: bool = false;
+
+    /// Default value of mouse support.
+    pub const DEFAULT_MOUSE: bool = false;
+
+    /// Default minimum number of selected options.
+    pub const DEFAULT_MINIMUM_SELECTION: usize = 0;
+
+    /// Default value of displaying the counter of selected options.
+    pub const DEFAULT_SELECTED_COUNTER: bool = true;
+
+    /// Default value of displaying the page indicator.
+    pub const DEFAULT_PAGE_INDICATOR: bool = true;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
-        Some("↑↓ to move, space to select one, → to all, ← to none, type to filter, ESC to cancel");
+        Some("↑↓ to move, space to toggle, → all, ← none, tab to invert, type to filter");
 
     /// Creates a [MultiSelect] with the provided message and options, along with default configuration values.
-    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+    ///
+    /// The options can be any iterable of displayable values, such as a `Vec`,
+    /// an array, a range or the keys of a map, and are collected internally.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let ans = MultiSelect::new("Ports:", 8080..=8085).prompt();
+    /// ```
+    pub fn new<I>(message: &'a str, options: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
         Self {
             message,
-            options,
+            options: options.into_iter().collect(),
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
             vim_mode: Self::DEFAULT_VIM_MODE,
+            wrap_around: Self::DEFAULT_WRAP_AROUND,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
             scorer: Self::DEFAULT_SCORER,
             formatter: Self::DEFAULT_FORMATTER,
-            validator: None,
+            filtered_formatter: None,
+            show_filter_in_answer: Self::DEFAULT_SHOW_FILTER_IN_ANSWER,
+            mouse: Self::DEFAULT_MOUSE,
+            minimum_selection: Self::DEFAULT_MINIMUM_SELECTION,
+            maximum_selection: None,
+            disabled_options: BTreeMap::new(),
+            group_headers: BTreeMap::new(),
+            selection_order: SelectionOrder::default(),
+            selected_counter: Self::DEFAULT_SELECTED_COUNTER,
+            page_indicator: Self::DEFAULT_PAGE_INDICATOR,
+            validators: Vec::new(),
             render_config: get_configuration(),
         }
     }
 
+    /// Creates a [MultiSelect] with the provided message and groups of options,
+    /// each preceded by its header, along with default configuration values.
+    ///
+    /// The options are flattened in order, so the indexes of the selections
+    /// refer to the positions of the options among all options, not counting
+    /// headers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let ans = MultiSelect::new_grouped(
+    ///     "Hosts:",
+    ///     vec![
+    ///         ("Production", vec!["prod-1", "prod-2"]),
+    ///         ("Staging", vec!["staging-1"]),
+    ///     ],
+    /// )
+    /// .prompt();
+    /// ```
+    pub fn new_grouped(message: &'a str, groups: Vec<(&'a str, Vec<T>)>) -> Self {
+        let mut options = vec![];
+        let mut group_headers = BTreeMap::new();
+
+        for (header, group) in groups {
+            if !group.is_empty() {
+                group_headers.insert(options.len(), header);
+            }
+            options.extend(group);
+        }
+
+        let mut multi_select = Self::new(message, options);
+        multi_select.group_headers = group_headers;
+        multi_select
+    }
+
     /// Sets the help message of the prompt.
     pub fn with_help_message(mut self, message: &'a str) -> Self {
         self.help_message = Some(message);
@@ -242,12 +411,32 @@ where
     }
 
     /// Enables or disables vim_mode.
+    ///
+    /// In vim mode, j/k move the cursor one row, g/G move it to the first and last
+    /// options, and ctrl+u/ctrl+d move it half a page. As these conflict with the
+    /// filter input, typed characters only filter the options after pressing `/`,
+    /// and ESC clears the filter and brings the vim keybindings back.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
-    /// Sets the keep filter behavior.
+    /// Enables or disables wrap-around navigation, in which moving up from the
+    /// first option moves the cursor to the last one and vice-versa. The page
+    /// displayed follows the cursor to the other end of the list.
+    ///
+    /// When disabled, the cursor stops at the first and last options.
+    /// Defaults to true.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets whether the filter input is kept or cleared after the selection is
+    /// changed, be it by toggling an option or selecting, clearing or inverting
+    /// all of them. The cursor stays on the option it was on either way.
+    ///
+    /// Defaults to true.
     pub fn with_keep_filter(mut self, keep_filter: bool) -> Self {
         self.keep_filter = keep_filter;
         self
@@ -259,34 +448,316 @@ where
         self
     }
 
-    /// Sets the formatter.
+    /// Sets how the filter input is matched against the case of the options,
+    /// replacing the scorer with the built-in one following the given mode.
+    ///
+    /// Call [`MultiSelect::with_scorer`] afterwards to customize it. Default is
+    /// [`CaseMatching::Insensitive`].
+    pub fn with_filter_case(mut self, filter_case: CaseMatching) -> Self {
+        self.scorer = match filter_case {
+            CaseMatching::Sensitive => &|input, _option, string_value, _idx| {
+                CaseMatching::Sensitive.score(input, string_value)
+            },
+            CaseMatching::Insensitive => Self::DEFAULT_SCORER,
+            CaseMatching::Smart => {
+                &|input, _option, string_value, _idx| CaseMatching::Smart.score(input, string_value)
+            }
+        };
+        self
+    }
+
+    /// Sets the formatter, called with the selected options to display the
+    /// answer, including when no option is selected.
+    ///
+    /// The [formatter](crate::formatter) module provides formatters that
+    /// summarize long selections, such as
+    /// [`selected_count`](crate::formatter::selected_count) and
+    /// [`first_selected_and_count`](crate::formatter::first_selected_and_count).
     pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
-    /// Sets the validator to apply to the user input. You might want to use this feature
-    /// in case you need to limit the user to specific choices, such as limiting the number
-    /// of selections.
+    /// Sets the formatter that also receives the text of the filter input when
+    /// the answer was submitted, allowing the final answer to describe what
+    /// was searched for. It replaces the formatter.
+    pub fn with_filtered_formatter(
+        mut self,
+        formatter: FilteredMultiOptionFormatter<'a, T>,
+    ) -> Self {
+        self.filtered_formatter = Some(formatter);
+        self
+    }
+
+    /// Enables or disables displaying the text of the filter input along with
+    /// the final answer, e.g. `web-frankfurt-03 (filtered: "frank")`. Nothing is
+    /// added when the filter input is empty or a filtered formatter is set.
+    /// Defaults to false.
+    pub fn with_filter_in_answer(mut self, show_filter_in_answer: bool) -> Self {
+        self.show_filter_in_answer = show_filter_in_answer;
+        self
+    }
+
+    /// Enables or disables mouse support.
+    ///
+    /// When enabled, clicking an option moves the cursor to it and clicking
+    /// the highlighted option toggles its selection, so double-clicking an
+    /// option selects it. Scrolling the mouse wheel moves the cursor up and down.
+    ///
+    /// Mouse events are only reported by the crossterm terminal backend.
+    /// Defaults to false.
+    pub fn with_mouse(mut self, mouse: bool) -> Self {
+        self.mouse = mouse;
+        self
+    }
+
+    /// Sets the minimum number of options to be selected. Submitting fewer
+    /// options displays an error above the prompt, which stays open.
+    ///
+    /// It must not be greater than the number of options or than the maximum
+    /// selection, otherwise the prompt returns an
+    /// [`InquireError::InvalidConfiguration`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let ans = MultiSelect::new("Toppings:", vec!["cheese", "ham", "olives", "onion"])
+    ///     .with_minimum_selection(1)
+    ///     .with_maximum_selection(3)
+    ///     .prompt();
+    /// ```
+    pub fn with_minimum_selection(mut self, minimum_selection: usize) -> Self {
+        self.minimum_selection = minimum_selection;
+        self
+    }
+
+    /// Sets the maximum number of options that can be selected. Selecting an
+    /// option beyond it, one by one or all at once, is rejected with an error
+    /// above the prompt, displayed until the next key press. Unselecting an
+    /// option allows selecting another one right away. Defaults selected beyond
+    /// it must be unselected before submitting.
+    ///
+    /// It must be greater than zero, otherwise the prompt returns an
+    /// [`InquireError::InvalidConfiguration`] error.
+    pub fn with_maximum_selection(mut self, maximum_selection: usize) -> Self {
+        self.maximum_selection = Some(maximum_selection);
+        self
+    }
+
+    /// Marks the options at the provided indexes as disabled. Disabled options
+    /// are still displayed, with the `disabled_option` style and the
+    /// `disabled_option_indicator` of the `RenderConfig`, but their selection
+    /// can not be changed, be it one by one or all at once.
+    ///
+    /// They keep the selection they start with: disabled options among the
+    /// defaults are always selected and returned in the answer, while the
+    /// others are never selected.
+    ///
+    /// Indexes out-of-range of the option list are ignored.
+    pub fn with_disabled_indices(mut self, indices: &[usize]) -> Self {
+        for index in indices {
+            self.disabled_options.entry(*index).or_insert(None);
+        }
+        self
+    }
+
+    /// Marks the option at the provided index as disabled, like
+    /// [`with_disabled_indices`](Self::with_disabled_indices), along with the
+    /// reason displayed as an error when the user tries to change its selection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let ans = MultiSelect::new("Features:", vec!["backups", "metrics", "sso"])
+    ///     .with_default(&[0])
+    ///     .with_disabled_option(0, "Backups are included in your plan")
+    ///     .with_disabled_option(2, "SSO requires the enterprise plan")
+    ///     .prompt();
+    /// ```
+    pub fn with_disabled_option(mut self, index: usize, reason: &'a str) -> Self {
+        self.disabled_options.insert(index, Some(reason));
+        self
+    }
+
+    /// Sets a header to be rendered above the option at the provided index,
+    /// starting a group that goes until the next header.
+    ///
+    /// Headers take a row of the page and can be highlighted by the cursor,
+    /// where pressing space selects the options of the group displayed by the
+    /// filter, or unselects them when all are selected. Headers are never part
+    /// of the answer. While filtering, headers of groups without matching
+    /// options are hidden.
+    ///
+    /// Indexes out-of-range of the option list are ignored.
+    pub fn with_group_header(mut self, index: usize, header: &'a str) -> Self {
+        self.group_headers.insert(index, header);
+        self
+    }
+
+    /// Sets the order of the selected options returned as the answer, and
+    /// received by the formatter and validator.
+    ///
+    /// With [`SelectionOrder::ToggleOrder`], options are returned in the order
+    /// the user selected them, after the default selections in the order they
+    /// were set. Unselecting an option and selecting it again moves it to the end.
+    ///
+    /// Defaults to [`SelectionOrder::ListOrder`].
     ///
-    /// In case of error, the message is displayed one line above the prompt.
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::{MultiSelect, SelectionOrder};
+    ///
+    /// let top_three = MultiSelect::new("Favorite languages, in order:", vec!["C", "Go", "Rust", "Zig"])
+    ///     .with_selection_order(SelectionOrder::ToggleOrder)
+    ///     .with_maximum_selection(3)
+    ///     .prompt();
+    /// ```
+    pub fn with_selection_order(mut self, selection_order: SelectionOrder) -> Self {
+        self.selection_order = selection_order;
+        self
+    }
+
+    /// Enables or disables the counter of selected options displayed next to
+    /// the filter input, such as `[3/10 selected]`. It counts the selected
+    /// options of the whole list, out of the maximum selection when set or
+    /// the number of options otherwise.
+    ///
+    /// Defaults to true.
+    pub fn with_selected_counter(mut self, selected_counter: bool) -> Self {
+        self.selected_counter = selected_counter;
+        self
+    }
+
+    /// Enables or disables the page indicator displayed below the options when
+    /// they do not fit in a single page, such as `items 8–14 of 33`. It shows
+    /// the range of the options displayed by the filter in the current page,
+    /// updated as the page scrolls, and is cleared along with the options once
+    /// the prompt finishes.
+    ///
+    /// Defaults to true.
+    pub fn with_page_indicator(mut self, page_indicator: bool) -> Self {
+        self.page_indicator = page_indicator;
+        self
+    }
+
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to check rules over the whole selection, such as options that can
+    /// not be selected together.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear. The selections are kept when the
+    /// answer is invalid.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::{list_option::ListOption, validator::Validation, MultiSelect};
+    ///
+    /// let ans = MultiSelect::new("Plan:", vec!["storage", "backups", "custom"])
+    ///     .with_validator(|selected: &[ListOption<&&str>]| {
+    ///         match selected.len() > 1 && selected.iter().any(|o| *o.value == "custom") {
+    ///             true => Ok(Validation::Invalid("A custom plan can not be combined".into())),
+    ///             false => Ok(Validation::Valid),
+    ///         }
+    ///     })
+    ///     .prompt();
+    /// ```
     pub fn with_validator<V>(mut self, validator: V) -> Self
     where
         V: MultiOptionValidator<T> + 'static,
     {
-        self.validator = Some(Box::new(validator));
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[Box<dyn MultiOptionValidator<T>>]) -> Self {
+        for validator in validators {
+            #[allow(suspicious_double_ref_op)]
+            self.validators.push(validator.clone());
+        }
         self
     }
 
     /// Sets the indexes to be selected by default.
     ///
-    /// The values should be valid indexes for the given option list. Any
-    /// numbers larger than the option list or duplicates will be ignored.
+    /// The values should be valid indexes for the given option list, otherwise
+    /// the prompt returns an [`InquireError::InvalidConfiguration`] error.
+    /// Duplicates are ignored.
     pub fn with_default(mut self, default: &'a [usize]) -> Self {
         self.default = Some(default.to_vec());
         self
     }
 
+    /// Sets the options equal to any of the provided values to be selected by
+    /// default, e.g. the currently enabled items of a configuration being
+    /// edited. Values not found among the options are ignored.
+    ///
+    /// This overrides any previously set default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let enabled = vec!["metrics", "tracing"];
+    /// let features = vec!["auth", "metrics", "cache", "tracing"];
+    ///
+    /// let ans = MultiSelect::new("Features:", features)
+    ///     .with_default_values(&enabled)
+    ///     .prompt();
+    /// ```
+    pub fn with_default_values(self, values: &[T]) -> Self
+    where
+        T: PartialEq,
+    {
+        self.with_default_by(|option| values.contains(option))
+    }
+
+    /// Sets the options for which the predicate returns true to be selected by
+    /// default, for options that can not be compared with `PartialEq`.
+    ///
+    /// This overrides any previously set default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let ports = vec![22, 80, 443, 8080];
+    ///
+    /// let ans = MultiSelect::new("Open ports:", ports)
+    ///     .with_default_by(|port| *port < 1024)
+    ///     .prompt();
+    /// ```
+    pub fn with_default_by<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&T) -> bool,
+    {
+        let default = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| predicate(option))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        self.default = Some(default);
+        self
+    }
+
     /// Sets all options to be selected by default.
     /// This overrides any previously set default and is equivalent to calling
     /// `with_default` with a slice containing all indexes for the given
@@ -297,6 +768,9 @@ where
     }
 
     /// Sets the starting cursor index.
+    ///
+    /// Indexes out-of-range of the option list make the prompt return an
+    /// [`InquireError::InvalidConfiguration`] error.
     pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
         self.starting_cursor = starting_cursor;
         self
@@ -374,8 +848,12 @@ where
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
-    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
-    /// the index of the selection and the owned object selected by the user.
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s containing
+    /// the index of the selections and the owned objects selected by the user.
+    ///
+    /// The indices refer to the list of options the prompt was created with,
+    /// even when the options were filtered or sorted by the scorer, and the
+    /// selections are returned in the order of that list.
     pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
         let terminal = get_default_terminal()?;
         let mut backend = Backend::new(terminal, self.render_config)?;
@@ -386,6 +864,13 @@ where
         self,
         backend: &mut B,
     ) -> InquireResult<Vec<ListOption<T>>> {
-        MultiSelectPrompt::new(self)?.prompt(backend)
+        let mouse = self.mouse;
+        let prompt = MultiSelectPrompt::new(self)?;
+
+        if mouse {
+            backend.enable_mouse_capture()?;
+        }
+
+        prompt.prompt(backend)
     }
 }
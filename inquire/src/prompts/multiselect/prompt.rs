@@ -1,20 +1,35 @@
-use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
 
 use crate::{
     error::InquireResult,
-    formatter::MultiOptionFormatter,
+    formatter::{FilteredMultiOptionFormatter, MultiOptionFormatter},
     input::{Input, InputActionResult},
     list_option::ListOption,
     prompts::prompt::{ActionResult, Prompt},
     type_aliases::Scorer,
-    ui::MultiSelectBackend,
-    utils::paginate,
+    ui::{GroupSelection, MultiSelectBackend, OptionRow},
+    utils::{paginate, Page},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
-    InquireError, MultiSelect,
+    InquireError, MultiSelect, SelectionOrder,
 };
 
 use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
 
+const DISABLED_OPTION_ERROR_MESSAGE: &str = "This option can not be changed";
+
+/// Row of the list of options, where the cursor can be.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Row {
+    /// Header of the group starting at the index of the option.
+    Header(usize),
+    /// Option at the index.
+    Option(usize),
+}
+
 pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     config: MultiSelectConfig,
@@ -23,12 +38,25 @@ pub struct MultiSelectPrompt<'a, T> {
     help_message: Option<&'a str>,
     cursor_index: usize,
     checked: BTreeSet<usize>,
+    /// Checked options in the order they were checked.
+    toggle_order: Vec<usize>,
+    selection_order: SelectionOrder,
+    disabled_options: BTreeMap<usize, Option<&'a str>>,
     input: Input,
     scored_options: Vec<usize>,
+    /// Rows displayed for the scored options, which the cursor moves through.
+    rows: Vec<Row>,
+    group_headers: BTreeMap<usize, &'a str>,
     scorer: Scorer<'a, T>,
     formatter: MultiOptionFormatter<'a, T>,
-    validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    filtered_formatter: Option<FilteredMultiOptionFormatter<'a, T>>,
+    show_filter_in_answer: bool,
+    minimum_selection: usize,
+    maximum_selection: Option<usize>,
+    validators: Vec<Box<dyn MultiOptionValidator<T>>>,
     error: Option<ErrorMessage>,
+    /// Error displayed until the next key press, such as a refused selection.
+    transient_error: Option<ErrorMessage>,
 }
 
 impl<'a, T> MultiSelectPrompt<'a, T>
@@ -41,6 +69,21 @@ where
                 "Available options can not be empty".into(),
             ));
         }
+
+        if mso.page_size == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Page size must be greater than zero".into(),
+            ));
+        }
+
+        if mso.starting_cursor >= mso.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor {} is out-of-bounds for length {} of options",
+                mso.starting_cursor,
+                mso.options.len()
+            )));
+        }
+
         if let Some(default) = &mso.default {
             for i in default {
                 if i >= &mso.options.len() {
@@ -53,34 +96,78 @@ where
             }
         }
 
+        if mso.maximum_selection == Some(0) {
+            return Err(InquireError::InvalidConfiguration(
+                "Maximum selection must be greater than zero".into(),
+            ));
+        }
+
+        if mso.minimum_selection > mso.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Minimum selection {} is greater than the length {} of options",
+                mso.minimum_selection,
+                mso.options.len()
+            )));
+        }
+
+        if let Some(maximum_selection) = mso.maximum_selection {
+            if mso.minimum_selection > maximum_selection {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum selection {} is greater than the maximum selection {}",
+                    mso.minimum_selection, maximum_selection
+                )));
+            }
+        }
+
+        let starting_cursor = mso.starting_cursor;
         let string_options = mso.options.iter().map(T::to_string).collect();
         let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
-            .default
-            .as_ref()
-            .map(|d| {
-                d.iter()
-                    .cloned()
-                    .filter(|i| *i < mso.options.len())
-                    .collect()
-            })
-            .unwrap_or_default();
+        let mut checked = BTreeSet::new();
+        let mut toggle_order = vec![];
+        for &i in mso.default.iter().flatten() {
+            if i < mso.options.len() && checked.insert(i) {
+                toggle_order.push(i);
+            }
+        }
 
-        Ok(Self {
+        let option_count = mso.options.len();
+        let mut group_headers = mso.group_headers.clone();
+        group_headers.retain(|index, _| *index < option_count);
+
+        let mut prompt = Self {
             message: mso.message,
             config: (&mso).into(),
             options: mso.options,
             string_options,
             scored_options,
+            rows: vec![],
+            group_headers,
             help_message: mso.help_message,
-            cursor_index: mso.starting_cursor,
+            cursor_index: 0,
             input: Input::new(),
             scorer: mso.scorer,
             formatter: mso.formatter,
-            validator: mso.validator,
+            filtered_formatter: mso.filtered_formatter,
+            show_filter_in_answer: mso.show_filter_in_answer,
+            minimum_selection: mso.minimum_selection,
+            maximum_selection: mso.maximum_selection,
+            validators: mso.validators,
             error: None,
-            checked: checked_options,
-        })
+            transient_error: None,
+            checked,
+            toggle_order,
+            selection_order: mso.selection_order,
+            disabled_options: mso.disabled_options,
+        };
+
+        prompt.refresh_rows();
+        prompt.cursor_index = prompt
+            .rows
+            .iter()
+            .position(|row| *row == Row::Option(starting_cursor))
+            .unwrap_or_default();
+
+        Ok(prompt)
     }
 
     fn score_options(&self) -> Vec<(usize, i64)> {
@@ -99,12 +186,87 @@ where
             .collect::<Vec<(usize, i64)>>()
     }
 
+    fn refresh_scored_options(&mut self) {
+        let mut options = self.score_options();
+        options.sort_by_key(|(_idx, score)| Reverse(*score));
+
+        self.scored_options = options.into_iter().map(|(idx, _)| idx).collect();
+        self.refresh_rows();
+
+        if self.config.reset_cursor {
+            let _ = self.update_cursor_position(0);
+        } else if self.rows.len() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.rows.len().saturating_sub(1));
+        }
+    }
+
+    fn refresh_rows(&mut self) {
+        if !self.group_headers.is_empty() {
+            // options of a group are listed together, under a single header,
+            // with the groups in the order of their best scored option.
+            let mut groups = vec![];
+            for idx in &self.scored_options {
+                let group = self.group_of(*idx);
+                if !groups.contains(&group) {
+                    groups.push(group);
+                }
+            }
+
+            let mut scored_options = std::mem::take(&mut self.scored_options);
+            scored_options.sort_by_key(|idx| {
+                let group = self.group_of(*idx);
+                groups.iter().position(|g| *g == group)
+            });
+            self.scored_options = scored_options;
+        }
+
+        let mut rows = vec![];
+        let mut last_group = None;
+        for idx in &self.scored_options {
+            let group = self.group_of(*idx);
+            if let Some(start) = group {
+                if last_group != group {
+                    rows.push(Row::Header(start));
+                }
+            }
+            last_group = group;
+
+            rows.push(Row::Option(*idx));
+        }
+
+        self.rows = rows;
+    }
+
+    /// Index of the first option of the group the option belongs to.
+    fn group_of(&self, index: usize) -> Option<usize> {
+        self.group_headers
+            .range(..=index)
+            .next_back()
+            .map(|(start, _)| *start)
+    }
+
+    fn group_selection(&self, start: usize) -> GroupSelection {
+        let end = self
+            .group_headers
+            .range(start + 1..)
+            .next()
+            .map(|(end, _)| *end)
+            .unwrap_or(self.options.len());
+        let checked = self.checked.range(start..end).count();
+
+        match checked {
+            0 => GroupSelection::None,
+            checked if checked == end - start => GroupSelection::All,
+            _ => GroupSelection::Partial,
+        }
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let new_position = if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.scored_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
@@ -115,13 +277,13 @@ where
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
-        if new_position >= self.scored_options.len() {
-            new_position = if self.scored_options.is_empty() {
+        if new_position >= self.rows.len() {
+            new_position = if self.rows.is_empty() {
                 0
             } else if wrap {
-                new_position % self.scored_options.len()
+                new_position % self.rows.len()
             } else {
-                self.scored_options.len().saturating_sub(1)
+                self.rows.len().saturating_sub(1)
             }
         }
 
@@ -138,58 +300,193 @@ where
     }
 
     fn toggle_cursor_selection(&mut self) -> ActionResult {
-        let idx = match self.scored_options.get(self.cursor_index) {
-            Some(val) => val,
+        let idx = match self.rows.get(self.cursor_index) {
+            Some(Row::Option(idx)) => *idx,
+            Some(Row::Header(start)) => return self.toggle_group(*start),
             None => return ActionResult::Clean,
         };
 
-        if self.checked.contains(idx) {
-            self.checked.remove(idx);
-        } else {
-            self.checked.insert(*idx);
+        if let Some(error) = self.disabled_option_error(idx) {
+            self.transient_error = Some(error);
+            return ActionResult::NeedsRedraw;
         }
 
-        if !self.config.keep_filter {
-            self.input.clear();
+        if self.checked.contains(&idx) {
+            self.uncheck(idx);
+        } else if let Some(error) = self.maximum_selection_error(self.checked.len() + 1) {
+            self.transient_error = Some(error);
+            return ActionResult::NeedsRedraw;
+        } else {
+            self.check(idx);
         }
 
+        // errors refer to the previous selection.
+        self.error = None;
+
+        self.clear_filter_unless_kept();
+
         ActionResult::NeedsRedraw
     }
 
-    fn validate_current_answer(&self) -> InquireResult<Validation> {
-        if let Some(validator) = &self.validator {
-            let selected_options = self
-                .options
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, opt)| match &self.checked.contains(&idx) {
-                    true => Some(ListOption::new(idx, opt)),
-                    false => None,
-                })
-                .collect::<Vec<_>>();
-
-            let res = validator.validate(&selected_options)?;
-            Ok(res)
+    /// Unselects the options of the group displayed by the filter when all of
+    /// them are selected, selecting them otherwise.
+    fn toggle_group(&mut self, start: usize) -> ActionResult {
+        let options = self
+            .enabled_scored_options()
+            .into_iter()
+            .filter(|idx| self.group_of(*idx) == Some(start))
+            .collect::<Vec<usize>>();
+
+        self.error = None;
+
+        if options.iter().all(|idx| self.checked.contains(idx)) {
+            for idx in options {
+                self.uncheck(idx);
+            }
         } else {
-            Ok(Validation::Valid)
+            let unchecked = options
+                .into_iter()
+                .filter(|idx| !self.checked.contains(idx))
+                .collect();
+            self.check_up_to_maximum(unchecked);
         }
+
+        self.clear_filter_unless_kept();
+
+        ActionResult::NeedsRedraw
     }
 
-    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
-        let mut answer = vec![];
+    /// Moves the cursor to the clicked option, or toggles its selection when
+    /// it is already highlighted.
+    fn click_option(&mut self, index: usize) -> ActionResult {
+        match self.rows.iter().position(|row| *row == Row::Option(index)) {
+            Some(position) if position == self.cursor_index => self.toggle_cursor_selection(),
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn disabled_option_error(&self, index: usize) -> Option<ErrorMessage> {
+        let reason = self.disabled_options.get(&index)?;
+
+        Some(ErrorMessage::Custom(
+            reason.unwrap_or(DISABLED_OPTION_ERROR_MESSAGE).to_string(),
+        ))
+    }
+
+    /// Options displayed by the filter whose selection can be changed.
+    fn enabled_scored_options(&self) -> Vec<usize> {
+        self.scored_options
+            .iter()
+            .filter(|idx| !self.disabled_options.contains_key(idx))
+            .copied()
+            .collect()
+    }
+
+    /// Error displayed when `count` selected options go beyond the maximum selection.
+    fn maximum_selection_error(&self, count: usize) -> Option<ErrorMessage> {
+        match self.maximum_selection {
+            Some(maximum) if count > maximum => Some(ErrorMessage::Custom(format!(
+                "Please select at most {}",
+                options_count(maximum)
+            ))),
+            _ => None,
+        }
+    }
+
+    /// Clears the filter input after a selection change, unless configured to
+    /// keep it, leaving the cursor on the option it was on.
+    fn clear_filter_unless_kept(&mut self) {
+        if self.config.keep_filter || self.input.content().is_empty() {
+            return;
+        }
+
+        let current = self.rows.get(self.cursor_index).copied();
+
+        self.input.clear();
+        self.refresh_scored_options();
+
+        if let Some(position) = current.and_then(|c| self.rows.iter().position(|row| *row == c)) {
+            self.cursor_index = position;
+        }
+    }
+
+    fn check(&mut self, idx: usize) {
+        if self.checked.insert(idx) {
+            self.toggle_order.push(idx);
+        }
+    }
+
+    fn uncheck(&mut self, idx: usize) {
+        if self.checked.remove(&idx) {
+            self.toggle_order.retain(|i| *i != idx);
+        }
+    }
+
+    /// Indices of the checked options, in the configured selection order.
+    fn selected_indices(&self) -> Vec<usize> {
+        match self.selection_order {
+            SelectionOrder::ListOrder => self.checked.iter().copied().collect(),
+            SelectionOrder::ToggleOrder => self.toggle_order.clone(),
+        }
+    }
+
+    /// Checks the given options in order, stopping with a note once the maximum
+    /// selection is reached.
+    fn check_up_to_maximum(&mut self, options: Vec<usize>) {
+        for idx in options {
+            match self.maximum_selection {
+                Some(maximum) if self.checked.len() >= maximum => {
+                    self.transient_error = Some(ErrorMessage::Custom(format!(
+                        "Selected up to the maximum of {}",
+                        options_count(maximum)
+                    )));
+                    break;
+                }
+                _ => self.check(idx),
+            }
+        }
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if self.checked.len() < self.minimum_selection {
+            return Ok(Validation::Invalid(ErrorMessage::Custom(format!(
+                "Please select at least {}",
+                options_count(self.minimum_selection)
+            ))));
+        }
 
-        // by iterating in descending order, we can safely
-        // swap remove because the elements to the right
-        // that we did not remove will not matter anymore.
-        for index in self.checked.iter().rev() {
-            let index = *index;
-            let value = self.options.swap_remove(index);
-            let lo = ListOption::new(index, value);
-            answer.push(lo);
+        if let Some(error) = self.maximum_selection_error(self.checked.len()) {
+            return Ok(Validation::Invalid(error));
         }
-        answer.reverse();
 
-        answer
+        let selected_options = self
+            .selected_indices()
+            .into_iter()
+            .map(|idx| ListOption::new(idx, &self.options[idx]))
+            .collect::<Vec<_>>();
+
+        for validator in &self.validators {
+            match validator.validate(&selected_options)? {
+                Validation::Valid => {}
+                Validation::Invalid(msg) => return Ok(Validation::Invalid(msg)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        let selected = self.selected_indices();
+        let mut options = std::mem::take(&mut self.options)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<Option<T>>>();
+
+        selected
+            .into_iter()
+            .filter_map(|idx| options[idx].take().map(|value| ListOption::new(idx, value)))
+            .collect()
     }
 }
 
@@ -212,10 +509,22 @@ where
 
     fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
         let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
-        (self.formatter)(&refs)
+        let filter = self.input.content();
+
+        if let Some(formatter) = self.filtered_formatter {
+            return formatter(&refs, filter);
+        }
+
+        let formatted = (self.formatter)(&refs);
+        match self.show_filter_in_answer && !filter.is_empty() {
+            true => format!("{formatted} (filtered: \"{filter}\")"),
+            false => formatted,
+        }
     }
 
     fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        self.transient_error = None;
+
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.get_final_answer()),
             Validation::Invalid(msg) => {
@@ -227,35 +536,83 @@ where
         Ok(answer)
     }
 
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if !self.config.vim_filtering {
+            return Ok(true);
+        }
+
+        // in vim mode, the first press leaves the filter input.
+        self.config.vim_filtering = false;
+        self.input.clear();
+        self.refresh_scored_options();
+
+        Ok(false)
+    }
+
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
+        // transient errors are cleared on the next key press.
+        let had_transient_error = self.transient_error.take().is_some();
+
         let result = match action {
-            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
             MultiSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
             MultiSelectPromptAction::PageDown => {
                 self.move_cursor_down(self.config.page_size, false)
             }
+            MultiSelectPromptAction::HalfPageUp => {
+                self.move_cursor_up((self.config.page_size / 2).max(1), false)
+            }
+            MultiSelectPromptAction::HalfPageDown => {
+                self.move_cursor_down((self.config.page_size / 2).max(1), false)
+            }
+            MultiSelectPromptAction::StartFiltering => {
+                self.config.vim_filtering = true;
+                ActionResult::NeedsRedraw
+            }
             MultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             MultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
+            // clicks are resolved in `handle_with_backend`, which knows where
+            // the options were rendered.
+            MultiSelectPromptAction::Click { .. } => ActionResult::Clean,
             MultiSelectPromptAction::SelectAll => {
-                self.checked.clear();
-                for idx in &self.scored_options {
-                    self.checked.insert(*idx);
-                }
+                self.error = None;
 
-                if !self.config.keep_filter {
-                    self.input.clear();
-                }
+                let unchecked = self
+                    .enabled_scored_options()
+                    .into_iter()
+                    .filter(|idx| !self.checked.contains(idx))
+                    .collect::<Vec<usize>>();
+                self.check_up_to_maximum(unchecked);
+
+                self.clear_filter_unless_kept();
 
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::ClearSelections => {
-                self.checked.clear();
+                for idx in self.enabled_scored_options() {
+                    self.uncheck(idx);
+                }
+                self.error = None;
+
+                self.clear_filter_unless_kept();
 
-                if !self.config.keep_filter {
-                    self.input.clear();
+                ActionResult::NeedsRedraw
+            }
+            MultiSelectPromptAction::InvertSelection => {
+                self.error = None;
+
+                let (checked, unchecked): (Vec<usize>, Vec<usize>) = self
+                    .enabled_scored_options()
+                    .into_iter()
+                    .partition(|idx| self.checked.contains(idx));
+                for idx in checked {
+                    self.uncheck(idx);
                 }
+                self.check_up_to_maximum(unchecked);
+
+                self.clear_filter_unless_kept();
 
                 ActionResult::NeedsRedraw
             }
@@ -263,44 +620,106 @@ where
                 let result = self.input.handle(input_action);
 
                 if let InputActionResult::ContentChanged = result {
-                    let mut options = self.score_options();
-                    options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
-
-                    self.scored_options = options.into_iter().map(|(idx, _)| idx).collect();
-                    if self.config.reset_cursor {
-                        let _ = self.update_cursor_position(0);
-                    } else if self.scored_options.len() <= self.cursor_index {
-                        let _ = self
-                            .update_cursor_position(self.scored_options.len().saturating_sub(1));
-                    }
+                    self.refresh_scored_options();
                 }
 
                 result.into()
             }
         };
 
-        Ok(result)
+        match result {
+            ActionResult::Clean if had_transient_error => Ok(ActionResult::NeedsRedraw),
+            result => Ok(result),
+        }
+    }
+
+    fn handle_with_backend(
+        &mut self,
+        action: MultiSelectPromptAction,
+        backend: &mut Backend,
+    ) -> InquireResult<ActionResult> {
+        match action {
+            MultiSelectPromptAction::Click { column, row } => {
+                let had_transient_error = self.transient_error.take().is_some();
+
+                let result = match backend.option_at(column, row)? {
+                    Some(index) => self.click_option(index),
+                    None => ActionResult::Clean,
+                };
+
+                match result {
+                    ActionResult::Clean if had_transient_error => Ok(ActionResult::NeedsRedraw),
+                    result => Ok(result),
+                }
+            }
+            _ => Prompt::<Backend>::handle(self, action),
+        }
     }
 
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        if let Some(err) = &self.error {
+        if let Some(err) = self.transient_error.as_ref().or(self.error.as_ref()) {
             backend.render_error_message(err)?;
         }
 
-        backend.render_multiselect_prompt(prompt, &self.input)?;
+        let selected_counter = self.config.selected_counter.then(|| {
+            let total = self.maximum_selection.unwrap_or(self.options.len());
+            (self.checked.len(), total)
+        });
 
-        let choices = self
-            .scored_options
+        backend.render_multiselect_prompt(prompt, &self.input, selected_counter)?;
+
+        let positions = paginate(self.config.page_size, &self.rows, Some(self.cursor_index));
+
+        let rows = positions
+            .content
+            .iter()
+            .map(|row| match row {
+                Row::Header(start) => OptionRow::Header(self.group_headers[start]),
+                Row::Option(i) => OptionRow::Option(ListOption::new(*i, &self.options[*i])),
+            })
+            .collect::<Vec<OptionRow<'_, &T>>>();
+        let group_selections = positions
+            .content
             .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|row| match row {
+                Row::Header(start) => Some(self.group_selection(*start)),
+                Row::Option(_) => None,
+            })
+            .collect::<Vec<Option<GroupSelection>>>();
+
+        let page = Page {
+            first: positions.first,
+            last: positions.last,
+            content: &rows,
+            start: positions.start,
+            cursor: positions.cursor,
+            total: positions.total,
+        };
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        let disabled = self.disabled_options.keys().copied().collect();
 
-        backend.render_options(page, &self.checked)?;
+        backend.render_options(page, &self.checked, &disabled, &group_selections)?;
+
+        if self.config.page_indicator && (!positions.first || !positions.last) {
+            // headers are not counted, so the range refers to options only.
+            let is_option = |row: &&Row| matches!(row, Row::Option(_));
+            let before = self.rows[..positions.start]
+                .iter()
+                .filter(is_option)
+                .count();
+            let displayed = positions.content.iter().filter(is_option).count();
+
+            // a page showing only headers has no range of options to refer to.
+            if displayed > 0 {
+                backend.render_page_indicator(
+                    before + 1,
+                    before + displayed,
+                    self.scored_options.len(),
+                )?;
+            }
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -309,3 +728,10 @@ where
         Ok(())
     }
 }
+
+fn options_count(count: usize) -> String {
+    match count {
+        1 => String::from("1 option"),
+        count => format!("{count} options"),
+    }
+}
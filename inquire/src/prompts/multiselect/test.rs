@@ -1,11 +1,14 @@
 use crate::{
-    formatter::MultiOptionFormatter,
+    formatter::{first_selected_and_count, selected_count, MultiOptionFormatter},
     list_option::ListOption,
     terminal::crossterm::CrosstermTerminal,
     ui::{Backend, RenderConfig},
-    MultiSelect,
+    validator::{MultiOptionValidator, Validation},
+    CaseMatching, InquireError, MultiSelect, SelectionOrder,
+};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
 };
-use crossterm::event::{KeyCode, KeyEvent};
 
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
@@ -129,3 +132,1262 @@ fn list_option_indexes_are_relative_to_input_vec() {
 
     assert_eq!(vec![ListOption::new(1, 2), ListOption::new(2, 3)], ans);
 }
+
+#[test]
+fn scroll_indicators_are_displayed_when_options_do_not_fit() {
+    let read: Vec<KeyEvent> = vec![KeyCode::End, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..10)
+        .map(|i| format!("item {i}"))
+        .collect::<Vec<String>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        MultiSelect::new("Item:", options)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    // first page, with options below it.
+    assert!(output.contains("v [ ] item 2"));
+    // last page, with options above it.
+    assert!(output.contains("^ [ ] item 7"));
+}
+
+#[test]
+fn scroll_indicators_are_hidden_when_all_options_fit() {
+    let read: Vec<KeyEvent> = vec![KeyCode::End, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..3).map(|i| format!("item {i}")).collect::<Vec<String>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        MultiSelect::new("Item:", options)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(!output.contains("v item"));
+    assert!(!output.contains("^ item"));
+}
+
+#[test]
+fn up_from_the_first_option_wraps_to_the_last_when_wrap_around_is_enabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Char(' '), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        MultiSelect::new("Number:", options)
+            .with_wrap_around(true)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(vec![ListOption::new(19, 19)], ans);
+
+    // the last page is displayed along with the cursor.
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("> [x] 19"));
+}
+
+#[test]
+fn up_from_the_first_option_stays_when_wrap_around_is_disabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Char(' '), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = MultiSelect::new("Number:", options)
+        .with_wrap_around(false)
+        .with_page_size(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 0)], ans);
+}
+
+#[test]
+fn typing_j_filters_when_vim_mode_is_disabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('j'), KeyCode::Char(' '), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "jackfruit"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Fruits:", options)
+        .with_vim_mode(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "jackfruit")], ans);
+}
+
+#[test]
+fn vim_mode_navigates_and_filters_after_slash() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('G'),
+        KeyCode::Char(' '),
+        KeyCode::Char('g'),
+        KeyCode::Char(' '),
+        KeyCode::Char('/'),
+        KeyCode::Char('b'),
+        KeyCode::Char(' '),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "jackfruit"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Fruits:", options)
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "apple"),
+            ListOption::new(1, "banana"),
+            ListOption::new(2, "jackfruit")
+        ],
+        ans
+    );
+}
+
+#[test]
+fn page_keys_stop_at_the_ends_of_the_filtered_list() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('1'),
+        KeyCode::PageDown,
+        KeyCode::PageDown,
+        KeyCode::PageDown,
+        KeyCode::Char(' '),
+        KeyCode::PageUp,
+        KeyCode::PageUp,
+        KeyCode::PageUp,
+        KeyCode::Char(' '),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Number:", options)
+        .with_wrap_around(true)
+        .with_page_size(5)
+        .with_scorer(&|input, _option, string_value, _idx| {
+            string_value.contains(input).then_some(0)
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 1), ListOption::new(19, 19)], ans);
+}
+
+#[test]
+fn home_and_end_move_to_the_ends_of_the_filtered_list() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('1'),
+        KeyCode::End,
+        KeyCode::Char(' '),
+        KeyCode::Home,
+        KeyCode::Char(' '),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Number:", options)
+        .with_scorer(&|input, _option, string_value, _idx| {
+            string_value.contains(input).then_some(0)
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 1), ListOption::new(19, 19)], ans);
+}
+
+#[test]
+fn options_can_be_any_iterable() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut prices = std::collections::BTreeMap::new();
+    prices.insert("apple", 3);
+    prices.insert("banana", 2);
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Fruits:", prices.keys())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, &"banana")], ans);
+}
+
+#[test]
+fn empty_options_are_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Option:", Vec::<&str>::new()).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn zero_page_size_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Option:", vec!["a", "b"])
+        .with_page_size(0)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn out_of_range_starting_cursor_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Option:", vec!["a", "b"])
+        .with_starting_cursor(2)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn out_of_range_default_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Option:", vec!["a", "b"])
+        .with_default(&[0, 2])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn filter_text_is_displayed_along_with_the_answer_when_enabled() {
+    let read: Vec<KeyEvent> = "frank"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(vec![KeyCode::Char(' '), KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        MultiSelect::new("Hosts:", vec!["web-amsterdam-01", "web-frankfurt-03"])
+            .with_filter_in_answer(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Hosts: web-frankfurt-03 (filtered: \"frank\")"));
+}
+
+#[test]
+fn filtered_formatter_receives_the_filter_text() {
+    let read: Vec<KeyEvent> = "web"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(vec![KeyCode::Right, KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        MultiSelect::new("Hosts:", vec!["web-amsterdam-01", "web-frankfurt-03"])
+            .with_filtered_formatter(&|options, filter| {
+                format!("{} hosts matching {}", options.len(), filter)
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Hosts: 2 hosts matching web"));
+}
+
+fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn click_on_highlighted_option_toggles_it() {
+    let scroll_up = Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollUp,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+    });
+
+    // the prompt is rendered on the first row, followed by the options.
+    let events = vec![
+        click(6, 2),
+        click(6, 2),
+        click(6, 3),
+        scroll_up.clone(),
+        scroll_up,
+        Event::Key(KeyEvent::from(KeyCode::Char(' '))),
+        Event::Key(KeyEvent::from(KeyCode::Enter)),
+    ];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_events(&mut write, Box::new(events.into_iter()));
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Fruits:", vec!["apple", "banana", "cherry"])
+        .with_mouse(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "apple"), ListOption::new(1, "banana")],
+        ans
+    );
+}
+
+#[test]
+fn case_sensitive_filter() {
+    let read: Vec<KeyEvent> = "ngin"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(vec![KeyCode::Char(' '), KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Pods:", vec!["pod/Nginx", "pod/nginx"])
+        .with_filter_case(CaseMatching::Sensitive)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "pod/nginx")], ans);
+}
+
+fn prompt_features(
+    select: MultiSelect<'static, &'static str>,
+    keys: Vec<KeyCode>,
+) -> Vec<ListOption<&'static str>> {
+    let read: Vec<KeyEvent> = keys.into_iter().map(KeyEvent::from).collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    select.prompt_with_backend(&mut backend).unwrap()
+}
+
+fn features() -> MultiSelect<'static, &'static str> {
+    MultiSelect::new("Features:", vec!["auth", "metrics", "cache", "tracing"])
+}
+
+#[test]
+fn submitting_right_away_returns_the_defaults() {
+    let ans = prompt_features(features().with_default(&[3, 1, 3]), vec![KeyCode::Enter]);
+
+    assert_eq!(
+        vec![ListOption::new(1, "metrics"), ListOption::new(3, "tracing")],
+        ans
+    );
+}
+
+#[test]
+fn toggling_a_default_off_and_on_keeps_it_selected() {
+    let untouched = prompt_features(features().with_default(&[1, 3]), vec![KeyCode::Enter]);
+    let toggled = prompt_features(
+        features().with_default(&[1, 3]),
+        vec![
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(untouched, toggled);
+}
+
+#[test]
+fn default_values_are_selected() {
+    let ans = prompt_features(
+        features().with_default_values(&["tracing", "metrics", "unknown"]),
+        vec![KeyCode::Enter],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(1, "metrics"), ListOption::new(3, "tracing")],
+        ans
+    );
+}
+
+#[test]
+fn default_predicate_selects_matching_options() {
+    let ans = prompt_features(
+        features().with_default_by(|feature| feature.starts_with('c')),
+        vec![KeyCode::Enter],
+    );
+
+    assert_eq!(vec![ListOption::new(2, "cache")], ans);
+}
+
+fn prompt_toppings(
+    select: MultiSelect<'static, &'static str>,
+    keys: Vec<KeyCode>,
+) -> (Vec<ListOption<&'static str>>, String) {
+    let read: Vec<KeyEvent> = keys.into_iter().map(KeyEvent::from).collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        select.prompt_with_backend(&mut backend).unwrap()
+    };
+
+    (ans, String::from_utf8(write).unwrap())
+}
+
+fn toppings() -> MultiSelect<'static, &'static str> {
+    MultiSelect::new("Toppings:", vec!["cheese", "ham", "olives", "onion"])
+}
+
+#[test]
+fn selecting_beyond_the_maximum_is_rejected() {
+    let (ans, output) = prompt_toppings(
+        toppings().with_maximum_selection(2),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(0, "cheese"), ListOption::new(1, "ham")],
+        ans
+    );
+    assert!(output.contains("Please select at most 2 options"));
+}
+
+#[test]
+fn unselecting_at_the_maximum_allows_selecting_another_option() {
+    let (ans, _) = prompt_toppings(
+        toppings().with_maximum_selection(2),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Up,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(0, "cheese"), ListOption::new(2, "olives")],
+        ans
+    );
+}
+
+#[test]
+fn selecting_all_stops_at_the_maximum() {
+    let (ans, output) = prompt_toppings(
+        toppings().with_default(&[2]).with_maximum_selection(3),
+        vec![KeyCode::Right, KeyCode::Enter],
+    );
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "cheese"),
+            ListOption::new(1, "ham"),
+            ListOption::new(2, "olives")
+        ],
+        ans
+    );
+    assert!(output.contains("Selected up to the maximum of 3 options"));
+}
+
+#[test]
+fn submitting_below_the_minimum_stays_in_the_prompt() {
+    let (ans, output) = prompt_toppings(
+        toppings().with_minimum_selection(1),
+        vec![
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(vec![ListOption::new(1, "ham")], ans);
+    assert!(output.contains("Please select at least 1 option"));
+}
+
+#[test]
+fn defaults_beyond_the_maximum_must_be_unselected() {
+    let (ans, output) = prompt_toppings(
+        toppings()
+            .with_default(&[0, 1, 2])
+            .with_minimum_selection(1)
+            .with_maximum_selection(2),
+        vec![KeyCode::Enter, KeyCode::Char(' '), KeyCode::Enter],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(1, "ham"), ListOption::new(2, "olives")],
+        ans
+    );
+    assert!(output.contains("Please select at most 2 options"));
+}
+
+#[test]
+fn minimum_selection_greater_than_maximum_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = toppings()
+        .with_minimum_selection(3)
+        .with_maximum_selection(2)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+fn typed(filter: &str, shortcut: char) -> Vec<KeyEvent> {
+    filter
+        .chars()
+        .map(|c| KeyEvent::from(KeyCode::Char(c)))
+        .chain(vec![
+            KeyEvent::new(KeyCode::Char(shortcut), KeyModifiers::CONTROL),
+            KeyEvent::from(KeyCode::Enter),
+        ])
+        .collect()
+}
+
+#[test]
+fn selecting_all_only_adds_the_displayed_options() {
+    let read = [vec![KeyEvent::from(KeyCode::Char(' '))], typed("on", 'a')].concat();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = toppings().prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "cheese"), ListOption::new(3, "onion")],
+        ans
+    );
+}
+
+#[test]
+fn clearing_all_only_removes_the_displayed_options() {
+    let read = typed("on", 'r');
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = toppings()
+        .with_all_selected_by_default()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "cheese"),
+            ListOption::new(1, "ham"),
+            ListOption::new(2, "olives")
+        ],
+        ans
+    );
+}
+
+#[test]
+fn inverting_returns_the_complement_of_the_selection() {
+    let (ans, _) = prompt_toppings(
+        toppings(),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Tab,
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(1, "ham"), ListOption::new(3, "onion")],
+        ans
+    );
+}
+
+#[test]
+fn inverting_only_flips_the_displayed_options() {
+    let read = typed("on", 'i');
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = toppings()
+        .with_default(&[0, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "cheese")], ans);
+}
+
+#[test]
+fn inverting_stops_at_the_maximum() {
+    let (ans, output) = prompt_toppings(
+        toppings().with_default(&[0]).with_maximum_selection(2),
+        vec![KeyCode::Tab, KeyCode::Enter],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(1, "ham"), ListOption::new(2, "olives")],
+        ans
+    );
+    assert!(output.contains("Selected up to the maximum of 2 options"));
+}
+
+#[test]
+fn selected_counter_counts_options_of_every_page() {
+    let (_, output) = prompt_toppings(
+        toppings().with_default(&[0]).with_page_size(2),
+        vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert!(output.contains("[1/4 selected]"));
+    assert!(output.contains("[2/4 selected]"));
+}
+
+#[test]
+fn selected_counter_is_out_of_the_maximum_selection() {
+    let (_, output) = prompt_toppings(
+        toppings().with_maximum_selection(3),
+        vec![KeyCode::Char(' '), KeyCode::Enter],
+    );
+
+    assert!(output.contains("[0/3 selected]"));
+    assert!(output.contains("[1/3 selected]"));
+}
+
+#[test]
+fn selected_counter_can_be_disabled() {
+    let (_, output) = prompt_toppings(
+        toppings().with_selected_counter(false),
+        vec![KeyCode::Char(' '), KeyCode::Enter],
+    );
+
+    assert!(!output.contains("selected]"));
+}
+
+fn services() -> MultiSelect<'static, &'static str> {
+    MultiSelect::new("Services:", vec!["api-a", "web", "api-b", "db", "api-c"])
+}
+
+fn filtered(filter: &str, keys: Vec<KeyCode>) -> Vec<KeyCode> {
+    filter.chars().map(KeyCode::Char).chain(keys).collect()
+}
+
+#[test]
+fn filter_is_kept_after_toggling_by_default() {
+    let keys = filtered(
+        "api",
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Enter,
+        ],
+    );
+    let (ans, _) = prompt_toppings(services(), keys);
+
+    assert_eq!(
+        vec![ListOption::new(0, "api-a"), ListOption::new(2, "api-b")],
+        ans
+    );
+}
+
+#[test]
+fn cleared_filter_keeps_the_cursor_on_the_toggled_option() {
+    let keys = filtered(
+        "api",
+        vec![
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+    let (ans, _) = prompt_toppings(services().with_keep_filter(false), keys);
+
+    assert_eq!(
+        vec![ListOption::new(2, "api-b"), ListOption::new(3, "db")],
+        ans
+    );
+}
+
+#[derive(Debug, PartialEq)]
+struct Feature {
+    name: String,
+}
+
+impl std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[test]
+fn owned_options_are_moved_out_in_list_order() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Char(' '),
+        KeyCode::Up,
+        KeyCode::Up,
+        KeyCode::Char(' '),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let features = vec!["auth", "metrics", "cache"]
+        .into_iter()
+        .map(|name| Feature {
+            name: name.to_string(),
+        })
+        .collect::<Vec<Feature>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        MultiSelect::new("Features:", features)
+            .with_formatter(&|selected| format!("{} features", selected.len()))
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    let expected = vec![
+        ListOption::new(
+            0,
+            Feature {
+                name: "auth".into(),
+            },
+        ),
+        ListOption::new(
+            2,
+            Feature {
+                name: "cache".into(),
+            },
+        ),
+    ];
+    assert_eq!(expected, ans);
+    assert!(String::from_utf8(write).unwrap().contains("2 features"));
+}
+
+#[test]
+fn selections_keep_their_original_index_and_order_when_sorted() {
+    // lists the matching options in reverse order.
+    let scorer = &|input: &str, _: &&str, value: &str, idx: usize| -> Option<i64> {
+        value.contains(input).then_some(idx as i64)
+    };
+
+    let keys = filtered(
+        "api",
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+    let (ans, _) = prompt_toppings(services().with_scorer(scorer), keys);
+
+    assert_eq!(
+        vec![ListOption::new(0, "api-a"), ListOption::new(4, "api-c")],
+        ans
+    );
+}
+
+fn letters() -> MultiSelect<'static, &'static str> {
+    MultiSelect::new("Letters:", vec!["A", "B", "C"])
+}
+
+/// Selects C, then A, then B.
+fn toggling_c_a_b() -> Vec<KeyCode> {
+    vec![
+        KeyCode::Up,
+        KeyCode::Char(' '),
+        KeyCode::Down,
+        KeyCode::Char(' '),
+        KeyCode::Down,
+        KeyCode::Char(' '),
+        KeyCode::Enter,
+    ]
+}
+
+#[test]
+fn selections_are_returned_in_list_order_by_default() {
+    let (ans, output) = prompt_toppings(letters(), toggling_c_a_b());
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "A"),
+            ListOption::new(1, "B"),
+            ListOption::new(2, "C")
+        ],
+        ans
+    );
+    assert!(output.contains("A, B, C"));
+}
+
+#[test]
+fn selections_can_be_returned_in_toggle_order() {
+    let (ans, output) = prompt_toppings(
+        letters().with_selection_order(SelectionOrder::ToggleOrder),
+        toggling_c_a_b(),
+    );
+
+    assert_eq!(
+        vec![
+            ListOption::new(2, "C"),
+            ListOption::new(0, "A"),
+            ListOption::new(1, "B")
+        ],
+        ans
+    );
+    assert!(output.contains("C, A, B"));
+}
+
+#[test]
+fn selecting_again_moves_an_option_to_the_end_of_the_toggle_order() {
+    let (ans, _) = prompt_toppings(
+        letters()
+            .with_default(&[1, 0])
+            .with_selection_order(SelectionOrder::ToggleOrder),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "B"),
+            ListOption::new(0, "A"),
+            ListOption::new(2, "C")
+        ],
+        ans
+    );
+}
+
+#[test]
+fn validators_run_in_order_and_keep_the_selections() {
+    let not_empty = |selected: &[ListOption<&&str>]| match selected.is_empty() {
+        true => Ok(Validation::Invalid("Select a plan".into())),
+        false => Ok(Validation::Valid),
+    };
+    let custom_alone = |selected: &[ListOption<&&str>]| match selected.len() > 1
+        && selected.iter().any(|o| *o.value == "custom")
+    {
+        true => Ok(Validation::Invalid(
+            "Custom plans can not be combined".into(),
+        )),
+        false => Ok(Validation::Valid),
+    };
+    let validators: Vec<Box<dyn MultiOptionValidator<&str>>> =
+        vec![Box::new(not_empty), Box::new(custom_alone)];
+
+    let (ans, output) = prompt_toppings(
+        MultiSelect::new("Plan:", vec!["storage", "backups", "custom"])
+            .with_validators(&validators),
+        vec![
+            KeyCode::Enter,
+            KeyCode::Char(' '),
+            KeyCode::Up,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(vec![ListOption::new(2, "custom")], ans);
+    assert!(output.contains("Select a plan"));
+    assert!(output.contains("Custom plans can not be combined"));
+}
+
+fn plan_features() -> MultiSelect<'static, &'static str> {
+    MultiSelect::new("Features:", vec!["backups", "metrics", "sso"])
+        .with_default(&[0])
+        .with_disabled_option(0, "Backups are included in your plan")
+        .with_disabled_indices(&[2])
+}
+
+#[test]
+fn disabled_options_can_not_be_toggled() {
+    let (ans, output) = prompt_toppings(
+        plan_features(),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(0, "backups"), ListOption::new(1, "metrics")],
+        ans
+    );
+    assert!(output.contains("Backups are included in your plan"));
+    assert!(output.contains("This option can not be changed"));
+    assert!(output.contains("backups 🔒"));
+}
+
+#[test]
+fn selecting_clearing_and_inverting_all_skip_disabled_options() {
+    let select_all = prompt_toppings(plan_features(), vec![KeyCode::Right, KeyCode::Enter]).0;
+    let clear_all = prompt_toppings(plan_features(), vec![KeyCode::Left, KeyCode::Enter]).0;
+    let invert = prompt_toppings(plan_features(), vec![KeyCode::Tab, KeyCode::Enter]).0;
+
+    let with_metrics = vec![ListOption::new(0, "backups"), ListOption::new(1, "metrics")];
+    assert_eq!(with_metrics, select_all);
+    assert_eq!(vec![ListOption::new(0, "backups")], clear_all);
+    assert_eq!(with_metrics, invert);
+}
+
+#[test]
+fn refused_selection_message_disappears_on_the_next_key_press() {
+    let (ans, output) = prompt_toppings(
+        toppings().with_maximum_selection(2),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(0, "cheese"), ListOption::new(1, "ham")],
+        ans
+    );
+    assert_eq!(1, output.matches("Please select at most 2 options").count());
+}
+
+#[test]
+fn unselecting_an_option_at_the_maximum_allows_a_new_selection() {
+    let (ans, _) = prompt_toppings(
+        toppings().with_maximum_selection(2),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Up,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(
+        vec![ListOption::new(0, "cheese"), ListOption::new(2, "olives")],
+        ans
+    );
+}
+
+#[test]
+fn submitting_replaces_the_refused_selection_message_with_the_validation_error() {
+    let (_, output) = prompt_toppings(
+        toppings()
+            .with_default(&[0])
+            .with_disabled_indices(&[0])
+            .with_minimum_selection(2),
+        vec![
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+
+    assert_eq!(1, output.matches("This option can not be changed").count());
+    assert!(output.contains("Please select at least 2 options"));
+}
+
+#[test]
+fn summary_formatters_are_used_for_empty_and_long_selections() {
+    let (_, output) = prompt_toppings(
+        toppings().with_formatter(&selected_count),
+        vec![KeyCode::Enter],
+    );
+    assert!(output.contains("Toppings: None"));
+
+    let read: Vec<KeyEvent> = vec![KeyCode::Right, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let formatter = first_selected_and_count(2);
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        toppings()
+            .with_formatter(&formatter)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Toppings: cheese, ham and 2 more"));
+}
+
+fn hosts() -> MultiSelect<'static, &'static str> {
+    MultiSelect::new_grouped(
+        "Hosts:",
+        vec![
+            ("Production", vec!["prod-1", "prod-2"]),
+            ("Staging", vec!["staging-1", "staging-2"]),
+        ],
+    )
+}
+
+#[test]
+fn space_on_a_group_header_toggles_the_whole_group() {
+    let (ans, _) = prompt_toppings(
+        hosts(),
+        vec![KeyCode::Up, KeyCode::Char(' '), KeyCode::Enter],
+    );
+    assert_eq!(
+        vec![ListOption::new(0, "prod-1"), ListOption::new(1, "prod-2")],
+        ans
+    );
+
+    let (ans, _) = prompt_toppings(
+        hosts().with_default(&[2]),
+        vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+    assert_eq!(Vec::<ListOption<&str>>::new(), ans);
+}
+
+#[test]
+fn partially_selected_groups_are_marked_on_their_header() {
+    let (_, output) = prompt_toppings(hosts(), vec![KeyCode::Char(' '), KeyCode::Enter]);
+
+    assert!(output.contains("[~] Production"));
+    assert!(output.contains("[ ] Staging"));
+}
+
+#[test]
+fn group_headers_are_kept_above_matching_options_while_filtering() {
+    let (ans, _) = prompt_toppings(
+        hosts(),
+        filtered(
+            "prod",
+            vec![KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter],
+        ),
+    );
+
+    assert_eq!(vec![ListOption::new(0, "prod-1")], ans);
+}
+
+#[test]
+fn page_indicator_follows_the_scrolled_window() {
+    let (_, output) = prompt_toppings(
+        toppings().with_page_size(2),
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+    );
+
+    assert!(output.contains("items 1–2 of 4"));
+    assert!(output.contains("items 2–3 of 4"));
+}
+
+#[test]
+fn page_indicator_is_hidden_on_pages_showing_only_headers() {
+    let (_, output) = prompt_toppings(
+        hosts().with_page_size(1),
+        vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+    );
+
+    assert!(output.contains("items 2–2 of 4"));
+    assert!(output.contains("Staging"));
+    assert!(!output.contains("items 3–2 of 4"));
+}
+
+#[test]
+fn page_indicator_is_hidden_when_disabled_or_on_a_single_page() {
+    let (_, output) = prompt_toppings(
+        toppings().with_page_size(2).with_page_indicator(false),
+        vec![KeyCode::Enter],
+    );
+    assert!(!output.contains("items "));
+
+    let (_, output) = prompt_toppings(toppings(), vec![KeyCode::Enter]);
+    assert!(!output.contains("items "));
+}
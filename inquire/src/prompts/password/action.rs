@@ -10,7 +10,7 @@ use super::config::PasswordConfig;
 pub enum PasswordPromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
-    /// Toggles the display mode between plain text and the initial one.
+    /// Cycles the display mode between hidden, masked and plain text.
     ToggleDisplayMode,
 }
 
@@ -24,6 +24,9 @@ impl InnerAction for PasswordPromptAction {
             {
                 Self::ToggleDisplayMode
             }
+            // pasted content, e.g. from password managers, often ends with a new line,
+            // which must neither submit the prompt nor become part of the password.
+            Key::Char(c, _) if c.is_control() => return None,
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::ValueInput(action),
                 None => return None,
@@ -1,24 +1,37 @@
 mod action;
 mod config;
 mod prompt;
+mod strength;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use strength::PasswordStrength;
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
+    terminal::{get_default_terminal, line::LineTerminal, no_echo, stdin_is_tty},
+    type_aliases::PasswordStrengthFn,
     ui::{Backend, PasswordBackend, RenderConfig},
-    validator::StringValidator,
+    validator::{ErrorMessage, StringValidator},
 };
 
+#[cfg(feature = "async")]
+use crate::validator::AsyncStringValidator;
+
+#[cfg(feature = "secrecy")]
+use secrecy::SecretString;
+
+use std::io::{stderr, Write};
+
 use self::prompt::PasswordPrompt;
 
+const DEFAULT_HELP_MESSAGE_WITH_TOGGLE: &str = "ctrl+r to toggle display";
+
 /// Display modes of the text input of a password prompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PasswordDisplayMode {
@@ -26,8 +39,8 @@ pub enum PasswordDisplayMode {
     Hidden,
 
     /// Characters of the password text input are rendered marked as different
-    /// characters, such as asterisks. These characters are configured in the
-    /// render config.
+    /// characters, such as asterisks, one per grapheme. These characters are
+    /// configured in the render config.
     Masked,
 
     /// Password text input is fully rendered as a normal input, just like
@@ -42,19 +55,33 @@ pub enum PasswordDisplayMode {
 /// However, you can still customize these and other behaviors if you wish:
 /// - **Standard display mode**: Set the display mode of the text input among hidden, masked and full via the `PasswordDisplayMode` enum.
 ///   - Hidden: default behavior, no UI indicators.
-///   - Masked: behaves like a normal text input, except that all characters of the input are masked to a special character, which is `'*'` by default but can be customized via `RenderConfig`.
+///   - Masked: behaves like a normal text input, except that all characters of the input are masked to a special character, which is `'*'` by default but can be customized via `RenderConfig` or the `with_mask_char()` method.
 ///   - Full: behaves like a normal text input, no modifications.
-/// - **Toggle display mode**: When enabling this feature by calling the `with_display_toggle_enabled()` method, you allow the user to toggle between the standard display mode set and the full display mode.
-///   - If you have set the standard display mode to hidden (which is also the default) or masked, the user can press `Ctrl+R` to change the display mode to `Full`, and `Ctrl+R` again to change it back to the standard one.
-///   - Obviously, if you have set the standard display mode to `Full`, pressing `Ctrl+R` won't cause any changes.
+/// - **Reveal last character**: When enabled via `with_reveal_last_char(true)`, the last typed character is displayed as itself in the masked display mode until the next key press.
+/// - **Toggle display mode**: When enabling this feature by calling the `with_display_toggle_enabled()` method, you allow the user to cycle through the display modes at runtime.
+///   - Starting from the standard display mode, each `Ctrl+R` press moves from hidden to masked, from masked to full and from full back to hidden.
+///   - Unless a custom help message is set, the help line mentions the toggle.
+///   - The final answer is always rendered by the formatter, regardless of the current display mode.
 /// - **Confirmation**: By default, the password will have a confirmation flow where the user will be asked for the input twice and the two responses will be compared. If they differ, an error message is shown and the user is prompted again.
-///   - By default, a "Confirmation:" message is shown for the confirmation prompts, but this can be modified by setting a custom confirmation message only shown the second time, using the `with_custom_confirmation_message()` method.
-///   - If confirmation is not desired, it can be turned off using the `without_confirmation()` method.
-/// - **Help message**: Message displayed at the line below the prompt.
+///   - By default, a "Confirmation:" message is shown for the confirmation prompts, but this can be modified by setting a custom confirmation message only shown the second time, using the `with_confirmation_message()` method.
+///   - Likewise, the error message displayed when the answers don't match can be modified using the `with_confirmation_error_message()` method.
+///   - If confirmation is not desired, it can be turned off using the `without_confirmation()` method, or `with_confirmation(false)`.
+///   - Cancelling the prompt while confirming the input cancels it as a whole, just like cancelling the first entry.
+/// - **Zeroization**: When the `zeroize` feature is enabled, the input buffers of the prompt and of its confirmation are overwritten in memory whenever they are cleared and when the prompt is dropped.
+///   - The returned `String` is a copy owned by the caller, who is responsible for wiping it.
+///   - Buffers released when the input outgrows its allocated capacity and the text written to the terminal in the `Full` display mode are not covered.
+/// - **Secret answer**: When the `secrecy` feature is enabled, the `prompt_secret()` method returns the answer wrapped in a `SecretString`, keeping it out of debug output.
+/// - **Numeric input**: Call `with_numeric_only(true)` to only accept digits, e.g. for PINs, and `with_exact_length()` to submit the answer as soon as the expected number of characters is entered.
+/// - **Strength meter**: Set a function via `with_strength_fn()` to display the strength of the input below the prompt as the user types, e.g. `PasswordStrength::estimate`. It is not displayed when the input is empty, and it never blocks submission.
+/// - **Standard input fallback**: When enabled via `with_stdin_fallback(true)`, the answer is read as a single line from the standard input if it is not a terminal, e.g. when piped, instead of failing.
+/// - **Raw mode fallback**: If the terminal can't be put in raw mode, the answer is read as a line with echo disabled, and read again after the error message when rejected, e.g. by a validator. An `InquireError::NotInteractive` error is returned if that is not possible either.
+/// - **Help message**: Message displayed at the line below the prompt, optionally only while the input is empty.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - By default, it prints eight asterisk characters: `********`.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
+///   - The built-in [`PasswordPolicyValidator`](crate::validator::PasswordPolicyValidator) covers the common requirements: a minimum length, a minimum number of character classes and not being one of the most common passwords.
 ///   - No validators are on by default.
+///   - When the `async` feature is enabled, asynchronous validators can be added via `with_async_validator()`, e.g. to check the input against a remote service, with a spinner displayed while they run.
 ///
 /// Remember that for CLI applications it is standard to not allow use any display modes other than `Hidden` and to not allow the user to see the text input in any way. _Use the customization options at your discretion_.
 ///
@@ -72,8 +99,8 @@ pub enum PasswordDisplayMode {
 ///  let name = Password::new("Encryption Key:")
 ///      .with_display_toggle_enabled()
 ///      .with_display_mode(PasswordDisplayMode::Hidden)
-///      .with_custom_confirmation_message("Encryption Key (confirm):")
-///      .with_custom_confirmation_error_message("The keys don't match.")
+///      .with_confirmation_message("Encryption Key (confirm):")
+///      .with_confirmation_error_message("The keys don't match.")
 ///      .with_validator(validator)
 ///      .with_formatter(&|_| String::from("Input received"))
 ///      .with_help_message("It is recommended to generate a new one only for this purpose")
@@ -98,15 +125,44 @@ pub struct Password<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Whether the help message is only displayed while the input is empty.
+    pub help_message_on_empty_only: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
     /// How the password input is displayed to the user.
     pub display_mode: PasswordDisplayMode,
 
-    /// Whether to allow the user to toggle the display of the current password input by pressing the Ctrl+R hotkey.
+    /// Character used to mask each grapheme of the input when the display mode is
+    /// [Masked](PasswordDisplayMode::Masked).
+    ///
+    /// If `None`, the `password_mask` of the render config is used.
+    pub mask_char: Option<char>,
+
+    /// Whether the last typed grapheme is displayed as itself, instead of masked, until
+    /// the next key press when the display mode is [Masked](PasswordDisplayMode::Masked).
+    pub reveal_last_char: bool,
+
+    /// Function that estimates the strength of the current input, displayed below
+    /// the prompt as the user types.
+    pub strength_fn: Option<PasswordStrengthFn<'a>>,
+
+    /// Whether only ASCII digits are accepted as input, e.g. for PINs.
+    pub numeric_only: bool,
+
+    /// Exact number of characters the answer must have. The answer is submitted
+    /// as soon as the last one is entered.
+    pub exact_length: Option<usize>,
+
+    /// Whether to allow the user to cycle the display mode of the current password input by pressing the Ctrl+R hotkey.
     pub enable_display_toggle: bool,
 
+    /// Whether to read the answer as a single line from the standard input when it
+    /// is not a terminal, e.g. when piped, instead of failing with
+    /// [InquireError::NotTTY].
+    pub stdin_fallback: bool,
+
     /// Whether to ask for input twice to see if the provided passwords are the same.
     pub enable_confirmation: bool,
 
@@ -118,6 +174,14 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Collection of asynchronous validators to apply to the user input, run in the order
+    /// they are stored once the input passes the other validators.
+    ///
+    /// The confirmation of the input is only requested after they all pass.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async_validators: Vec<Box<dyn AsyncStringValidator>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -145,6 +209,12 @@ impl<'a> Password<'a> {
     /// Default value for the enable confirmation variable.
     pub const DEFAULT_ENABLE_CONFIRMATION: bool = true;
 
+    /// Default message of the prompt when asking for the password confirmation.
+    pub const DEFAULT_CONFIRMATION_MESSAGE: &'a str = "Confirmation:";
+
+    /// Default error message of the prompt when password confirmation fails.
+    pub const DEFAULT_CONFIRMATION_ERROR_MESSAGE: &'a str = "The answers don't match.";
+
     /// Default password display mode.
     pub const DEFAULT_DISPLAY_MODE: PasswordDisplayMode = PasswordDisplayMode::Hidden;
 
@@ -157,9 +227,18 @@ impl<'a> Password<'a> {
             enable_confirmation: Self::DEFAULT_ENABLE_CONFIRMATION,
             enable_display_toggle: Self::DEFAULT_ENABLE_DISPLAY_TOGGLE,
             display_mode: Self::DEFAULT_DISPLAY_MODE,
+            mask_char: None,
+            reveal_last_char: false,
+            strength_fn: None,
+            numeric_only: false,
+            exact_length: None,
+            stdin_fallback: false,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            help_message_on_empty_only: false,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            #[cfg(feature = "async")]
+            async_validators: vec![],
             render_config: get_configuration(),
         }
     }
@@ -170,7 +249,15 @@ impl<'a> Password<'a> {
         self
     }
 
-    /// Sets the flag to enable display toggling.
+    /// Sets whether the help message is only displayed while the input is empty,
+    /// disappearing once the user starts typing.
+    pub fn with_help_message_on_empty_only(mut self, on_empty_only: bool) -> Self {
+        self.help_message_on_empty_only = on_empty_only;
+        self
+    }
+
+    /// Sets the flag to enable display toggling, allowing the user to cycle through
+    /// the hidden, masked and full display modes by pressing Ctrl+R.
     pub fn with_display_toggle_enabled(mut self) -> Self {
         self.enable_display_toggle = true;
         self
@@ -182,24 +269,116 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets whether the user is asked for the input twice, with the answer only
+    /// being returned when both entries match.
+    pub fn with_confirmation(mut self, enabled: bool) -> Self {
+        self.enable_confirmation = enabled;
+        self
+    }
+
     /// Sets the prompt message when asking for the password confirmation.
-    pub fn with_custom_confirmation_message(mut self, message: &'a str) -> Self {
+    ///
+    /// Defaults to [`Password::DEFAULT_CONFIRMATION_MESSAGE`].
+    pub fn with_confirmation_message(mut self, message: &'a str) -> Self {
         self.custom_confirmation_message.replace(message);
         self
     }
 
-    /// Sets the prompt error message when password confirmation fails.
-    pub fn with_custom_confirmation_error_message(mut self, message: &'a str) -> Self {
+    /// Sets the prompt error message when password confirmation fails. It is rendered
+    /// just like the error messages of validators.
+    ///
+    /// Defaults to [`Password::DEFAULT_CONFIRMATION_ERROR_MESSAGE`].
+    pub fn with_confirmation_error_message(mut self, message: &'a str) -> Self {
         self.custom_confirmation_error_message.replace(message);
         self
     }
 
+    /// Sets the prompt message when asking for the password confirmation.
+    ///
+    /// Same as [`Password::with_confirmation_message`].
+    pub fn with_custom_confirmation_message(self, message: &'a str) -> Self {
+        self.with_confirmation_message(message)
+    }
+
+    /// Sets the prompt error message when password confirmation fails.
+    ///
+    /// Same as [`Password::with_confirmation_error_message`].
+    pub fn with_custom_confirmation_error_message(self, message: &'a str) -> Self {
+        self.with_confirmation_error_message(message)
+    }
+
     /// Sets the standard display mode for the prompt.
     pub fn with_display_mode(mut self, mode: PasswordDisplayMode) -> Self {
         self.display_mode = mode;
         self
     }
 
+    /// Sets the character used to mask the input in the masked display mode,
+    /// overriding the `password_mask` of the render config.
+    ///
+    /// Characters without a visible width, such as control characters, are
+    /// rejected with an [InquireError::InvalidConfiguration] error when prompting.
+    pub fn with_mask_char(mut self, mask: char) -> Self {
+        self.mask_char = Some(mask);
+        self
+    }
+
+    /// Sets whether the last typed grapheme is displayed as itself in the masked display
+    /// mode, like in the password fields of mobile devices, reducing typos. It is masked
+    /// again on the next key press or when the answer is submitted.
+    ///
+    /// Keep in mind that the revealed characters are written to the terminal.
+    pub fn with_reveal_last_char(mut self, reveal: bool) -> Self {
+        self.reveal_last_char = reveal;
+        self
+    }
+
+    /// Sets the function used to estimate the strength of the current input,
+    /// displayed below the prompt as the user types. [PasswordStrength::estimate]
+    /// can be used as a simple default.
+    ///
+    /// The strength is only displayed as feedback and never prevents the user
+    /// from submitting an answer. Use validators to enforce requirements.
+    pub fn with_strength_fn(mut self, strength_fn: PasswordStrengthFn<'a>) -> Self {
+        self.strength_fn = Some(strength_fn);
+        self
+    }
+
+    /// Sets whether only ASCII digits are accepted as input, e.g. when asking for
+    /// a PIN. Other characters are rejected as they are typed, ringing the terminal bell.
+    ///
+    /// Enabling it also sets the display mode to [Masked](PasswordDisplayMode::Masked),
+    /// with one `'•'` per digit unless a mask character is set via `with_mask_char()`.
+    pub fn with_numeric_only(mut self, numeric_only: bool) -> Self {
+        self.numeric_only = numeric_only;
+        if numeric_only {
+            self.display_mode = PasswordDisplayMode::Masked;
+        }
+        self
+    }
+
+    /// Sets the exact number of characters the answer must have. The input is limited
+    /// to that length and submitted as soon as the last character is entered, without
+    /// waiting for the user to press enter.
+    ///
+    /// Validators still run on submission. If they reject the answer, the error is
+    /// displayed and the user can keep editing the input.
+    pub fn with_exact_length(mut self, length: usize) -> Self {
+        self.exact_length = Some(length);
+        self
+    }
+
+    /// Sets whether to read the answer as a single line from the standard input when
+    /// it is not a terminal, e.g. when the program is run as `echo "secret" | program`.
+    ///
+    /// In that case, nothing is rendered and the confirmation step is skipped, while
+    /// validators and the formatter still run. If the line is rejected by a validator,
+    /// an [InquireError::IO] error is returned.
+    pub fn with_stdin_fallback(mut self, stdin_fallback: bool) -> Self {
+        self.stdin_fallback = stdin_fallback;
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -242,6 +421,39 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Adds the validators to the collection of validators in the order they are given,
+    /// taking ownership of them. Useful when the list is built at runtime.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_owned_validators(mut self, validators: Vec<Box<dyn StringValidator>>) -> Self {
+        self.validators.extend(validators);
+        self
+    }
+
+    /// Adds an asynchronous validator to the collection of asynchronous validators, e.g.
+    /// to check the password against a remote service.
+    ///
+    /// They are run in the order they are stored once the input passes the synchronous
+    /// validators. While they run, a spinner is displayed and key presses are ignored,
+    /// except for Ctrl+C, which interrupts the prompt. If one of them rejects the input,
+    /// the error is displayed and the user can edit the input again.
+    ///
+    /// See [`AsyncStringValidator`] for the async runtimes supported. The `console`
+    /// backend does not read key presses while validating, so the prompt can't be
+    /// interrupted until the validators finish.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn with_async_validator<V>(mut self, validator: V) -> Self
+    where
+        V: AsyncStringValidator + 'static,
+    {
+        self.async_validators.push(Box::new(validator));
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -274,16 +486,114 @@ impl<'a> Password<'a> {
 
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
+    ///
+    /// If the terminal can't be put in raw mode, e.g. in some minimal containers,
+    /// the messages of the prompt are written as plain text and each answer is read
+    /// as a line with echo disabled at the OS level, reading it again along with the
+    /// error message when it is rejected. If that is not possible either, an
+    /// [InquireError::NotInteractive] error is returned.
     pub fn prompt(self) -> InquireResult<String> {
-        let terminal = get_default_terminal()?;
+        self.prompt_as()
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, wrapping the
+    /// answer in a [SecretString] so that it is not exposed by accident, e.g.
+    /// in debug output.
+    ///
+    /// The answer is moved into the [SecretString] without being copied.
+    #[cfg(feature = "secrecy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "secrecy")))]
+    pub fn prompt_secret(self) -> InquireResult<SecretString> {
+        self.prompt_as()
+    }
+
+    fn prompt_as<A: From<String>>(self) -> InquireResult<A> {
+        if self.stdin_fallback && !stdin_is_tty() {
+            return self
+                .prompt_with_line_terminal(LineTerminal::from_stdin()?)
+                .map(A::from);
+        }
+
+        let terminal = match get_default_terminal() {
+            Ok(terminal) => terminal,
+            // raw mode can't be enabled, but echo might still be disabled.
+            Err(InquireError::NotTTY) => {
+                return self
+                    .prompt_without_raw_mode(&mut stderr(), no_echo::read_line)
+                    .map(A::from)
+            }
+            Err(err) => return Err(err),
+        };
+
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend_as(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_line_terminal(self, terminal: LineTerminal) -> InquireResult<String> {
         let mut backend = Backend::new(terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        self.without_confirmation()
+            .prompt_with_backend(&mut backend)
+    }
+
+    /// Fallback for terminals that can't be put in raw mode, writing the plain
+    /// messages of the prompt and reading each answer as a line with echo disabled.
+    pub(crate) fn prompt_without_raw_mode<W, R>(
+        self,
+        writer: &mut W,
+        mut read_line: R,
+    ) -> InquireResult<String>
+    where
+        W: Write,
+        R: FnMut() -> std::io::Result<String>,
+    {
+        let render_config = self.render_config;
+        let mut prompt = PasswordPrompt::from(self);
+        // validators might render their progress, which is discarded.
+        let mut backend = Backend::new(LineTerminal::from_lines::<&str>(&[]), render_config)?;
+
+        loop {
+            write!(writer, "{} ", prompt.step_message())?;
+            writer.flush()?;
+
+            let line = read_line().map_err(|_unused| InquireError::NotInteractive)?;
+            // the new line typed by the user is not echoed either.
+            writeln!(writer)?;
+
+            let answer = prompt.submit_line(&line, &mut backend);
+
+            #[cfg(feature = "zeroize")]
+            {
+                let mut line = line;
+                zeroize::Zeroize::zeroize(&mut line);
+            }
+
+            if let Some(answer) = answer? {
+                return Ok(answer);
+            }
+
+            // rejected answers are read again, e.g. when the confirmation does not match.
+            if let Some(error) = prompt.take_error() {
+                let message = match &error {
+                    ErrorMessage::Default => render_config.error_message.default_message,
+                    ErrorMessage::Custom(msg) => msg,
+                };
+                writeln!(writer, "{}", message)?;
+            }
+        }
     }
 
     pub(crate) fn prompt_with_backend<B: PasswordBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
-        PasswordPrompt::from(self).prompt(backend)
+        self.prompt_with_backend_as(backend)
+    }
+
+    pub(crate) fn prompt_with_backend_as<A: From<String>, B: PasswordBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<A> {
+        PasswordPrompt::from(self).prompt(backend).map(A::from)
     }
 }
@@ -3,12 +3,28 @@ use crate::{
     formatter::StringFormatter,
     input::Input,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::PasswordStrengthFn,
     ui::PasswordBackend,
     validator::{ErrorMessage, StringValidator, Validation},
-    InquireError, Password, PasswordDisplayMode,
+    InputAction, InquireError, Password, PasswordDisplayMode,
 };
 
-use super::{action::PasswordPromptAction, config::PasswordConfig};
+use unicode_width::UnicodeWidthChar;
+
+#[cfg(feature = "async")]
+use crate::{utils::block_on, validator::AsyncStringValidator, Action};
+#[cfg(feature = "async")]
+use std::time::Duration;
+
+use super::{
+    action::PasswordPromptAction, config::PasswordConfig, DEFAULT_HELP_MESSAGE_WITH_TOGGLE,
+};
+
+#[cfg(feature = "async")]
+const VALIDATION_SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+#[cfg(feature = "async")]
+const VALIDATION_TICK: Duration = Duration::from_millis(80);
 
 // Helper type for representing the password confirmation flow.
 struct PasswordConfirmation<'a> {
@@ -26,12 +42,22 @@ pub struct PasswordPrompt<'a> {
     message: &'a str,
     config: PasswordConfig,
     help_message: Option<&'a str>,
+    help_message_on_empty_only: bool,
     input: Input,
     current_mode: PasswordDisplayMode,
+    mask_char: Option<char>,
+    reveal_last_char: bool,
+    revealed_grapheme: Option<usize>,
+    numeric_only: bool,
+    exact_length: Option<usize>,
+    rejected_input: bool,
+    strength_fn: Option<PasswordStrengthFn<'a>>,
     confirmation: Option<PasswordConfirmation<'a>>, // if `None`, confirmation is disabled, `Some(_)` confirmation is enabled
     confirmation_stage: bool,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    #[cfg(feature = "async")]
+    async_validators: Vec<Box<dyn AsyncStringValidator>>,
     error: Option<ErrorMessage>,
 }
 
@@ -39,11 +65,13 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
     fn from(so: Password<'a>) -> Self {
         let confirmation = match so.enable_confirmation {
             true => Some(PasswordConfirmation {
-                message: so.custom_confirmation_message.unwrap_or("Confirmation:"),
+                message: so
+                    .custom_confirmation_message
+                    .unwrap_or(Password::DEFAULT_CONFIRMATION_MESSAGE),
                 error_message: so
                     .custom_confirmation_error_message
-                    .unwrap_or("The answers don't match."),
-                input: Input::new(),
+                    .unwrap_or(Password::DEFAULT_CONFIRMATION_ERROR_MESSAGE),
+                input: Input::new().with_max_length(so.exact_length),
             }),
             false => None,
         };
@@ -52,12 +80,25 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             message: so.message,
             config: (&so).into(),
             help_message: so.help_message,
+            help_message_on_empty_only: so.help_message_on_empty_only,
             current_mode: so.display_mode,
+            mask_char: match so.mask_char {
+                None if so.numeric_only => Some('•'),
+                mask_char => mask_char,
+            },
+            reveal_last_char: so.reveal_last_char,
+            revealed_grapheme: None,
+            numeric_only: so.numeric_only,
+            exact_length: so.exact_length,
+            rejected_input: false,
+            strength_fn: so.strength_fn,
             confirmation,
             confirmation_stage: false,
             formatter: so.formatter,
             validators: so.validators,
-            input: Input::new(),
+            #[cfg(feature = "async")]
+            async_validators: so.async_validators,
+            input: Input::new().with_max_length(so.exact_length),
             error: None,
         }
     }
@@ -70,6 +111,13 @@ impl<'a> From<&'a str> for Password<'a> {
 }
 
 impl<'a> PasswordPrompt<'a> {
+    fn active_input(&self) -> &Input {
+        match &self.confirmation {
+            Some(c) if self.confirmation_stage => &c.input,
+            _ => &self.input,
+        }
+    }
+
     fn active_input_mut(&mut self) -> &mut Input {
         if let Some(c) = &mut self.confirmation {
             if self.confirmation_stage {
@@ -81,26 +129,37 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn toggle_display_mode(&mut self) -> ActionResult {
-        let new_mode = match self.current_mode {
-            PasswordDisplayMode::Hidden | PasswordDisplayMode::Masked => PasswordDisplayMode::Full,
-            PasswordDisplayMode::Full => self.config.display_mode,
+        self.current_mode = match self.current_mode {
+            PasswordDisplayMode::Hidden => PasswordDisplayMode::Masked,
+            PasswordDisplayMode::Masked => PasswordDisplayMode::Full,
+            PasswordDisplayMode::Full => PasswordDisplayMode::Hidden,
         };
 
-        if new_mode != self.current_mode {
-            self.current_mode = new_mode;
-            ActionResult::NeedsRedraw
-        } else {
-            ActionResult::Clean
+        ActionResult::NeedsRedraw
+    }
+
+    fn is_rejected(&self, action: &PasswordPromptAction) -> bool {
+        match action {
+            PasswordPromptAction::ValueInput(InputAction::Write(c)) => {
+                self.numeric_only && !c.is_ascii_digit()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        match self.exact_length {
+            Some(length) => self.active_input().length() == length,
+            None => true,
         }
     }
 
     fn confirmation_step(&mut self) -> ConfirmationStepResult {
-        let cur_answer = self.cur_answer().to_owned();
         match &mut self.confirmation {
             None => ConfirmationStepResult::NoConfirmationRequired,
             Some(confirmation) => {
                 if self.confirmation_stage {
-                    if cur_answer == confirmation.input.content() {
+                    if self.input.content() == confirmation.input.content() {
                         ConfirmationStepResult::ConfirmationValidated
                     } else {
                         self.confirmation_stage = false;
@@ -119,6 +178,60 @@ impl<'a> PasswordPrompt<'a> {
         }
     }
 
+    /// Message of the current step of the prompt, i.e. of the confirmation once the
+    /// answer is accepted.
+    pub fn step_message(&self) -> &'a str {
+        match &self.confirmation {
+            Some(c) if self.confirmation_stage => c.message,
+            _ => self.message,
+        }
+    }
+
+    /// Takes the error of the last rejected submission, if any.
+    pub fn take_error(&mut self) -> Option<ErrorMessage> {
+        self.error.take()
+    }
+
+    /// Submits a line read without raw mode as the input of the current step,
+    /// returning the answer once it is accepted.
+    pub fn submit_line<Backend>(
+        &mut self,
+        line: &str,
+        backend: &mut Backend,
+    ) -> InquireResult<Option<String>>
+    where
+        Backend: PasswordBackend,
+    {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        self.active_input_mut().clear();
+        for c in line.chars() {
+            let action = PasswordPromptAction::ValueInput(InputAction::Write(c));
+            Prompt::<Backend>::handle(self, action)?;
+        }
+
+        Prompt::<Backend>::submit_with_backend(self, backend)
+    }
+
+    fn clear_inputs(&mut self) {
+        self.input.clear();
+
+        if let Some(confirmation) = &mut self.confirmation {
+            confirmation.input.clear();
+        }
+    }
+
+    #[cfg(all(test, feature = "crossterm"))]
+    pub fn is_cleared(&self) -> bool {
+        let confirmation_cleared = match &self.confirmation {
+            Some(confirmation) => confirmation.input.is_empty(),
+            None => true,
+        };
+
+        self.input.is_empty() && confirmation_cleared
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         for validator in &self.validators {
             match validator.validate(self.cur_answer()) {
@@ -131,47 +244,90 @@ impl<'a> PasswordPrompt<'a> {
         Ok(Validation::Valid)
     }
 
-    fn cur_answer(&self) -> &str {
-        self.input.content()
-    }
-}
-
-impl<'a, Backend> Prompt<Backend> for PasswordPrompt<'a>
-where
-    Backend: PasswordBackend,
-{
-    type Config = PasswordConfig;
-    type InnerAction = PasswordPromptAction;
-    type Output = String;
-
-    fn message(&self) -> &str {
-        self.message
-    }
+    #[cfg(feature = "async")]
+    fn validate_current_answer_async<Backend>(
+        &self,
+        backend: &mut Backend,
+    ) -> InquireResult<Validation>
+    where
+        Backend: PasswordBackend,
+    {
+        let mut spinner = VALIDATION_SPINNER.iter().cycle();
+
+        // lets the validators use the tokio runtime the prompt is called from,
+        // e.g. for IO and timers, which its other threads keep driving.
+        #[cfg(feature = "tokio")]
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        #[cfg(feature = "tokio")]
+        let _guard = runtime.as_ref().map(tokio::runtime::Handle::enter);
+
+        for validator in &self.async_validators {
+            let validation = block_on(validator.validate(self.cur_answer()), || {
+                backend.frame_setup()?;
+                self.render_frame(backend, spinner.next().copied())?;
+                backend.frame_finish()?;
+
+                // input is ignored while validating, except for interruptions.
+                match backend.poll_key(VALIDATION_TICK)? {
+                    Some(key)
+                        if Action::<PasswordPromptAction>::from_key(key, &self.config)
+                            == Some(Action::Interrupt) =>
+                    {
+                        Err(InquireError::OperationInterrupted)
+                    }
+                    _ => Ok(()),
+                }
+            })?;
 
-    fn config(&self) -> &PasswordConfig {
-        &self.config
-    }
+            match validation {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
 
-    fn format_answer(&self, answer: &String) -> String {
-        (self.formatter)(answer)
+        Ok(Validation::Valid)
     }
 
-    fn pre_cancel(&mut self) -> InquireResult<bool> {
-        if let Some(confirmation) = &mut self.confirmation {
-            if self.confirmation_stage {
-                confirmation.input.clear();
-                self.confirmation_stage = false;
-                return Ok(false);
+    fn submit_with<F>(&mut self, validate_async: F) -> InquireResult<Option<String>>
+    where
+        F: FnOnce(&Self) -> InquireResult<Validation>,
+    {
+        self.rejected_input = false;
+        self.revealed_grapheme = None;
+
+        if let Some(length) = self.exact_length {
+            if !self.is_complete() {
+                let unit = if self.numeric_only {
+                    "digits"
+                } else {
+                    "characters"
+                };
+                self.error = Some(ErrorMessage::Custom(format!(
+                    "Please enter exactly {} {}.",
+                    length, unit
+                )));
+                return Ok(None);
             }
         }
 
-        Ok(true)
-    }
+        let mut validation = self.validate_current_answer()?;
 
-    fn submit(&mut self) -> InquireResult<Option<String>> {
-        if let Validation::Invalid(msg) = self.validate_current_answer()? {
+        // the answer does not change on the confirmation step, so it is not
+        // validated again by potentially slow validators.
+        if validation == Validation::Valid && !self.confirmation_stage {
+            validation = match validate_async(self) {
+                Ok(validation) => validation,
+                Err(err) => {
+                    self.clear_inputs();
+                    return Err(err);
+                }
+            };
+        }
+
+        if let Validation::Invalid(msg) = validation {
             self.error = Some(msg);
-            if self.config.display_mode == PasswordDisplayMode::Hidden {
+            if self.current_mode == PasswordDisplayMode::Hidden {
                 self.input.clear();
             }
             return Ok(None);
@@ -179,11 +335,9 @@ where
 
         let confirmation = self.confirmation_step();
 
-        let cur_answer = self.cur_answer().to_owned();
-
         let result = match confirmation {
             ConfirmationStepResult::NoConfirmationRequired
-            | ConfirmationStepResult::ConfirmationValidated => Some(cur_answer),
+            | ConfirmationStepResult::ConfirmationValidated => Some(self.input.take_content()),
             ConfirmationStepResult::ConfirmationPending => None,
             ConfirmationStepResult::ConfirmationInvalidated(message) => {
                 self.error = Some(message);
@@ -195,20 +349,21 @@ where
         Ok(result)
     }
 
-    fn handle(&mut self, action: PasswordPromptAction) -> InquireResult<ActionResult> {
-        let result = match action {
-            PasswordPromptAction::ValueInput(input_action) => {
-                self.active_input_mut().handle(input_action).into()
-            }
-            PasswordPromptAction::ToggleDisplayMode => self.toggle_display_mode(),
-        };
-
-        Ok(result)
-    }
+    fn render_frame<Backend>(
+        &self,
+        backend: &mut Backend,
+        spinner: Option<&str>,
+    ) -> InquireResult<()>
+    where
+        Backend: PasswordBackend,
+    {
+        if self.rejected_input {
+            backend.ring_bell()?;
+        }
 
-    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
-        if let Some(err) = &self.error {
-            backend.render_error_message(err)?;
+        match &self.error {
+            Some(err) if spinner.is_none() => backend.render_error_message(err)?,
+            _ => {}
         }
 
         match self.current_mode {
@@ -223,13 +378,25 @@ where
                 }
             }
             PasswordDisplayMode::Masked => {
-                backend.render_prompt_with_masked_input(self.message, &self.input)?;
+                let (revealed, confirmation_revealed) = match self.confirmation_stage {
+                    true => (None, self.revealed_grapheme),
+                    false => (self.revealed_grapheme, None),
+                };
+
+                backend.render_prompt_with_masked_input(
+                    self.message,
+                    &self.input,
+                    self.mask_char,
+                    revealed,
+                )?;
 
                 match &self.confirmation {
                     Some(confirmation) if self.confirmation_stage => {
                         backend.render_prompt_with_masked_input(
                             confirmation.message,
                             &confirmation.input,
+                            self.mask_char,
+                            confirmation_revealed,
                         )?;
                     }
                     _ => {}
@@ -250,12 +417,130 @@ where
             }
         }
 
-        if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
+        match self.strength_fn {
+            Some(strength_fn) if !self.confirmation_stage && !self.input.is_empty() => {
+                backend.render_password_strength(strength_fn(self.input.content()))?;
+            }
+            _ => {}
+        }
+
+        if let Some(spinner) = spinner {
+            backend.render_help_message(&format!("{} Validating...", spinner))?;
+        } else if let Some(message) = self.help_message {
+            if !self.help_message_on_empty_only || self.active_input().is_empty() {
+                backend.render_help_message(message)?;
+            }
+        } else if self.config.enable_display_toggle {
+            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_TOGGLE)?;
         }
 
         Ok(())
     }
+
+    fn cur_answer(&self) -> &str {
+        self.input.content()
+    }
+}
+
+impl<'a, Backend> Prompt<Backend> for PasswordPrompt<'a>
+where
+    Backend: PasswordBackend,
+{
+    type Config = PasswordConfig;
+    type InnerAction = PasswordPromptAction;
+    type Output = String;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &PasswordConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &String) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        if let Some(mask) = self.mask_char {
+            if UnicodeWidthChar::width(mask).unwrap_or(0) == 0 {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Mask character {:?} has no visible width",
+                    mask
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        self.clear_inputs();
+        Ok(true)
+    }
+
+    fn pre_interrupt(&mut self) -> InquireResult<()> {
+        self.clear_inputs();
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        self.submit_with(|_| Ok(Validation::Valid))
+    }
+
+    #[cfg(feature = "async")]
+    fn submit_with_backend(&mut self, backend: &mut Backend) -> InquireResult<Option<String>> {
+        self.submit_with(|prompt| prompt.validate_current_answer_async(backend))
+    }
+
+    fn handle(&mut self, action: PasswordPromptAction) -> InquireResult<ActionResult> {
+        // the revealed grapheme is masked again on the next key press.
+        let was_revealed = self.revealed_grapheme.take().is_some();
+
+        self.rejected_input = self.is_rejected(&action);
+        if self.rejected_input {
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        let result = match action {
+            PasswordPromptAction::ValueInput(input_action) => {
+                let result = self.active_input_mut().handle(input_action);
+
+                if let InputAction::Write(_) = input_action {
+                    if self.reveal_last_char && result.needs_redraw() {
+                        self.revealed_grapheme = self.active_input().cursor().checked_sub(1);
+                    }
+                }
+
+                match (input_action, self.exact_length) {
+                    (InputAction::Write(_), Some(_))
+                        if self.is_complete() && result.needs_redraw() =>
+                    {
+                        ActionResult::NeedsSubmit
+                    }
+                    _ => result.into(),
+                }
+            }
+            PasswordPromptAction::ToggleDisplayMode => self.toggle_display_mode(),
+        };
+
+        match result {
+            ActionResult::Clean if was_revealed => Ok(ActionResult::NeedsRedraw),
+            result => Ok(result),
+        }
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        self.render_frame(backend, None)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> Drop for PasswordPrompt<'a> {
+    fn drop(&mut self) {
+        self.clear_inputs();
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,49 @@
+use crate::validator::character_classes;
+
+/// Strength of a password, displayed below Password prompts that have a
+/// strength function set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    /// Password that is easy to guess.
+    Weak,
+
+    /// Password that is acceptable, but could be improved.
+    Fair,
+
+    /// Password that is hard to guess.
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Simple heuristic to estimate the strength of a password, based on its length
+    /// and on how many classes of characters it contains: lowercase letters, uppercase
+    /// letters, digits and others.
+    ///
+    /// It is meant as a sensible default. For more accurate estimates, consider
+    /// calling a dedicated library, such as `zxcvbn`, in your own strength function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::PasswordStrength;
+    ///
+    /// assert_eq!(PasswordStrength::Weak, PasswordStrength::estimate("hunter2"));
+    /// assert_eq!(PasswordStrength::Fair, PasswordStrength::estimate("hunter2hunter"));
+    /// assert_eq!(PasswordStrength::Strong, PasswordStrength::estimate("Hunter2-hunter"));
+    /// ```
+    pub fn estimate(password: &str) -> Self {
+        let length = password.chars().count();
+        if length < 8 {
+            return Self::Weak;
+        }
+
+        let score =
+            character_classes(password) + usize::from(length >= 12) + usize::from(length >= 16);
+
+        match score {
+            0..=2 => Self::Weak,
+            3 => Self::Fair,
+            _ => Self::Strong,
+        }
+    }
+}
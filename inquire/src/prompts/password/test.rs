@@ -4,11 +4,16 @@ use crate::{
     ui::{Backend, RenderConfig},
     validator::{ErrorMessage, Validation},
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+// new lines are sent as enter key presses, a `KeyCode::Char('\n')` being
+// handled as a pasted character instead.
 macro_rules! text_to_events {
     ($text:expr) => {{
-        $text.chars().map(KeyCode::Char)
+        $text.chars().map(|c| match c {
+            '\n' => KeyCode::Enter,
+            c => KeyCode::Char(c),
+        })
     }};
 }
 
@@ -260,3 +265,998 @@ password_test!(
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+password_test!(
+    ctrl_u_clears_the_input,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("garbage").map(KeyEvent::from).collect());
+        events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("answer\n").map(KeyEvent::from).collect());
+        events
+    },
+    "answer",
+    Password::new("").without_confirmation()
+);
+
+password_test!(
+    insert_and_delete_in_the_middle,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("pasword").collect());
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Left);
+        events.append(&mut text_to_events!("s").collect());
+        events.push(KeyCode::End);
+        events.append(&mut text_to_events!("1").collect());
+        events.push(KeyCode::Home);
+        events.push(KeyCode::Right);
+        events.push(KeyCode::Backspace);
+        events.append(&mut text_to_events!("P").collect());
+        events.push(KeyCode::Enter);
+        events
+    },
+    "Password1",
+    Password::new("").without_confirmation()
+);
+
+password_test!(
+    delete_grapheme_in_the_middle,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("a🧘🏻‍♂️b").collect());
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Backspace);
+        events.push(KeyCode::Delete);
+        events.append(&mut text_to_events!("c").collect());
+        events.push(KeyCode::Enter);
+        events
+    },
+    "ac",
+    Password::new("").without_confirmation()
+);
+
+password_test!(
+    ctrl_u_clears_input,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("mistyped").map(KeyEvent::from).collect());
+        events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("secret\n").map(KeyEvent::from).collect());
+        events
+    },
+    "secret",
+    Password::new("").without_confirmation()
+);
+
+password_test!(
+    ctrl_u_clears_confirmation_input,
+    {
+        let mut events = vec![];
+        events.append(
+            &mut text_to_events!("secret\nsecrte")
+                .map(KeyEvent::from)
+                .collect(),
+        );
+        events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("secret\n").map(KeyEvent::from).collect());
+        events
+    },
+    "secret",
+    Password::new("")
+);
+
+fn prompt_with_output(prompt: Password<'_>, events: Vec<KeyEvent>) -> (String, String) {
+    let mut read = events.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        prompt.prompt_with_backend(&mut backend).unwrap()
+    };
+
+    (ans, String::from_utf8(write).unwrap())
+}
+
+#[test]
+fn masked_mode_renders_one_mask_per_grapheme() {
+    let mut events: Vec<KeyEvent> = text_to_events!("a🧘🏻‍♂️é").map(KeyEvent::from).collect();
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_mode(crate::PasswordDisplayMode::Masked),
+        events,
+    );
+
+    assert_eq!("a🧘🏻‍♂️", ans);
+    assert!(output.contains("Password: *** "));
+    assert!(output.contains("Password: ** "));
+    assert!(!output.contains("🧘"));
+    // the answer line still goes through the formatter
+    assert!(output.contains("Password: ********\r\n"));
+}
+
+#[test]
+fn hidden_mode_is_the_default() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:").without_confirmation(),
+        text_to_events!("secret\n").map(KeyEvent::from).collect(),
+    );
+
+    assert_eq!(
+        crate::PasswordDisplayMode::Hidden,
+        Password::DEFAULT_DISPLAY_MODE
+    );
+    assert_eq!("secret", ans);
+    // only the answer line, rendered by the formatter, shows any characters
+    assert_eq!(1, output.matches("Password: *").count());
+    assert!(!output.contains("secret"));
+}
+
+#[test]
+fn custom_mask_char_overrides_render_config() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_mode(crate::PasswordDisplayMode::Masked)
+            .with_mask_char('•'),
+        text_to_events!("abc\n").map(KeyEvent::from).collect(),
+    );
+
+    assert_eq!("abc", ans);
+    assert!(output.contains("Password: ••• "));
+    assert!(!output.contains("Password: * "));
+}
+
+#[test]
+fn wide_mask_char_moves_cursor_by_its_width() {
+    let mut events: Vec<KeyEvent> = text_to_events!("ab").map(KeyEvent::from).collect();
+    events.push(KeyEvent::from(KeyCode::Left));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_mode(crate::PasswordDisplayMode::Masked)
+            .with_mask_char('＊'),
+        events,
+    );
+
+    assert_eq!("ab", ans);
+    assert!(output.contains("Password: ＊＊ "));
+    // "Password: " plus one full-width mask puts the cursor on column 12,
+    // rendered by crossterm as the 1-based column 13
+    assert!(output.contains("\u{1b}[13G"));
+}
+
+#[test]
+fn control_mask_char_is_rejected() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Password::new("Password:")
+        .without_confirmation()
+        .with_mask_char('\u{7}')
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(crate::InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn display_toggle_cycles_through_all_modes() {
+    let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+
+    let mut events: Vec<KeyEvent> = text_to_events!("ab").map(KeyEvent::from).collect();
+    events.push(ctrl_r);
+    events.push(KeyEvent::from(KeyCode::Char('c')));
+    events.push(ctrl_r);
+    events.push(KeyEvent::from(KeyCode::Char('d')));
+    events.push(ctrl_r);
+    events.push(KeyEvent::from(KeyCode::Char('e')));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_toggle_enabled(),
+        events,
+    );
+
+    assert_eq!("abcde", ans);
+    assert!(output.contains("Password: *** "));
+    assert!(output.contains("Password: abcd "));
+    assert!(!output.contains("abcde"));
+    assert!(output.contains("ctrl+r to toggle display"));
+    // full mode does not leak into the answer line
+    assert!(output.contains("Password: ********\r\n"));
+}
+
+#[test]
+fn invalid_input_is_kept_after_toggling_out_of_hidden_mode() {
+    let mut events = vec![KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)];
+    events.extend(text_to_events!("ab").map(KeyEvent::from));
+    events.push(KeyEvent::from(KeyCode::Enter));
+    events.push(KeyEvent::from(KeyCode::Char('c')));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_toggle_enabled()
+            .with_validator(|input: &str| match input.len() {
+                0..=2 => Ok(Validation::Invalid("Too short.".into())),
+                _ => Ok(Validation::Valid),
+            }),
+        events,
+    );
+
+    assert_eq!("abc", ans);
+    assert!(output.contains("Too short."));
+}
+
+#[test]
+fn display_toggle_is_ignored_when_disabled() {
+    let mut events: Vec<KeyEvent> = text_to_events!("ab").map(KeyEvent::from).collect();
+    events.push(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) =
+        prompt_with_output(Password::new("Password:").without_confirmation(), events);
+
+    assert_eq!("ab", ans);
+    assert!(!output.contains("Password: ab"));
+    assert!(!output.contains("ctrl+r"));
+}
+
+#[test]
+fn custom_help_message_replaces_toggle_hint() {
+    let (_, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_toggle_enabled()
+            .with_help_message("Use a strong one"),
+        text_to_events!("ab\n").map(KeyEvent::from).collect(),
+    );
+
+    assert!(output.contains("Use a strong one"));
+    assert!(!output.contains("ctrl+r"));
+}
+
+#[test]
+fn confirmation_mismatch_restarts_from_first_entry() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .with_confirmation(true)
+            .with_custom_confirmation_message("Again:")
+            .with_custom_confirmation_error_message("Nope, try again.")
+            .with_display_mode(crate::PasswordDisplayMode::Full),
+        text_to_events!("first\nother\nsecond\nsecond\n")
+            .map(KeyEvent::from)
+            .collect(),
+    );
+
+    assert_eq!("second", ans);
+    assert!(output.contains("Again: other"));
+    assert!(output.contains("Nope, try again."));
+    assert!(output.contains("Password: second"));
+    assert!(output.contains("Again: second"));
+}
+
+#[test]
+fn confirmation_messages_can_be_localized() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Senha:")
+            .with_confirmation_message("Confirme a senha:")
+            .with_confirmation_error_message("As senhas não coincidem")
+            .with_validator(|input: &str| match input.is_empty() {
+                true => Ok(Validation::Invalid("Digite uma senha".into())),
+                false => Ok(Validation::Valid),
+            }),
+        text_to_events!(
+            "
+first
+other
+second
+second
+"
+        )
+        .map(KeyEvent::from)
+        .collect(),
+    );
+
+    assert_eq!("second", ans);
+    assert!(output.contains("Confirme a senha:"));
+    // rendered just like validation errors
+    assert!(output.contains("# Digite uma senha"));
+    assert!(output.contains("# As senhas não coincidem"));
+}
+
+#[test]
+fn confirmation_messages_default_to_english() {
+    let (_, output) = prompt_with_output(
+        Password::new("Password:"),
+        text_to_events!(
+            "first
+other
+second
+second
+"
+        )
+        .map(KeyEvent::from)
+        .collect(),
+    );
+
+    assert!(output.contains(Password::DEFAULT_CONFIRMATION_MESSAGE));
+    assert!(output.contains(Password::DEFAULT_CONFIRMATION_ERROR_MESSAGE));
+}
+
+#[test]
+fn confirmation_can_be_disabled_with_flag() {
+    let (ans, _) = prompt_with_output(
+        Password::new("Password:").with_confirmation(false),
+        text_to_events!("secret\n").map(KeyEvent::from).collect(),
+    );
+
+    assert_eq!("secret", ans);
+}
+
+#[test]
+fn cancelling_confirmation_cancels_prompt() {
+    let mut events: Vec<KeyEvent> = text_to_events!("secret\nsec").map(KeyEvent::from).collect();
+    events.push(KeyEvent::from(KeyCode::Esc));
+    let mut read = events.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Password::new("Password:").prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(crate::InquireError::OperationCanceled)));
+}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn prompt_secret_wraps_answer() {
+    use secrecy::ExposeSecret;
+
+    let events: Vec<KeyEvent> = text_to_events!("secret\n").map(KeyEvent::from).collect();
+    let mut read = events.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    // the answer goes through the same conversion as `prompt_secret`.
+    let ans: secrecy::SecretString = Password::new("Password:")
+        .without_confirmation()
+        .prompt_with_backend_as(&mut backend)
+        .unwrap();
+
+    assert_eq!("secret", ans.expose_secret());
+    assert!(!format!("{:?}", ans).contains("secret"));
+}
+
+#[test]
+fn masked_cursor_tracks_logical_position() {
+    let mut events: Vec<KeyEvent> = text_to_events!("abcd").map(KeyEvent::from).collect();
+    events.push(KeyEvent::from(KeyCode::Left));
+    events.push(KeyEvent::from(KeyCode::Left));
+    events.push(KeyEvent::from(KeyCode::Char('x')));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_mode(crate::PasswordDisplayMode::Masked),
+        events,
+    );
+
+    assert_eq!("abxcd", ans);
+    assert!(output.contains("Password: *****"));
+    // "Password: " plus the three masks before the cursor, in crossterm's 1-based columns
+    assert!(output.contains("\u{1b}[14G"));
+}
+
+#[test]
+fn ctrl_u_collapses_masked_input() {
+    let mut events: Vec<KeyEvent> = text_to_events!("abc").map(KeyEvent::from).collect();
+    events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    events.push(KeyEvent::from(KeyCode::Char('d')));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_mode(crate::PasswordDisplayMode::Masked),
+        events,
+    );
+
+    assert_eq!("d", ans);
+    let cleared = output.find("Password: *** ").unwrap();
+    let retyped = output.rfind("Password: * ").unwrap();
+    assert!(output[cleared..retyped].contains("Password:  "));
+}
+
+#[test]
+fn strength_meter_follows_input() {
+    let mut events: Vec<KeyEvent> = text_to_events!("Hunter2-hunter")
+        .map(KeyEvent::from)
+        .collect();
+    events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    events.append(&mut text_to_events!("abc\n").map(KeyEvent::from).collect());
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_strength_fn(&crate::PasswordStrength::estimate),
+        events,
+    );
+
+    assert_eq!("abc", ans);
+    assert!(output.contains("Strength: Weak"));
+    assert!(output.contains("Strength: Strong"));
+}
+
+#[test]
+fn strength_meter_is_hidden_for_empty_input_and_confirmation() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:").with_strength_fn(&|_| crate::PasswordStrength::Fair),
+        text_to_events!("\n\n").map(KeyEvent::from).collect(),
+    );
+
+    assert_eq!("", ans);
+    assert!(!output.contains("Strength"));
+}
+
+#[test]
+fn strength_meter_does_not_block_submission() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_strength_fn(&|_| crate::PasswordStrength::Weak),
+        text_to_events!("a\n").map(KeyEvent::from).collect(),
+    );
+
+    assert_eq!("a", ans);
+    assert!(output.contains("Strength: Weak"));
+}
+
+type TestBackend<'a> = Backend<'a, CrosstermTerminal<'a>>;
+
+fn type_into(prompt: &mut super::prompt::PasswordPrompt<'_>, text: &str) {
+    use crate::prompts::prompt::Prompt;
+
+    for c in text.chars() {
+        let action = super::PasswordPromptAction::ValueInput(crate::InputAction::Write(c));
+        <super::prompt::PasswordPrompt<'_> as Prompt<TestBackend<'_>>>::handle(prompt, action)
+            .unwrap();
+    }
+}
+
+#[test]
+fn canceling_clears_buffers() {
+    use crate::prompts::prompt::Prompt;
+
+    let mut prompt = super::prompt::PasswordPrompt::from(Password::new("Password:"));
+    type_into(&mut prompt, "secret");
+    <super::prompt::PasswordPrompt<'_> as Prompt<TestBackend<'_>>>::submit(&mut prompt).unwrap();
+    type_into(&mut prompt, "sec");
+    assert!(!prompt.is_cleared());
+
+    let can_cancel =
+        <super::prompt::PasswordPrompt<'_> as Prompt<TestBackend<'_>>>::pre_cancel(&mut prompt)
+            .unwrap();
+
+    assert!(can_cancel);
+    assert!(prompt.is_cleared());
+}
+
+#[test]
+fn interrupting_clears_buffers() {
+    use crate::prompts::prompt::Prompt;
+
+    let mut prompt = super::prompt::PasswordPrompt::from(Password::new("Password:"));
+    type_into(&mut prompt, "secret");
+    assert!(!prompt.is_cleared());
+
+    <super::prompt::PasswordPrompt<'_> as Prompt<TestBackend<'_>>>::pre_interrupt(&mut prompt)
+        .unwrap();
+
+    assert!(prompt.is_cleared());
+}
+
+#[test]
+fn canceled_hidden_prompt_never_writes_input() {
+    for interrupt in [
+        KeyEvent::from(KeyCode::Esc),
+        KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+    ] {
+        let mut events: Vec<KeyEvent> = text_to_events!("QWXQWX").map(KeyEvent::from).collect();
+        events.push(interrupt);
+        let mut read = events.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let ans = {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            Password::new("Password:")
+                .without_confirmation()
+                .prompt_with_backend(&mut backend)
+        };
+
+        assert!(ans.is_err());
+        let output = String::from_utf8(write).unwrap();
+        for c in "QWX".chars() {
+            assert!(!output.contains(c), "output contains {:?}: {:?}", c, output);
+        }
+    }
+}
+
+#[test]
+fn stdin_fallback_reads_single_line_without_confirmation() {
+    let formatted = std::cell::Cell::new(false);
+    let formatter = |ans: &str| {
+        formatted.set(true);
+        ans.to_owned()
+    };
+
+    let ans = Password::new("Password:")
+        .with_stdin_fallback(true)
+        .with_formatter(&formatter)
+        .prompt_with_line_terminal(crate::terminal::line::LineTerminal::new("hunter2\r\n"))
+        .unwrap();
+
+    assert_eq!("hunter2", ans);
+    assert!(formatted.get());
+}
+
+#[test]
+fn stdin_fallback_still_runs_validators() {
+    let ans = Password::new("Password:")
+        .with_stdin_fallback(true)
+        .with_validator(|ans: &str| match ans.len() {
+            len if len >= 8 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Too short".into())),
+        })
+        .prompt_with_line_terminal(crate::terminal::line::LineTerminal::new("hunter2\n"));
+
+    assert!(matches!(ans, Err(crate::InquireError::IO(_))));
+}
+
+#[test]
+fn help_message_on_empty_only_disappears_when_typing() {
+    let mut events: Vec<KeyEvent> = text_to_events!("ab").map(KeyEvent::from).collect();
+    events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    events.append(&mut text_to_events!("c\n").map(KeyEvent::from).collect());
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_help_message("press enter when done")
+            .with_help_message_on_empty_only(true),
+        events,
+    );
+
+    assert_eq!("c", ans);
+    // rendered on the first frame and again after ctrl+u, but not while typing
+    assert_eq!(2, output.matches("press enter when done").count());
+}
+
+password_test!(
+    boxed_validator,
+    text_to_events!("ab\nabc\n"),
+    "abc",
+    Password::new("").without_confirmation().with_validator({
+        let min = 3;
+        let validator: Box<dyn crate::validator::StringValidator> =
+            Box::new(move |input: &str| match input.len() >= min {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(ErrorMessage::Default)),
+            });
+        validator
+    })
+);
+
+#[test]
+fn pasted_control_chars_are_ignored() {
+    use super::{config::PasswordConfig, PasswordPromptAction};
+    use crate::{
+        ui::{Key, KeyModifiers},
+        Action, InnerAction, InputAction,
+    };
+
+    let config: PasswordConfig = (&Password::new("")).into();
+
+    // pasted text is received as chars, while the enter key is received as such
+    for c in ['\n', '\r', '\t', '\u{7}'] {
+        assert_eq!(
+            None,
+            Action::<PasswordPromptAction>::from_key(Key::Char(c, KeyModifiers::NONE), &config)
+        );
+    }
+    assert_eq!(
+        Some(PasswordPromptAction::ValueInput(InputAction::Write('a'))),
+        PasswordPromptAction::from_key(Key::Char('a', KeyModifiers::NONE), &config)
+    );
+    assert_eq!(
+        Some(Action::Submit),
+        Action::<PasswordPromptAction>::from_key(Key::Enter, &config)
+    );
+}
+
+password_test!(
+    typed_control_chars_are_ignored,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("hunter2\t\u{7}").collect());
+        events.push(KeyCode::Enter);
+        events
+    },
+    "hunter2",
+    Password::new("").without_confirmation()
+);
+
+#[test]
+fn trailing_new_line_char_does_not_submit() {
+    let mut events: Vec<KeyEvent> = "hunter2\n"
+        .chars()
+        .map(|c| KeyEvent::from(KeyCode::Char(c)))
+        .collect();
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let read_keys = std::cell::Cell::new(0);
+    let mut read = events
+        .iter()
+        .inspect(|_| read_keys.set(read_keys.get() + 1));
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        Password::new("")
+            .without_confirmation()
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!("hunter2", ans);
+    // submitted by the enter key, not by the new line char
+    assert_eq!(events.len(), read_keys.get());
+}
+
+#[test]
+fn numeric_only_rejects_non_digits_with_bell() {
+    let events: Vec<KeyEvent> = text_to_events!("12a3\n").map(KeyEvent::from).collect();
+
+    let (ans, output) = prompt_with_output(
+        Password::new("PIN:")
+            .without_confirmation()
+            .with_numeric_only(true),
+        events,
+    );
+
+    assert_eq!("123", ans);
+    assert_eq!(1, output.matches('\u{7}').count());
+    assert!(output.contains("PIN: •••"));
+}
+
+password_test!(
+    exact_length_submits_without_enter,
+    text_to_events!("1234"),
+    "1234",
+    Password::new("")
+        .without_confirmation()
+        .with_numeric_only(true)
+        .with_exact_length(4)
+);
+
+password_test!(
+    exact_length_submits_each_confirmation_step,
+    text_to_events!("12341234"),
+    "1234",
+    Password::new("")
+        .with_numeric_only(true)
+        .with_exact_length(4)
+);
+
+#[test]
+fn exact_length_resumes_editing_when_validator_fails() {
+    let mut events: Vec<KeyEvent> = text_to_events!("0000").map(KeyEvent::from).collect();
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(KeyEvent::from(KeyCode::Char('1')));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("PIN:")
+            .without_confirmation()
+            .with_numeric_only(true)
+            .with_exact_length(4)
+            .with_validator(|input: &str| match input {
+                "0000" => Ok(Validation::Invalid("Too easy to guess.".into())),
+                _ => Ok(Validation::Valid),
+            }),
+        events,
+    );
+
+    assert_eq!("0001", ans);
+    assert!(output.contains("Too easy to guess."));
+}
+
+#[test]
+fn exact_length_rejects_incomplete_answers() {
+    let events: Vec<KeyEvent> = text_to_events!("12\n34").map(KeyEvent::from).collect();
+
+    let (ans, output) = prompt_with_output(
+        Password::new("PIN:")
+            .without_confirmation()
+            .with_numeric_only(true)
+            .with_exact_length(4),
+        events,
+    );
+
+    assert_eq!("1234", ans);
+    assert!(output.contains("Please enter exactly 4 digits."));
+}
+
+#[test]
+fn reveal_last_char_until_next_key() {
+    let mut events: Vec<KeyEvent> = text_to_events!("abd").map(KeyEvent::from).collect();
+    events.push(KeyEvent::from(KeyCode::Left));
+    events.push(KeyEvent::from(KeyCode::Char('c')));
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .without_confirmation()
+            .with_display_mode(crate::PasswordDisplayMode::Masked)
+            .with_reveal_last_char(true),
+        events,
+    );
+
+    assert_eq!("abd", ans);
+    assert!(output.contains("Password: a"));
+    assert!(output.contains("Password: *b"));
+    assert!(output.contains("Password: **d"));
+    // moving the cursor masks the last character again
+    assert!(output.contains("Password: ***"));
+    // characters typed in the middle of the input are revealed too
+    assert!(output.contains("Password: **c*"));
+}
+
+#[test]
+fn revealed_char_is_masked_after_submit() {
+    let (ans, output) = prompt_with_output(
+        Password::new("Password:")
+            .with_display_mode(crate::PasswordDisplayMode::Masked)
+            .with_reveal_last_char(true)
+            .with_formatter(&|_| String::from("done"))
+            .with_validator(|input: &str| match input.len() < 3 {
+                true => Ok(Validation::Invalid("Too short".into())),
+                false => Ok(Validation::Valid),
+            }),
+        text_to_events!("ab\nc\nabc\n")
+            .map(KeyEvent::from)
+            .collect(),
+    );
+
+    assert_eq!("abc", ans);
+
+    // the input is masked again when it is rejected, when moving on to the
+    // confirmation step and when the answer is finally rendered.
+    let rejected = output.find("Too short").unwrap();
+    assert!(output[rejected..].starts_with("Too short\r\n? Password: ** "));
+    assert!(output.contains("? Password: *** \r\n? Confirmation:  "));
+    let last = output.rfind("Password: ").unwrap();
+    assert!(output[last..].starts_with("Password: done"));
+}
+
+#[test]
+fn raw_mode_fallback_reads_answer_and_confirmation() {
+    let mut lines = vec!["secret\n", "secret\n"].into_iter();
+    let mut write: Vec<u8> = Vec::new();
+
+    let ans = Password::new("Password:")
+        .prompt_without_raw_mode(&mut write, || Ok(lines.next().unwrap().to_owned()))
+        .unwrap();
+
+    assert_eq!("secret", ans);
+    assert_eq!(
+        "Password: \nConfirmation: \n",
+        String::from_utf8(write).unwrap()
+    );
+}
+
+#[test]
+fn raw_mode_fallback_asks_again_on_mismatched_confirmation() {
+    let mut lines = vec!["secret\n", "other\n", "secret\n", "secret\n"].into_iter();
+    let mut write: Vec<u8> = Vec::new();
+
+    let ans = Password::new("Password:")
+        .prompt_without_raw_mode(&mut write, || Ok(lines.next().unwrap().to_owned()))
+        .unwrap();
+
+    assert_eq!("secret", ans);
+    assert_eq!(
+        "Password: \nConfirmation: \nThe answers don't match.\nPassword: \nConfirmation: \n",
+        String::from_utf8(write).unwrap()
+    );
+}
+
+#[test]
+fn raw_mode_fallback_asks_again_on_invalid_answer() {
+    let mut lines = vec!["abc\n", "abcdef\n"].into_iter();
+    let mut write: Vec<u8> = Vec::new();
+
+    let ans = Password::new("Password:")
+        .without_confirmation()
+        .with_validator(|input: &str| match input.len() {
+            0..=5 => Ok(Validation::Invalid("Too short.".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_without_raw_mode(&mut write, || Ok(lines.next().unwrap().to_owned()))
+        .unwrap();
+
+    assert_eq!("abcdef", ans);
+    assert_eq!(
+        "Password: \nToo short.\nPassword: \n",
+        String::from_utf8(write).unwrap()
+    );
+}
+
+#[test]
+fn raw_mode_fallback_fails_when_echo_cannot_be_disabled() {
+    let ans = Password::new("Password:").prompt_without_raw_mode(&mut Vec::new(), || {
+        Err(std::io::Error::from_raw_os_error(25))
+    });
+
+    assert!(matches!(ans, Err(crate::InquireError::NotInteractive)));
+}
+
+#[cfg(feature = "async")]
+mod async_validators {
+    use std::{
+        cell::Cell,
+        future::Future,
+        pin::Pin,
+        rc::Rc,
+        task::{Context, Poll},
+    };
+
+    use super::*;
+    use crate::InquireError;
+
+    /// Future that is only ready after being polled a given number of times.
+    struct PendingFor(usize);
+
+    impl Future for PendingFor {
+        type Output = Result<Validation, crate::error::CustomUserError>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.0 == 0 {
+                return Poll::Ready(Ok(Validation::Valid));
+            }
+
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn rejection_resumes_editing() {
+        let (ans, output) = prompt_with_output(
+            Password::new("Password:")
+                .without_confirmation()
+                .with_async_validator(|input: &str| {
+                    let input = input.to_owned();
+                    async move {
+                        match input.as_str() {
+                            "leaked" => Ok(Validation::Invalid("This password has leaked".into())),
+                            _ => Ok(Validation::Valid),
+                        }
+                    }
+                }),
+            text_to_events!("leaked\nsecret\n")
+                .map(KeyEvent::from)
+                .collect(),
+        );
+
+        assert_eq!("secret", ans);
+        assert!(output.contains("This password has leaked"));
+    }
+
+    #[test]
+    fn spinner_is_shown_and_input_ignored_while_validating() {
+        let (ans, output) = prompt_with_output(
+            Password::new("Password:")
+                .without_confirmation()
+                .with_async_validator(|_: &str| PendingFor(2)),
+            text_to_events!("secret\nxy").map(KeyEvent::from).collect(),
+        );
+
+        assert_eq!("secret", ans);
+        assert!(output.contains("⠋ Validating..."));
+        assert!(output.contains("⠙ Validating..."));
+    }
+
+    #[test]
+    fn ctrl_c_interrupts_validation() {
+        let mut events: Vec<KeyEvent> = text_to_events!("secret\n").map(KeyEvent::from).collect();
+        events.push(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        let mut read = events.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Password::new("Password:")
+            .without_confirmation()
+            .with_async_validator(|_: &str| std::future::pending())
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+    }
+
+    #[test]
+    fn runs_once_before_confirmation() {
+        let calls = Rc::new(Cell::new(0));
+
+        let (ans, _) = prompt_with_output(
+            Password::new("Password:").with_async_validator({
+                let calls = Rc::clone(&calls);
+                move |_: &str| {
+                    calls.set(calls.get() + 1);
+                    PendingFor(0)
+                }
+            }),
+            text_to_events!("secret\nsecret\n")
+                .map(KeyEvent::from)
+                .collect(),
+        );
+
+        assert_eq!("secret", ans);
+        assert_eq!(1, calls.get());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn validators_run_in_the_calling_tokio_runtime() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let _guard = runtime.enter();
+
+        let (ans, _) = prompt_with_output(
+            Password::new("Password:")
+                .without_confirmation()
+                .with_async_validator(|_: &str| async {
+                    match tokio::runtime::Handle::try_current() {
+                        Ok(_) => Ok(Validation::Valid),
+                        Err(_) => Ok(Validation::Invalid("No runtime".into())),
+                    }
+                }),
+            text_to_events!("secret\n").map(KeyEvent::from).collect(),
+        );
+
+        assert_eq!("secret", ans);
+    }
+}
@@ -1,6 +1,11 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use crate::{
+    error::InquireResult,
+    input::InputActionResult,
+    ui::{CommonBackend, Key},
+    InquireError,
+};
 
 use super::action::{Action, InnerAction};
 
@@ -14,6 +19,10 @@ pub enum ActionResult {
     /// The action either didn't result in a state change or the state
     /// change does not require a redraw.
     Clean,
+
+    /// The action completed the answer, which should be submitted as if
+    /// the user had pressed enter.
+    NeedsSubmit,
 }
 
 impl From<InputActionResult> for ActionResult {
@@ -61,6 +70,12 @@ where
         Ok(())
     }
 
+    /// Hook called right after `setup`. Returns whether the answer should be
+    /// submitted right away, before waiting for any key press.
+    fn submit_on_start(&self) -> bool {
+        false
+    }
+
     /// Hook called when an input to cancel the prompt is triggered.
     ///
     /// Returns whether the prompt can be terminated.
@@ -68,6 +83,12 @@ where
         Ok(true)
     }
 
+    /// Hook called when an input to interrupt the prompt is triggered,
+    /// right before the prompt returns.
+    fn pre_interrupt(&mut self) -> InquireResult<()> {
+        Ok(())
+    }
+
     /// Hook called when the user submits the answer to the prompt.
     ///
     /// On success, it should return `Some(ReturnType)` when the user
@@ -79,6 +100,18 @@ where
     /// On `Err(*)`, the prompt is teared down.
     fn submit(&mut self) -> InquireResult<Option<Self::Output>>;
 
+    /// Variant of [`submit`](Prompt::submit) called by the prompt loop, with access
+    /// to the backend in order to render frames while the answer is processed, e.g.
+    /// to display the progress of a slow validation.
+    ///
+    /// Defaults to calling `submit`.
+    fn submit_with_backend(
+        &mut self,
+        _backend: &mut Backend,
+    ) -> InquireResult<Option<Self::Output>> {
+        self.submit()
+    }
+
     /// Entrypoint for any business logic for the prompt. Returns the result
     /// of the action. If the result is `Clean`, the prompt will
     /// not be re-rendered.
@@ -90,6 +123,19 @@ where
     /// to the prompt, which will then be submitted to this method just the same.
     fn handle(&mut self, action: Self::InnerAction) -> InquireResult<ActionResult>;
 
+    /// Variant of [`handle`](Prompt::handle) called by the prompt loop, with access
+    /// to the backend in order to render frames while the action is processed, e.g.
+    /// to display a loading message while slow work is done.
+    ///
+    /// Defaults to calling `handle`.
+    fn handle_with_backend(
+        &mut self,
+        action: Self::InnerAction,
+        _backend: &mut Backend,
+    ) -> InquireResult<ActionResult> {
+        self.handle(action)
+    }
+
     /// Hook called for the rendering of the prompt UI.
     ///
     /// The implementation should **not** call neither `frame_setup` or
@@ -104,8 +150,19 @@ where
     fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
         self.setup()?;
 
-        let mut last_handle = ActionResult::NeedsRedraw;
+        let mut last_handle = match self.submit_on_start() {
+            true => ActionResult::NeedsSubmit,
+            false => ActionResult::NeedsRedraw,
+        };
+
         let final_answer = loop {
+            if let ActionResult::NeedsSubmit = last_handle {
+                if let Some(answer) = self.submit_with_backend(backend)? {
+                    break answer;
+                }
+                last_handle = ActionResult::NeedsRedraw;
+            }
+
             if let ActionResult::NeedsRedraw = last_handle {
                 backend.frame_setup()?;
                 self.render(backend)?;
@@ -114,12 +171,19 @@ where
             }
 
             let key = backend.read_key()?;
+
+            // the frame is laid out again for the new size of the terminal.
+            if key == Key::Resize {
+                last_handle = ActionResult::NeedsRedraw;
+                continue;
+            }
+
             let action = Action::from_key(key, self.config());
 
             if let Some(action) = action {
                 last_handle = match action {
                     Action::Submit => {
-                        if let Some(answer) = self.submit()? {
+                        if let Some(answer) = self.submit_with_backend(backend)? {
                             break answer;
                         }
                         ActionResult::NeedsRedraw
@@ -136,8 +200,13 @@ where
 
                         ActionResult::NeedsRedraw
                     }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
+                    Action::Interrupt => {
+                        self.pre_interrupt()?;
+                        return Err(InquireError::OperationInterrupted);
+                    }
+                    Action::Inner(inner_action) => {
+                        self.handle_with_backend(inner_action, backend)?
+                    }
                 };
             }
         };
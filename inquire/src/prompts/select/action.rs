@@ -14,24 +14,62 @@ pub enum SelectPromptAction {
     MoveUp,
     /// Moves the cursor to the option below.
     MoveDown,
+    /// Moves the cursor to the option on the left, when laid out in columns.
+    MoveLeft,
+    /// Moves the cursor to the option on the right, when laid out in columns.
+    MoveRight,
     /// Moves the cursor to the page above.
     PageUp,
     /// Moves the cursor to the page below.
     PageDown,
+    /// Moves the cursor half a page above.
+    HalfPageUp,
+    /// Moves the cursor half a page below.
+    HalfPageDown,
     /// Moves the cursor to the start of the list.
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Activates the filter input when in vim mode.
+    StartFiltering,
+    /// Moves the cursor to the option at the 1-based position of a typed digit.
+    QuickSelect(usize),
+    /// Moves the cursor to the next option starting with the letter.
+    MoveToLetter(char),
+    /// Moves the cursor to the option clicked at the given position of the
+    /// terminal screen, submitting it when it was already highlighted.
+    Click {
+        /// Column of the click.
+        column: u16,
+        /// Row of the click.
+        row: u16,
+    },
+    /// Submits the single option left by the filter input, when auto-select
+    /// is enabled.
+    AcceptSingle,
 }
 
 impl InnerAction for SelectPromptAction {
     type Config = SelectConfig;
 
     fn from_key(key: Key, config: &SelectConfig) -> Option<Self> {
-        if config.vim_mode {
+        let vim_normal_mode = config.vim_mode && !config.vim_filtering;
+
+        if vim_normal_mode {
             let action = match key {
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
                 Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) if config.inline => Some(Self::MoveUp),
+                Key::Char('l', KeyModifiers::NONE) if config.inline => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) if config.columns > 1 => Some(Self::MoveLeft),
+                Key::Char('l', KeyModifiers::NONE) if config.columns > 1 => Some(Self::MoveRight),
+                Key::Char('g', KeyModifiers::NONE) => Some(Self::MoveToStart),
+                Key::Char('G', KeyModifiers::NONE | KeyModifiers::SHIFT) => Some(Self::MoveToEnd),
+                Key::Char('u', KeyModifiers::CONTROL) => Some(Self::HalfPageUp),
+                Key::Char('d', KeyModifiers::CONTROL) => Some(Self::HalfPageDown),
+                Key::Char('/', KeyModifiers::NONE) if config.filter_input_enabled => {
+                    Some(Self::StartFiltering)
+                }
                 _ => None,
             };
 
@@ -49,7 +87,31 @@ impl InnerAction for SelectPromptAction {
             Key::PageDown => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Click { column, row } if config.mouse => Self::Click { column, row },
+            Key::ScrollUp if config.mouse => Self::MoveUp,
+            Key::ScrollDown if config.mouse => Self::MoveDown,
+
+            Key::Left(KeyModifiers::NONE) | Key::BackTab if config.inline => Self::MoveUp,
+            Key::Right(KeyModifiers::NONE) | Key::Tab if config.inline => Self::MoveDown,
+            Key::Tab if config.auto_select_single => Self::AcceptSingle,
+
+            Key::Left(KeyModifiers::NONE) if config.columns > 1 => Self::MoveLeft,
+            Key::Right(KeyModifiers::NONE) if config.columns > 1 => Self::MoveRight,
+
+            Key::Char(c @ '1'..='9', KeyModifiers::NONE) if config.quick_select_digits => {
+                Self::QuickSelect(c as usize - '0' as usize)
+            }
+
+            Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT)
+                if config.letter_navigation && !c.is_control() =>
+            {
+                Self::MoveToLetter(c)
+            }
+
+            _ if !config.filter_input_enabled => return None,
             key => match InputAction::from_key(key, &()) {
+                // in vim mode, typing only filters after pressing '/'.
+                Some(InputAction::Write(_)) if vim_normal_mode => return None,
                 Some(action) => Self::FilterInput(action),
                 None => return None,
             },
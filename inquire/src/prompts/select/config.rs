@@ -5,18 +5,56 @@ use crate::Select;
 pub struct SelectConfig {
     /// Whether to use vim-style keybindings.
     pub vim_mode: bool,
+    /// Whether the filter input was activated by pressing `/` in vim mode,
+    /// making the vim keybindings type into it instead.
+    pub vim_filtering: bool,
+    /// Whether moving past the first or last option wraps to the other end.
+    pub wrap_around: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether typing filters the options.
+    pub filter_input_enabled: bool,
+    /// Whether typing a letter moves the cursor to the next option starting with it.
+    pub letter_navigation: bool,
+    /// Whether typing a digit from 1 to 9 moves the cursor to the option at that position.
+    pub quick_select_digits: bool,
+    /// Whether quick selecting an option submits it.
+    pub quick_select_submit: bool,
+    /// Whether the single option left by the filter input is submitted by
+    /// typing on or pressing tab.
+    pub auto_select_single: bool,
+    /// Whether a single option is submitted without waiting for a key press.
+    pub submit_if_single_option: bool,
+    /// Number of columns the options are laid out in.
+    pub columns: usize,
+    /// Whether the options are displayed on the prompt line.
+    pub inline: bool,
+    /// Whether typing more characters only scores the options that matched the previous input.
+    pub incremental_filtering: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether mouse clicks and wheel scrolls are handled.
+    pub mouse: bool,
 }
 
 impl<T> From<&Select<'_, T>> for SelectConfig {
     fn from(value: &Select<'_, T>) -> Self {
         Self {
             vim_mode: value.vim_mode,
+            vim_filtering: false,
+            wrap_around: value.wrap_around,
             page_size: value.page_size,
             reset_cursor: value.reset_cursor,
+            incremental_filtering: value.incremental_filtering,
+            filter_input_enabled: value.filter_input_enabled && !value.inline,
+            letter_navigation: value.letter_navigation,
+            quick_select_digits: value.quick_select_digits,
+            quick_select_submit: value.quick_select_submit,
+            columns: value.columns,
+            inline: value.inline,
+            auto_select_single: value.auto_select_single,
+            submit_if_single_option: value.submit_if_single_option,
+            mouse: value.mouse,
         }
     }
 }
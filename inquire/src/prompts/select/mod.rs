@@ -6,27 +6,22 @@ mod prompt;
 mod test;
 
 pub use action::*;
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
-    formatter::OptionFormatter,
+    formatter::{FilteredOptionFormatter, OptionFormatter},
     list_option::ListOption,
-    prompts::prompt::Prompt,
+    prompts::{prompt::Prompt, CaseMatching},
     terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    type_aliases::{Filter, Highlighter, OptionProvider, Previewer, Scorer, Sorter},
+    ui::{Backend, IndexPrefix, RenderConfig, SelectBackend},
+    SelectableVariants,
 };
 
 use self::prompt::SelectPrompt;
 
-#[cfg(feature = "fuzzy")]
-use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
-#[cfg(feature = "fuzzy")]
-use once_cell::sync::Lazy;
-#[cfg(feature = "fuzzy")]
-static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
 /// Prompt suitable for when you need the user to select one option among many.
 ///
 /// The user can select and submit the current highlighted option by pressing enter.
@@ -36,19 +31,36 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 ///
 /// This prompt does not support custom validators because of its nature. A submission always selects exactly one of the options. If this option was not supposed to be selected or is invalid in some way, it probably should not be included in the options list.
 ///
-/// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
+/// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa), unless wrap-around navigation is disabled.
 ///
 /// Like all others, this prompt also allows you to customize several aspects of it:
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
-/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the cursor starts at the last option.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+/// - **Filter in answer**: Whether the text of the filter input is displayed along with the final answer. Formatters set with `with_filtered_formatter` also receive it.
 ///   - Prints the selected option string value by default.
-/// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Page size**: Number of options displayed at once, 7 by default. The displayed window scrolls to keep the cursor in view. Must be greater than zero. When there are options above or below the page, the first and last displayed options are prefixed with scroll indicators.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Filter function**: Function that defines if an option is displayed, replacing the scorer when set. Receives the option itself, so it can match on data its string value doesn't show.
+/// - **Sorter function**: Function that defines the order the options are displayed in, set with `with_sorter`. The indexes of the options keep referring to the original list.
+/// - **Disabled options**: Options that are displayed but can not be selected, skipped by the cursor. An optional reason is displayed when the user tries to select them.
+/// - **Group headers**: Non-selectable headers rendered above groups of options, which can be created with `new_grouped`.
+/// - **Option descriptions**: Descriptions displayed below the list for the highlighted option, set with `with_descriptions`.
+/// - **Aliases**: Hidden strings matched by the filter input along with each option, set with `with_aliases`.
+/// - **Columns**: Options can be laid out in a grid with `with_columns`, navigated with the arrow keys.
+/// - **Inline**: Options can be displayed on the prompt line with `with_inline`, for choices among a few options.
+/// - **Option provider**: Options can be loaded on demand with `from_provider`, for sources too large to be loaded up front.
+/// - **Auto-select**: The single option left by the filter input can be submitted by typing on or pressing tab with `with_auto_select_single`.
+/// - **Mouse**: Options can be clicked and scrolled with the mouse when enabled with `with_mouse`.
+/// - **Preview**: Details of the highlighted option displayed below the list, computed by a function set with `with_preview`.
+/// - **Filter input**: Whether typing filters the options, enabled by default. It can be disabled with `without_filtering`.
+/// - **Letter navigation**: Whether typing a letter moves the cursor to the next option starting with it, disabled by default. Requires the filter input to be disabled.
+/// - **Quick select digits**: Whether typing a digit from 1 to 9 moves the cursor to the option at that position, optionally submitting it, disabled by default. Requires the filter input to be disabled.
+/// - **Highlighter function**: Function that finds the characters of each option matching the filter input, which are rendered with the `filter_match` style of the `RenderConfig`. By default, it follows the default scorer.
 ///
 /// # Example
 ///
@@ -66,8 +78,6 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 ///     Err(_) => println!("There was an error, please try again"),
 /// }
 /// ```
-///
-/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
 #[derive(Clone)]
 pub struct Select<'a, T> {
     /// Message to be presented to the user.
@@ -83,23 +93,140 @@ pub struct Select<'a, T> {
     pub page_size: usize,
 
     /// Whether vim mode is enabled. When enabled, the user can
-    /// navigate through the options using hjkl.
+    /// navigate through the options using j/k, g/G and ctrl+d/ctrl+u,
+    /// and typing only filters the options after pressing `/`.
     pub vim_mode: bool,
 
-    /// Starting cursor index of the selection.
+    /// Whether moving up from the first option moves the cursor to the last
+    /// one, and moving down from the last option moves it to the first one.
+    /// Defaults to true.
+    pub wrap_around: bool,
+
+    /// Starting cursor index of the selection, clamped to the last option.
+    /// When the options are sorted, it is the position in the sorted list.
     pub starting_cursor: usize,
 
+    /// Index of the option the cursor starts at, found by its value. When set,
+    /// it takes precedence over the starting cursor.
+    pub starting_option: Option<usize>,
+
     /// Reset cursor position to first option on filter input change.
     /// Defaults to true.
+    ///
+    /// Regardless of this setting, the cursor is moved to the first option
+    /// when the scores reorder the displayed options.
     pub reset_cursor: bool,
 
+    /// Whether typing more characters only scores the options that matched
+    /// the previous input, instead of all of them. Does not apply to filters.
+    /// Defaults to true.
+    ///
+    /// This assumes that an option not matching an input does not match any
+    /// input starting with it, which holds for the default scorer.
+    pub incremental_filtering: bool,
+
     /// Function called with the current user input to score the provided
     /// options.
     pub scorer: Scorer<'a, T>,
 
+    /// Function called with the current user input to filter the provided
+    /// options. When set, it replaces the scorer.
+    pub filter: Option<Filter<'a, T>>,
+
+    /// Function that sorts the options for display. The indexes of the options
+    /// keep referring to the original list.
+    pub sorter: Option<Sorter<'a, T>>,
+
+    /// Function called with the current user input to find the characters
+    /// of the displayed options to be highlighted. When None, no characters
+    /// are highlighted.
+    pub highlighter: Option<Highlighter<'a, T>>,
+
+    /// Function called to load the options on demand, in batches, instead of
+    /// providing them all up front. When set, the options are filtered by the
+    /// provider instead of the scorer or the filter.
+    pub option_provider: Option<OptionProvider<'a, T>>,
+
+    /// Indexes of the options that can not be selected, along with an optional
+    /// reason displayed when the user tries to select them.
+    pub disabled_options: BTreeMap<usize, Option<&'a str>>,
+
+    /// Headers of groups of options, rendered above the option at their
+    /// index, which is the first of the group.
+    pub group_headers: BTreeMap<usize, &'a str>,
+
+    /// Descriptions of options, indexed by the position of the option. The
+    /// description of the highlighted option is displayed below the list.
+    pub descriptions: BTreeMap<usize, &'a str>,
+
+    /// Hidden aliases of options, in the same order as the options, which the
+    /// filter input matches along with the string values of the options.
+    pub aliases: Vec<Vec<String>>,
+
+    /// Function that computes the preview of the highlighted option, displayed
+    /// below the list.
+    pub previewer: Option<Previewer<'a, T>>,
+
+    /// Maximum number of lines of the preview. Defaults to 5.
+    pub preview_height: usize,
+
+    /// Whether the options are filtered by the text typed by the user.
+    /// Defaults to true.
+    pub filter_input_enabled: bool,
+
+    /// Whether typing a letter moves the cursor to the next option starting
+    /// with it. Can not be enabled along with the filter input.
+    /// Defaults to false.
+    pub letter_navigation: bool,
+
+    /// Whether typing a digit from 1 to 9 moves the cursor to the option at
+    /// that position. Can not be enabled along with the filter input.
+    /// Defaults to false.
+    pub quick_select_digits: bool,
+
+    /// Whether quick selecting an option with a digit also submits it.
+    /// Defaults to false.
+    pub quick_select_submit: bool,
+
+    /// Whether the option is submitted as soon as typing into the filter
+    /// input leaves a single option displayed. Defaults to false.
+    pub auto_select_single: bool,
+
+    /// Whether the option is submitted right away, without displaying the
+    /// list, when there is a single option. Defaults to false.
+    pub submit_if_single_option: bool,
+
+    /// Number of columns the options are laid out in. With more than one
+    /// column, options fill the grid row by row. The layout falls back to a
+    /// single column while the filter input is not empty.
+    /// Can not be used along with group headers. Defaults to 1.
+    pub columns: usize,
+
+    /// Whether the options are displayed on the prompt line, separated by
+    /// slashes, instead of in a list. Filtering is disabled in this mode.
+    /// Can not be used along with columns, group headers or an option
+    /// provider. Defaults to false.
+    pub inline: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Function that formats the answer along with the text of the filter
+    /// input when it was submitted. When set, it replaces the formatter.
+    pub filtered_formatter: Option<FilteredOptionFormatter<'a, T>>,
+
+    /// Whether the text of the filter input is displayed along with the
+    /// final answer, when not empty. Defaults to false.
+    pub show_filter_in_answer: bool,
+
+    /// Strings displayed for the options instead of their `Display`
+    /// implementation, set by `new_from_enum` from the labels of the variants.
+    labels: Vec<String>,
+
+    /// Whether the options can be clicked and scrolled with the mouse.
+    /// Defaults to false.
+    pub mouse: bool,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -133,9 +260,16 @@ where
     /// Default scoring function, which will create a score for the current option using the input value.
     /// The return will be sorted in Descending order, leaving options with None as a score.
     ///
+    /// With the `fuzzy` feature, enabled by default, options are fuzzy matched against the
+    /// input, so "fbr" matches "foo-bar". Otherwise, options containing the input are kept
+    /// in their original order. Matching ignores case in both modes.
+    ///
     /// # Examples
     ///
-    /// ```
+    /// With the `fuzzy` feature:
+    ///
+    #[cfg_attr(feature = "fuzzy", doc = "```")]
+    #[cfg_attr(not(feature = "fuzzy"), doc = "```ignore")]
     /// use inquire::Select;
     ///
     /// let scorer = Select::<&str>::DEFAULT_SCORER;
@@ -153,20 +287,27 @@ where
     /// assert_eq!(None,     scorer("sa", &"Jacksonville",  "Jacksonville",  11));
     /// assert_eq!(Some(49), scorer("sa", &"San Jose",      "San Jose",      12));
     /// ```
-    #[cfg(feature = "fuzzy")]
     pub const DEFAULT_SCORER: Scorer<'a, T> =
         &|input, _option, string_value, _idx| -> Option<i64> {
-            DEFAULT_MATCHER.fuzzy_match(string_value, input)
+            CaseMatching::Insensitive.score(input, string_value)
         };
 
-    #[cfg(not(feature = "fuzzy"))]
-    pub const DEFAULT_SCORER: Scorer<'a, T> =
-        &|input, _option, string_value, _idx| -> Option<i64> {
-            let filter = input.to_lowercase();
-            match string_value.to_lowercase().contains(&filter) {
-                true => Some(0),
-                false => None,
-            }
+    /// Default highlighting function, which returns the indices of the characters
+    /// of the option matched by the default scorer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let highlighter = Select::<&str>::DEFAULT_HIGHLIGHTER;
+    /// assert_eq!(vec![0, 1], highlighter("sa", &"San Diego", "San Diego", 0));
+    /// assert_eq!(vec![2, 3], highlighter("sa", &"Mesa", "Mesa", 1));
+    /// assert_eq!(Vec::<usize>::new(), highlighter("sa", &"Dallas", "Dallas", 2));
+    /// ```
+    pub const DEFAULT_HIGHLIGHTER: Highlighter<'a, T> =
+        &|input, _option, string_value, _idx| -> Vec<usize> {
+            CaseMatching::Insensitive.highlight(input, string_value)
         };
 
     /// Default page size.
@@ -175,6 +316,9 @@ where
     /// Default value of vim mode.
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
+    /// Default value of wrap-around navigation.
+    pub const DEFAULT_WRAP_AROUND: bool = true;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
@@ -182,26 +326,221 @@ where
     /// Defaults to true.
     pub const DEFAULT_RESET_CURSOR: bool = true;
 
+    /// Default value of incremental filtering.
+    pub const DEFAULT_INCREMENTAL_FILTERING: bool = true;
+
+    /// Default value of the filter input being enabled.
+    pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
+
+    /// Default value of letter navigation.
+    pub const DEFAULT_LETTER_NAVIGATION: bool = false;
+
+    /// Default value of quick select digits.
+    pub const DEFAULT_QUICK_SELECT_DIGITS: bool = false;
+
+    /// Default value of submitting options quick selected with digits.
+    pub const DEFAULT_QUICK_SELECT_SUBMIT: bool = false;
+
+    /// Default value of submitting the single option left by the filter input.
+    pub const DEFAULT_AUTO_SELECT_SINGLE: bool = false;
+
+    /// Default value of submitting the option of single-option lists.
+    pub const DEFAULT_SUBMIT_IF_SINGLE_OPTION: bool = false;
+
+    /// Default maximum number of lines of the preview.
+    pub const DEFAULT_PREVIEW_HEIGHT: usize = 5;
+
+    /// Default number of columns.
+    pub const DEFAULT_COLUMNS: usize = 1;
+
+    /// Default value of displaying the options on the prompt line.
+    pub const DEFAULT_INLINE: bool = false;
+
+    /// Default value of displaying the filter input along with the answer.
+    pub const DEFAULT_SHOW_FILTER_IN_ANSWER: bool = false;
+
+    /// Default value of mouse support.
+    pub const DEFAULT_MOUSE: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter, ESC to cancel");
 
     /// Creates a [Select] with the provided message and options, along with default configuration values.
-    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+    ///
+    /// The options can be any iterable of displayable values, such as a `Vec`,
+    /// an array, a range or the keys of a map, and are collected internally.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("Port:", 8080..=8085).prompt();
+    /// ```
+    pub fn new<I>(message: &'a str, options: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
         Self {
             message,
-            options,
+            options: options.into_iter().collect(),
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
             vim_mode: Self::DEFAULT_VIM_MODE,
+            wrap_around: Self::DEFAULT_WRAP_AROUND,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            starting_option: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
+            incremental_filtering: Self::DEFAULT_INCREMENTAL_FILTERING,
             scorer: Self::DEFAULT_SCORER,
+            filter: None,
+            sorter: None,
+            highlighter: Some(Self::DEFAULT_HIGHLIGHTER),
+            option_provider: None,
+            disabled_options: BTreeMap::new(),
+            group_headers: BTreeMap::new(),
+            descriptions: BTreeMap::new(),
+            aliases: vec![],
+            previewer: None,
+            preview_height: Self::DEFAULT_PREVIEW_HEIGHT,
+            filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            letter_navigation: Self::DEFAULT_LETTER_NAVIGATION,
+            quick_select_digits: Self::DEFAULT_QUICK_SELECT_DIGITS,
+            quick_select_submit: Self::DEFAULT_QUICK_SELECT_SUBMIT,
+            auto_select_single: Self::DEFAULT_AUTO_SELECT_SINGLE,
+            submit_if_single_option: Self::DEFAULT_SUBMIT_IF_SINGLE_OPTION,
+            columns: Self::DEFAULT_COLUMNS,
+            inline: Self::DEFAULT_INLINE,
             formatter: Self::DEFAULT_FORMATTER,
+            filtered_formatter: None,
+            show_filter_in_answer: Self::DEFAULT_SHOW_FILTER_IN_ANSWER,
+            labels: vec![],
+            mouse: Self::DEFAULT_MOUSE,
             render_config: get_configuration(),
         }
     }
 
+    /// Creates a [Select] with the provided message, whose options are loaded
+    /// on demand by the provider, along with default configuration values.
+    ///
+    /// The provider is called with the current filter input, an offset and
+    /// a limit. It is called when the filter input changes and when the
+    /// cursor gets close to the end of the loaded options, while a loading
+    /// message is displayed.
+    ///
+    /// As only part of the options is loaded, the index of the selection
+    /// refers to the position of the option among the ones loaded for the
+    /// submitted filter input, not to a position in the whole source.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let provider = |filter: &str, offset: usize, limit: usize| {
+    ///     (0..1_000_000)
+    ///         .map(|n| format!("row-{n}"))
+    ///         .filter(|row| row.contains(filter))
+    ///         .skip(offset)
+    ///         .take(limit)
+    ///         .collect()
+    /// };
+    ///
+    /// let ans = Select::from_provider("Row:", &provider).prompt();
+    /// ```
+    pub fn from_provider(message: &'a str, option_provider: OptionProvider<'a, T>) -> Self {
+        let mut select = Self::new(message, vec![]);
+        select.option_provider = Some(option_provider);
+        select
+    }
+
+    /// Creates a [Select] with the provided message and groups of options, each
+    /// preceded by its header, along with default configuration values.
+    ///
+    /// The options are flattened in order, so the index of the selection refers
+    /// to the position of the option among all options, not counting headers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new_grouped(
+    ///     "Host:",
+    ///     vec![
+    ///         ("Production", vec!["prod-1", "prod-2"]),
+    ///         ("Staging", vec!["staging-1"]),
+    ///     ],
+    /// )
+    /// .prompt();
+    /// ```
+    pub fn new_grouped(message: &'a str, groups: Vec<(&'a str, Vec<T>)>) -> Self {
+        let mut options = vec![];
+        let mut group_headers = BTreeMap::new();
+
+        for (header, group) in groups {
+            if !group.is_empty() {
+                group_headers.insert(options.len(), header);
+            }
+            options.extend(group);
+        }
+
+        let mut select = Self::new(message, options);
+        select.group_headers = group_headers;
+        select
+    }
+
+    /// Creates a [Select] listing the variants of an enum, along with default
+    /// configuration values. The variants are displayed with their labels, both
+    /// in the list and in the answer, and the selected one is returned directly.
+    ///
+    /// The type of the enum is set on the prompt, as in
+    /// `Select::<Conflict>::new_from_enum`, or inferred from the answer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use std::fmt::{Display, Formatter};
+    ///
+    /// use inquire::{Select, SelectableVariants};
+    ///
+    /// #[derive(SelectableVariants)]
+    /// enum Conflict {
+    ///     Overwrite,
+    ///     Skip,
+    ///     #[inquire(label = "Abort the copy")]
+    ///     Abort,
+    /// }
+    ///
+    /// impl Display for Conflict {
+    ///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    ///         match self {
+    ///             Self::Overwrite => write!(f, "Overwrite"),
+    ///             Self::Skip => write!(f, "Skip"),
+    ///             Self::Abort => write!(f, "Abort"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let ans: Conflict = Select::new_from_enum("File exists:").prompt().unwrap();
+    /// # }
+    /// ```
+    pub fn new_from_enum(message: &'a str) -> Self
+    where
+        T: SelectableVariants,
+    {
+        let options = T::variants();
+        let labels = options.iter().map(T::label).collect();
+
+        let mut select = Self::new(message, options);
+        select.labels = labels;
+        select.formatter = &|ans| ans.value.label();
+        select
+    }
+
     /// Sets the help message of the prompt.
     pub fn with_help_message(mut self, message: &'a str) -> Self {
         self.help_message = Some(message);
@@ -214,44 +553,578 @@ where
         self
     }
 
-    /// Sets the page size.
+    /// Sets the page size, the maximum number of options displayed at once.
+    ///
+    /// When there are more options than fit in a page, the displayed window
+    /// scrolls one row at a time to keep the cursor in view, centered when
+    /// possible. A page size of zero makes the prompt fail with an
+    /// [`InquireError::InvalidConfiguration`] error.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
     }
 
     /// Enables or disables vim_mode.
+    ///
+    /// In vim mode, j/k move the cursor one row, g/G move it to the first and last
+    /// options, and ctrl+u/ctrl+d move it half a page. As these conflict with the
+    /// filter input, typed characters only filter the options after pressing `/`,
+    /// and ESC clears the filter and brings the vim keybindings back.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
+    /// Enables or disables wrap-around navigation, in which moving up from the
+    /// first option moves the cursor to the last one and vice-versa. The page
+    /// displayed follows the cursor to the other end of the list.
+    ///
+    /// When disabled, the cursor stops at the first and last options.
+    /// Defaults to true.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
     /// Sets the scoring function.
+    ///
+    /// If an option that does not match an input can match an input starting
+    /// with it, e.g. when matching whole words, incremental filtering must be
+    /// disabled with [`with_incremental_filtering`](Select::with_incremental_filtering).
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
         self
     }
 
-    /// Sets the formatter.
+    /// Sets the filter function, which replaces the scorer in deciding which options
+    /// are displayed. Returning `true` keeps the option visible, and the visible
+    /// options keep their order.
+    ///
+    /// Filters are always applied to all options, as they commonly match whole
+    /// codes or aliases, so incremental filtering does not apply to them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let options = vec!["New York", "Los Angeles", "San Francisco"];
+    /// let aliases = ["nyc", "la", "sf"];
+    ///
+    /// let ans = Select::new("City:", options)
+    ///     .with_filter(&|input, option, _string_value, idx| {
+    ///         let input = input.to_lowercase();
+    ///         option.to_lowercase().contains(&input) || aliases[idx] == input
+    ///     })
+    ///     .prompt();
+    /// ```
+    pub fn with_filter(mut self, filter: Filter<'a, T>) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the function that sorts the options for display, without changing
+    /// their indexes: the answer, disabled options and descriptions keep
+    /// referring to the original list, while the starting cursor is a position
+    /// in the sorted list.
+    ///
+    /// The filter input and the pages operate on the sorted list, and options
+    /// with the same score keep their sorted order. Can not be used along with
+    /// group headers or an option provider.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let branches = vec!["main", "feature/login", "bugfix/crash"];
+    ///
+    /// let ans = Select::new("Branch:", branches)
+    ///     .with_sorter(&|a, b| a.cmp(b))
+    ///     .raw_prompt();
+    /// ```
+    pub fn with_sorter(mut self, sorter: Sorter<'a, T>) -> Self {
+        self.sorter = Some(sorter);
+        self
+    }
+
+    /// Sets the function that finds the characters of each option to be
+    /// highlighted as matching the filter input.
+    ///
+    /// Consider setting it along with a custom scorer or filter, as the default
+    /// highlighter follows the default scorer.
+    pub fn with_highlighter(mut self, highlighter: Highlighter<'a, T>) -> Self {
+        self.highlighter = Some(highlighter);
+        self
+    }
+
+    /// Disables the highlighting of the characters matching the filter input.
+    pub fn without_highlighter(mut self) -> Self {
+        self.highlighter = None;
+        self
+    }
+
+    /// Sets how the filter input is matched against the case of the options,
+    /// replacing the scorer, and the highlighter when highlighting is enabled,
+    /// with the built-in ones following the given mode. Aliases are matched
+    /// through the scorer, so they follow the same mode.
+    ///
+    /// Call [`Select::with_scorer`] or [`Select::with_highlighter`] afterwards
+    /// to customize them. Default is [`CaseMatching::Insensitive`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::{CaseMatching, Select};
+    ///
+    /// let objects = vec!["configmap/App", "configmap/app", "secret/App"];
+    ///
+    /// let ans = Select::new("Object:", objects)
+    ///     .with_filter_case(CaseMatching::Smart)
+    ///     .raw_prompt();
+    /// ```
+    pub fn with_filter_case(mut self, filter_case: CaseMatching) -> Self {
+        self.scorer = match filter_case {
+            CaseMatching::Sensitive => &|input, _option, string_value, _idx| {
+                CaseMatching::Sensitive.score(input, string_value)
+            },
+            CaseMatching::Insensitive => Self::DEFAULT_SCORER,
+            CaseMatching::Smart => {
+                &|input, _option, string_value, _idx| CaseMatching::Smart.score(input, string_value)
+            }
+        };
+
+        if self.highlighter.is_some() {
+            self.highlighter = Some(match filter_case {
+                CaseMatching::Sensitive => &|input, _option, string_value, _idx| {
+                    CaseMatching::Sensitive.highlight(input, string_value)
+                },
+                CaseMatching::Insensitive => Self::DEFAULT_HIGHLIGHTER,
+                CaseMatching::Smart => &|input, _option, string_value, _idx| {
+                    CaseMatching::Smart.highlight(input, string_value)
+                },
+            });
+        }
+
+        self
+    }
+
+    /// Marks the options at the provided indexes as disabled. Disabled options
+    /// are still displayed, with the `disabled_option` style of the `RenderConfig`,
+    /// but are skipped by the cursor and can not be submitted.
+    ///
+    /// Indexes out-of-range of the option list are ignored. If all options are
+    /// disabled, the prompt fails with an [`InquireError::InvalidConfiguration`] error.
+    pub fn with_disabled_indices(mut self, indices: &[usize]) -> Self {
+        for index in indices {
+            self.disabled_options.entry(*index).or_insert(None);
+        }
+        self
+    }
+
+    /// Marks the option at the provided index as disabled, like
+    /// [`with_disabled_indices`](Self::with_disabled_indices), along with the
+    /// reason displayed as an error when the user tries to select it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("Environment:", vec!["Development", "Staging", "Production"])
+    ///     .with_disabled_option(2, "Deploying to production requires approval")
+    ///     .prompt();
+    /// ```
+    pub fn with_disabled_option(mut self, index: usize, reason: &'a str) -> Self {
+        self.disabled_options.insert(index, Some(reason));
+        self
+    }
+
+    /// Sets a header to be rendered above the option at the provided index,
+    /// starting a group that goes until the next header.
+    ///
+    /// Headers are not selectable, are skipped by the cursor and take a row of
+    /// the page. While filtering, headers of groups without matching options
+    /// are hidden.
+    pub fn with_group_header(mut self, index: usize, header: &'a str) -> Self {
+        self.group_headers.insert(index, header);
+        self
+    }
+
+    /// Sets the descriptions of the options, in the same order as the options.
+    ///
+    /// The description of the highlighted option is displayed on a line below
+    /// the list, updated as the cursor moves. Options beyond the length of
+    /// the slice have no description.
+    pub fn with_descriptions(mut self, descriptions: &[&'a str]) -> Self {
+        self.descriptions = descriptions.iter().cloned().enumerate().collect();
+        self
+    }
+
+    /// Sets the hidden aliases of the options, in the same order as the options.
+    /// Options beyond the length of the list have no aliases.
+    ///
+    /// Aliases are not displayed, but the filter input matches them along with
+    /// the string value of each option, which gets the best score among them.
+    /// The scorer and the filter function receive each alias in place of the
+    /// string value. When an option matches better through an alias, the alias
+    /// is displayed next to it. Aliases of options loaded by a provider are
+    /// ignored, as the provider does the filtering.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let regions = vec!["Frankfurt (eu-central-1)", "Amsterdam (eu-west-4)"];
+    /// let aliases = vec![vec!["fra1".to_string()], vec!["ams3".to_string()]];
+    ///
+    /// let ans = Select::new("Region:", regions).with_aliases(aliases).prompt();
+    /// ```
+    pub fn with_aliases(mut self, aliases: Vec<Vec<String>>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Sets the function that computes the preview of the highlighted option.
+    ///
+    /// The preview is displayed below the list and updated as the cursor moves.
+    /// It is capped to the preview height, and lines wider than the terminal
+    /// are truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let profiles = vec!["default", "staging", "production"];
+    ///
+    /// let ans = Select::new("Profile:", profiles)
+    ///     .with_preview(&|profile| format!("region: eu-west-1\nprofile: {}", profile))
+    ///     .prompt();
+    /// ```
+    pub fn with_preview(mut self, previewer: Previewer<'a, T>) -> Self {
+        self.previewer = Some(previewer);
+        self
+    }
+
+    /// Sets the maximum number of lines of the preview.
+    pub fn with_preview_height(mut self, preview_height: usize) -> Self {
+        self.preview_height = preview_height;
+        self
+    }
+
+    /// Sets the description of the option at the provided index.
+    pub fn with_description(mut self, index: usize, description: &'a str) -> Self {
+        self.descriptions.insert(index, description);
+        self
+    }
+
+    /// Disables the filter input, so that typing does not filter the options.
+    /// Useful for small static menus, e.g. along with letter navigation.
+    pub fn without_filtering(mut self) -> Self {
+        self.filter_input_enabled = false;
+        self
+    }
+
+    /// Enables or disables letter navigation, in which typing a letter moves the
+    /// cursor to the next option whose string value starts with it, ignoring case.
+    /// Typing the same letter again cycles through the options starting with it.
+    ///
+    /// As it conflicts with the filter input, it requires calling
+    /// [`without_filtering`](Self::without_filtering). Otherwise, the prompt
+    /// fails with an [`InquireError::InvalidConfiguration`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("Action:", vec!["Deploy", "Rollback", "Cancel"])
+    ///     .without_filtering()
+    ///     .with_letter_navigation(true)
+    ///     .prompt();
+    /// ```
+    pub fn with_letter_navigation(mut self, letter_navigation: bool) -> Self {
+        self.letter_navigation = letter_navigation;
+        self
+    }
+
+    /// Enables or disables quick select digits, in which typing a digit from 1 to 9
+    /// moves the cursor to the option at that position. Digits beyond the number
+    /// of options are ignored.
+    ///
+    /// The positions are displayed before each option, as in `1) Deploy`, unless
+    /// the render config already sets an [`IndexPrefix`].
+    ///
+    /// As it conflicts with the filter input, it requires calling
+    /// [`without_filtering`](Self::without_filtering). Otherwise, the prompt
+    /// fails with an [`InquireError::InvalidConfiguration`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("Action:", vec!["Deploy", "Rollback"])
+    ///     .without_filtering()
+    ///     .with_quick_select_digits(true)
+    ///     .with_quick_select_submit(true)
+    ///     .prompt();
+    /// ```
+    pub fn with_quick_select_digits(mut self, quick_select_digits: bool) -> Self {
+        self.quick_select_digits = quick_select_digits;
+        self
+    }
+
+    /// Enables or disables submitting an option as soon as it is quick selected
+    /// with a digit. Only applies when quick select digits are enabled.
+    pub fn with_quick_select_submit(mut self, quick_select_submit: bool) -> Self {
+        self.quick_select_submit = quick_select_submit;
+        self
+    }
+
+    /// Enables or disables submitting the option left displayed once typing
+    /// into the filter input narrows the list down to it.
+    ///
+    /// The option is first rendered alone, and submitted when the next typed
+    /// character keeps it alone or when tab is pressed. A prefix of the
+    /// intended input that momentarily matches a single option is then not
+    /// submitted as long as the typing goes on to match another one.
+    /// Deleting characters never submits, and lists that have a single option
+    /// from the start are never submitted by it, see
+    /// [`with_submit_if_single_option`](Select::with_submit_if_single_option).
+    ///
+    /// Defaults to false.
+    pub fn with_auto_select_single(mut self, auto_select_single: bool) -> Self {
+        self.auto_select_single = auto_select_single;
+        self
+    }
+
+    /// Enables or disables submitting the option right away when there is a
+    /// single option, without waiting for the user to press enter.
+    /// Defaults to false.
+    pub fn with_submit_if_single_option(mut self, submit_if_single_option: bool) -> Self {
+        self.submit_if_single_option = submit_if_single_option;
+        self
+    }
+
+    /// Sets the number of columns the options are laid out in.
+    ///
+    /// Options fill the grid row by row, the left and right arrow keys move
+    /// across columns and the up and down arrow keys move within them. The
+    /// page size is the number of rows of the grid.
+    ///
+    /// While the filter input is not empty, options are displayed in a single
+    /// column and the left and right arrow keys move the cursor of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let codes = vec!["BR", "CA", "DE", "FR", "JP", "PT", "US"];
+    /// let ans = Select::new("Country:", codes).with_columns(4).prompt();
+    /// ```
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets whether the options are displayed on the prompt line, separated
+    /// by slashes, with the highlighted one styled as the selected option.
+    ///
+    /// The left and right arrow keys, as well as tab and shift-tab, move the
+    /// cursor across the options, cycling from one end to the other
+    /// regardless of wrap-around navigation, and enter submits the highlighted
+    /// one. The
+    /// filter input is disabled and, unless a custom help message is set, no
+    /// help line is displayed. The answer is displayed as in the list mode.
+    ///
+    /// Meant for choices among a few options, it can not be used along with
+    /// columns, group headers or an option provider.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("File exists:", vec!["overwrite", "skip", "abort"])
+    ///     .with_inline(true)
+    ///     .prompt();
+    /// ```
+    pub fn with_inline(mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Enables or disables mouse support.
+    ///
+    /// When enabled, clicking an option moves the cursor to it and clicking
+    /// the highlighted option submits it, so double-clicking an option selects
+    /// it. Scrolling the mouse wheel moves the cursor up and down.
+    ///
+    /// Mouse events are only reported by the crossterm terminal backend. While
+    /// they are captured, selecting text with the mouse usually requires holding
+    /// a modifier key, such as shift, depending on the terminal emulator.
+    /// Defaults to false.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("Fruit:", vec!["Apple", "Banana", "Cherry"])
+    ///     .with_mouse(true)
+    ///     .prompt();
+    /// ```
+    pub fn with_mouse(mut self, mouse: bool) -> Self {
+        self.mouse = mouse;
+        self
+    }
+
+    /// Sets the formatter, which receives the submitted option along with its
+    /// index and returns the text displayed as the answer of the prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let cities = vec!["Amsterdam", "Frankfurt", "Lisbon"];
+    /// let total = cities.len();
+    ///
+    /// let ans = Select::new("Region:", cities)
+    ///     .with_formatter(&|option| {
+    ///         format!("option {} of {}: {}", option.index + 1, total, option.value)
+    ///     })
+    ///     .prompt();
+    /// ```
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
-    /// Sets the starting cursor index.
+    /// Sets the formatter that also receives the text of the filter input when
+    /// the answer was submitted, allowing the final answer to describe what
+    /// was searched for. It replaces the formatter.
+    pub fn with_filtered_formatter(mut self, formatter: FilteredOptionFormatter<'a, T>) -> Self {
+        self.filtered_formatter = Some(formatter);
+        self
+    }
+
+    /// Enables or disables displaying the text of the filter input along with
+    /// the final answer, e.g. `web-frankfurt-03 (filtered: "frank")`. Nothing is
+    /// added when the filter input is empty or a filtered formatter is set.
+    /// Defaults to false.
+    pub fn with_filter_in_answer(mut self, show_filter_in_answer: bool) -> Self {
+        self.show_filter_in_answer = show_filter_in_answer;
+        self
+    }
+
+    /// Sets the starting cursor index, e.g. to highlight a previous answer when
+    /// asking the user to change it. The first page displayed includes it.
+    ///
+    /// Indexes out-of-range of the option list are clamped to the last option.
+    /// When the options are sorted, it is the position in the sorted list.
     pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
         self.starting_cursor = starting_cursor;
+        self.starting_option = None;
+        self
+    }
+
+    /// Starts the cursor at the first option equal to the provided one, e.g.
+    /// a previous answer, regardless of where it ends up being displayed.
+    /// The first page displayed includes it.
+    ///
+    /// When no option is equal to it, the cursor starts at the first option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let previous = "staging";
+    /// let environments = vec!["development", "staging", "production"];
+    ///
+    /// let ans = Select::new("Environment:", environments)
+    ///     .with_starting_choice(&previous)
+    ///     .prompt();
+    /// ```
+    pub fn with_starting_choice(self, choice: &T) -> Self
+    where
+        T: PartialEq,
+    {
+        self.with_starting_choice_by(|option| option == choice)
+    }
+
+    /// Starts the cursor at the first option for which the predicate returns
+    /// true, for options that can not be compared with `PartialEq`. The first
+    /// page displayed includes it.
+    ///
+    /// When the predicate returns false for all options, the cursor starts at
+    /// the first option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fmt;
+    /// use inquire::Select;
+    ///
+    /// struct Service {
+    ///     name: &'static str,
+    ///     port: u16,
+    /// }
+    ///
+    /// impl fmt::Display for Service {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{} ({})", self.name, self.port)
+    ///     }
+    /// }
+    ///
+    /// let services = vec![
+    ///     Service { name: "web", port: 8080 },
+    ///     Service { name: "api", port: 3000 },
+    /// ];
+    ///
+    /// let ans = Select::new("Service:", services)
+    ///     .with_starting_choice_by(|service| service.name == "api")
+    ///     .prompt();
+    /// ```
+    pub fn with_starting_choice_by<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.starting_option = self.options.iter().position(predicate);
+        self.starting_cursor = 0;
         self
     }
 
     /// Sets the reset_cursor behaviour.
     /// Will reset cursor to first option on filter input change.
     /// Defaults to true.
+    ///
+    /// Regardless of this setting, the cursor is moved to the first option
+    /// when the scores reorder the displayed options.
     pub fn with_reset_cursor(mut self, reset_cursor: bool) -> Self {
         self.reset_cursor = reset_cursor;
         self
     }
 
+    /// Enables or disables incremental filtering, in which typing more
+    /// characters only scores the options that matched the previous input.
+    /// Deleting characters scores all options again.
+    ///
+    /// It keeps filtering fast on large lists of options, but assumes that an
+    /// option not matching an input does not match any input starting with it.
+    /// This holds for the default scorer, but not for scorers that, for instance,
+    /// match whole words or aliases. Filters always check all options.
+    /// Defaults to true.
+    pub fn with_incremental_filtering(mut self, incremental_filtering: bool) -> Self {
+        self.incremental_filtering = incremental_filtering;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -280,14 +1153,15 @@ where
     /// the prompt - by pressing ESC - is considered normal behavior. In this case,
     /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
     ///
+    /// When the filter input is not empty, ESC clears it instead and a second
+    /// press is needed to skip the prompt.
+    ///
     /// Meanwhile, if the user does submit an answer, the method wraps the return
     /// type with `Some`.
     pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
-        match self.prompt() {
-            Ok(answer) => Ok(Some(answer)),
-            Err(InquireError::OperationCanceled) => Ok(None),
-            Err(err) => Err(err),
-        }
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.prompt_render_config())?;
+        self.prompt_skippable_with_backend(&mut backend)
     }
 
     /// Parses the provided behavioral and rendering options and prompts
@@ -295,16 +1169,49 @@ where
     ///
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
+    ///
+    /// The index refers to the original list of options, regardless of the
+    /// filter input or of the page being displayed.
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
         let terminal = get_default_terminal()?;
-        let mut backend = Backend::new(terminal, self.render_config)?;
+        let mut backend = Backend::new(terminal, self.prompt_render_config())?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Render config used by the prompt, which displays the positions of the
+    /// options when quick select digits are enabled.
+    fn prompt_render_config(&self) -> RenderConfig<'a> {
+        let mut render_config = self.render_config;
+
+        if self.quick_select_digits && render_config.option_index_prefix == IndexPrefix::None {
+            render_config.option_index_prefix = IndexPrefix::Simple;
+        }
+
+        render_config
+    }
+
+    pub(crate) fn prompt_skippable_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Option<T>> {
+        match self.prompt_with_backend(backend) {
+            Ok(answer) => Ok(Some(answer.value)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        let mouse = self.mouse;
+        let prompt = SelectPrompt::new(self)?;
+
+        if mouse {
+            backend.enable_mouse_capture()?;
+        }
+
+        prompt.prompt(backend)
     }
 }
@@ -1,30 +1,69 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     error::InquireResult,
-    formatter::OptionFormatter,
-    input::{Input, InputActionResult},
+    formatter::{FilteredOptionFormatter, OptionFormatter},
+    input::{Input, InputActionResult, LineDirection, Magnitude},
     list_option::ListOption,
     prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    type_aliases::{Filter, Highlighter, OptionProvider, Previewer, Scorer},
+    ui::{OptionRow, SelectBackend},
+    utils::{paginate, Page},
+    validator::ErrorMessage,
+    InputAction, InquireError, Select,
 };
 
 use super::{action::SelectPromptAction, config::SelectConfig};
 
+const NO_MATCHES_HELP_MESSAGE: &str = "No options match the filter, press ESC to clear it";
+const DISABLED_OPTION_ERROR_MESSAGE: &str = "This option can not be selected";
+const LOADING_OPTIONS_MESSAGE: &str = "Loading…";
+const OPTION_PROVIDER_BATCH_SIZE: usize = 100;
+
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
     config: SelectConfig,
     options: Vec<T>,
     string_options: Vec<String>,
     scored_options: Vec<usize>,
+    /// Indexes of the options in display order, when sorted.
+    sorted_options: Vec<usize>,
+    /// Display position of each option, when sorted.
+    sorted_positions: Vec<usize>,
+    score_buffer: Vec<(usize, i64)>,
+    scored_input: String,
+    column_width: usize,
+    disabled_options: BTreeMap<usize, Option<&'a str>>,
+    group_headers: BTreeMap<usize, &'a str>,
+    descriptions: BTreeMap<usize, &'a str>,
+    aliases: Vec<Vec<String>>,
+    previewer: Option<Previewer<'a, T>>,
+    preview_height: usize,
+    error: Option<ErrorMessage>,
     help_message: Option<&'a str>,
     cursor_index: usize,
     input: Input,
+    last_letter: Option<char>,
     scorer: Scorer<'a, T>,
+    filter: Option<Filter<'a, T>>,
+    highlighter: Option<Highlighter<'a, T>>,
     formatter: OptionFormatter<'a, T>,
+    filtered_formatter: Option<FilteredOptionFormatter<'a, T>>,
+    show_filter_in_answer: bool,
+    option_provider: Option<OptionProvider<'a, T>>,
+    provider_exhausted: bool,
+    needs_load: bool,
+    loading: bool,
+    /// Option left alone by the last character typed into the filter, which
+    /// is submitted when the next one keeps it alone.
+    single_match: Option<usize>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -32,51 +71,362 @@ where
     T: Display,
 {
     pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
+        if so.options.is_empty() && so.option_provider.is_none() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
 
-        if so.starting_cursor >= so.options.len() {
-            return Err(InquireError::InvalidConfiguration(format!(
-                "Starting cursor index {} is out-of-bounds for length {} of options",
-                so.starting_cursor,
-                &so.options.len()
-            )));
+        if so.letter_navigation && so.filter_input_enabled && !so.inline {
+            return Err(InquireError::InvalidConfiguration(
+                "Letter navigation can not be enabled along with the filter input".into(),
+            ));
+        }
+
+        if so.quick_select_digits && so.filter_input_enabled && !so.inline {
+            return Err(InquireError::InvalidConfiguration(
+                "Quick select digits can not be enabled along with the filter input".into(),
+            ));
+        }
+
+        if so.option_provider.is_none()
+            && (0..so.options.len()).all(|i| so.disabled_options.contains_key(&i))
+        {
+            return Err(InquireError::InvalidConfiguration(
+                "At least one option must not be disabled".into(),
+            ));
+        }
+
+        if so.columns == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Number of columns must be greater than zero".into(),
+            ));
+        }
+
+        if so.columns > 1 && !so.group_headers.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Group headers can not be used along with multiple columns".into(),
+            ));
+        }
+
+        if so.inline
+            && (so.columns > 1 || !so.group_headers.is_empty() || so.option_provider.is_some())
+        {
+            return Err(InquireError::InvalidConfiguration(
+                "Inline options can not be used along with columns, group headers or an option provider".into(),
+            ));
+        }
+
+        if so.page_size == 0 {
+            return Err(InquireError::InvalidConfiguration(
+                "Page size must be greater than zero".into(),
+            ));
+        }
+
+        if so.sorter.is_some() && (!so.group_headers.is_empty() || so.option_provider.is_some()) {
+            return Err(InquireError::InvalidConfiguration(
+                "Options can not be sorted along with group headers or an option provider".into(),
+            ));
+        }
+
+        let string_options = match so.labels.is_empty() {
+            true => so.options.iter().map(T::to_string).collect(),
+            false => so.labels.clone(),
+        };
+
+        let mut sorted_options: Vec<usize> = vec![];
+        let mut sorted_positions = vec![];
+        if let Some(sorter) = so.sorter {
+            sorted_options.extend(0..so.options.len());
+            sorted_options.sort_by(|a, b| sorter(&so.options[*a], &so.options[*b]));
+
+            sorted_positions.resize(so.options.len(), 0);
+            for (position, index) in sorted_options.iter().enumerate() {
+                sorted_positions[*index] = position;
+            }
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let scored_options = (0..so.options.len()).collect();
+        let cursor_index = match so.starting_option {
+            Some(index) if index < so.options.len() => {
+                sorted_positions.get(index).copied().unwrap_or(index)
+            }
+            _ => so.starting_cursor.min(so.options.len().saturating_sub(1)),
+        };
+
+        let scored_options = match sorted_options.is_empty() {
+            true => (0..so.options.len()).collect(),
+            false => sorted_options.clone(),
+        };
 
-        Ok(Self {
+        let mut prompt = Self {
             message: so.message,
             config: (&so).into(),
             options: so.options,
             string_options,
             scored_options,
-            help_message: so.help_message,
-            cursor_index: so.starting_cursor,
+            sorted_options,
+            sorted_positions,
+            score_buffer: vec![],
+            scored_input: String::new(),
+            column_width: 0,
+            disabled_options: so.disabled_options,
+            group_headers: so.group_headers,
+            descriptions: so.descriptions,
+            aliases: so.aliases,
+            previewer: so.previewer,
+            preview_height: so.preview_height,
+            error: None,
+            // the default help message describes the keys of the list mode.
+            help_message: match so.inline && so.help_message == Select::<T>::DEFAULT_HELP_MESSAGE {
+                true => None,
+                false => so.help_message,
+            },
+            cursor_index,
             input: Input::new(),
+            last_letter: None,
             scorer: so.scorer,
+            filter: so.filter,
+            highlighter: so.highlighter,
             formatter: so.formatter,
-        })
+            filtered_formatter: so.filtered_formatter,
+            show_filter_in_answer: so.show_filter_in_answer,
+            option_provider: so.option_provider,
+            provider_exhausted: false,
+            needs_load: false,
+            loading: false,
+            single_match: None,
+        };
+
+        if prompt.option_provider.is_some() {
+            prompt.load_options();
+        }
+
+        prompt.column_width = prompt.max_option_width();
+
+        prompt.cursor_index = prompt.closest_enabled_position(prompt.cursor_index, false, false);
+
+        Ok(prompt)
     }
 
-    fn score_options(&self) -> Vec<(usize, i64)> {
-        self.options
+    fn is_disabled(&self, position: usize) -> bool {
+        matches!(
+            self.scored_options.get(position),
+            Some(index) if self.disabled_options.contains_key(index)
+        )
+    }
+
+    /// Closest position to the provided one, in the direction of the movement,
+    /// that holds an option that is not disabled. Falls back to the opposite
+    /// direction when the movement can not go further.
+    fn closest_enabled_position(&self, position: usize, upwards: bool, wrap: bool) -> usize {
+        let len = self.scored_options.len();
+        let find = |upwards: bool, wrap: bool| {
+            (0..len)
+                .map_while(|offset| match (upwards, wrap) {
+                    (true, true) => Some((position + len - offset) % len),
+                    (true, false) => position.checked_sub(offset),
+                    (false, true) => Some((position + offset) % len),
+                    (false, false) => Some(position + offset).filter(|p| *p < len),
+                })
+                .find(|p| !self.is_disabled(*p))
+        };
+
+        find(upwards, wrap)
+            .or_else(|| find(!upwards, false))
+            .unwrap_or(position)
+    }
+
+    /// Header of the group the option belongs to, along with its index.
+    fn group_of(&self, index: usize) -> Option<(usize, &'a str)> {
+        self.group_headers
+            .range(..=index)
+            .next_back()
+            .map(|(start, header)| (*start, *header))
+    }
+
+    fn max_option_width(&self) -> usize {
+        self.string_options
             .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(
-                    self.input.content(),
-                    opt,
-                    self.string_options.get(i).unwrap(),
-                    i,
-                )
+            .map(|option| option.width())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Scores the options into the reusable buffer. With incremental filtering,
+    /// when the input extends the previously scored one, only the options that
+    /// matched it are scored.
+    fn score_options(&mut self) {
+        let input = self.input.content();
+        let narrowing = self.config.incremental_filtering
+            && self.filter.is_none()
+            && input.starts_with(self.scored_input.as_str());
+
+        let mut scores = std::mem::take(&mut self.score_buffer);
+        scores.clear();
+
+        // options are scored by the best score among their string value and
+        // their aliases.
+        let score = |i: usize| {
+            let string_value = self.string_options.get(i).unwrap();
+            let aliases = self.aliases.get(i).map(Vec::as_slice).unwrap_or_default();
+
+            std::iter::once(string_value)
+                .chain(aliases)
+                .map(|value| self.score_value(input, i, value))
+                .max()
+                .flatten()
                 .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>()
+        };
+
+        if narrowing {
+            scores.extend(self.scored_options.iter().filter_map(|i| score(*i)));
+        } else if !self.sorted_options.is_empty() {
+            scores.extend(self.sorted_options.iter().filter_map(|i| score(*i)));
+        } else {
+            scores.extend((0..self.options.len()).filter_map(score));
+        }
+
+        self.score_buffer = scores;
+        self.scored_input.clear();
+        self.scored_input.push_str(input);
+    }
+
+    /// Position of the option in the list before it is filtered.
+    fn display_position(&self, index: usize) -> usize {
+        self.sorted_positions.get(index).copied().unwrap_or(index)
+    }
+
+    fn score_value(&self, input: &str, index: usize, value: &str) -> Option<i64> {
+        let option = self.options.get(index).unwrap();
+
+        match self.filter {
+            Some(filter) => filter(input, option, value, index).then_some(0),
+            None => (self.scorer)(input, option, value, index),
+        }
+    }
+
+    /// Alias of the option that matches the filter input better than its
+    /// string value, if any.
+    fn matched_alias(&self, index: usize) -> Option<&str> {
+        let input = self.input.content();
+        if input.is_empty() || self.option_provider.is_some() {
+            return None;
+        }
+
+        let string_value_score = self.score_value(input, index, self.string_options.get(index)?);
+
+        self.aliases
+            .get(index)?
+            .iter()
+            .map(|alias| (self.score_value(input, index, alias), alias))
+            .filter(|(score, _)| *score > string_value_score)
+            .max_by_key(|(score, _)| *score)
+            .map(|(_, alias)| alias.as_str())
+    }
+
+    /// Loads the next batch of options from the provider, for the current
+    /// filter input.
+    fn load_options(&mut self) {
+        let provider = match self.option_provider {
+            Some(provider) => provider,
+            None => return,
+        };
+
+        let batch = provider(
+            self.input.content(),
+            self.options.len(),
+            OPTION_PROVIDER_BATCH_SIZE,
+        );
+
+        self.provider_exhausted = batch.len() < OPTION_PROVIDER_BATCH_SIZE;
+        self.string_options.extend(batch.iter().map(T::to_string));
+        self.options.extend(batch);
+        self.scored_options = (0..self.options.len()).collect();
+        self.column_width = self.max_option_width();
+    }
+
+    /// Whether the cursor got close enough to the end of the loaded options
+    /// for the next batch to be loaded.
+    fn is_near_end_of_loaded_options(&self) -> bool {
+        self.option_provider.is_some()
+            && !self.provider_exhausted
+            && self.cursor_index + self.config.page_size >= self.options.len()
+    }
+
+    fn refresh_scored_options(&mut self) {
+        // options loaded by a provider are already filtered, so they are
+        // discarded and loaded again for the new input.
+        if self.option_provider.is_some() {
+            self.options.clear();
+            self.string_options.clear();
+            self.scored_options.clear();
+            self.cursor_index = 0;
+            self.needs_load = true;
+            return;
+        }
+
+        self.score_options();
+        self.score_buffer
+            .sort_by_key(|(_idx, score)| Reverse(*score));
+
+        self.scored_options.clear();
+        self.scored_options
+            .extend(self.score_buffer.iter().map(|(idx, _)| *idx));
+
+        // when the scores reorder the options, the best match is highlighted
+        // even if the cursor would otherwise be kept in place.
+        let reordered = self
+            .scored_options
+            .windows(2)
+            .any(|w| self.display_position(w[0]) > self.display_position(w[1]));
+
+        if self.config.reset_cursor || reordered {
+            let _ = self.update_cursor_position(0);
+        } else if self.scored_options.len() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
+        }
+
+        let position = self.closest_enabled_position(self.cursor_index, false, false);
+        let _ = self.update_cursor_position(position);
+    }
+
+    fn highlight_option(&self, index: usize) -> Vec<usize> {
+        let input = self.input.content();
+
+        match (self.highlighter, input.is_empty()) {
+            (Some(highlighter), false) => highlighter(
+                input,
+                self.options.get(index).unwrap(),
+                self.string_options.get(index).unwrap(),
+                index,
+            ),
+            _ => vec![],
+        }
+    }
+
+    fn move_cursor_to_letter(&mut self, letter: char, repeated: bool) -> ActionResult {
+        let len = self.scored_options.len();
+        // the first press may stay on the current option, repeated ones cycle.
+        let skip = usize::from(repeated);
+        let letter = letter.to_lowercase().collect::<String>();
+
+        let target = (0..len)
+            .map(|offset| (self.cursor_index + skip + offset) % len)
+            .filter(|&position| !self.is_disabled(position))
+            .find(|&position| {
+                let option = self.scored_options[position];
+                self.string_options[option]
+                    .graphemes(true)
+                    .next()
+                    .map(str::to_lowercase)
+                    .as_deref()
+                    == Some(letter.as_str())
+            });
+
+        match target {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
     }
 
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
@@ -89,6 +439,7 @@ where
             self.cursor_index.saturating_sub(qty)
         };
 
+        let new_position = self.closest_enabled_position(new_position, true, wrap);
         self.update_cursor_position(new_position)
     }
 
@@ -105,6 +456,52 @@ where
             }
         }
 
+        let new_position = self.closest_enabled_position(new_position, false, wrap);
+        self.update_cursor_position(new_position)
+    }
+
+    /// Number of columns the options are currently laid out in, falling back
+    /// to a single column while filtering.
+    fn grid_columns(&self) -> usize {
+        if self.input.content().is_empty() {
+            self.config.columns
+        } else {
+            1
+        }
+    }
+
+    /// Moves the cursor to the row above or below, keeping its column when
+    /// laid out in a grid.
+    fn move_cursor_vertically(&mut self, upwards: bool) -> ActionResult {
+        let columns = self.grid_columns();
+        if columns == 1 {
+            // inline options are always cycled, as only one is highlighted.
+            let wrap_around = self.config.wrap_around || self.config.inline;
+            return match upwards {
+                true => self.move_cursor_up(1, wrap_around),
+                false => self.move_cursor_down(1, wrap_around),
+            };
+        }
+
+        let len = self.scored_options.len();
+        let column = self.cursor_index % columns;
+
+        let new_position = match upwards {
+            true if self.cursor_index >= columns => self.cursor_index - columns,
+            false if self.cursor_index + columns < len => self.cursor_index + columns,
+            _ if !self.config.wrap_around => return ActionResult::Clean,
+            true => {
+                // the last row might not reach this column.
+                let last_row_start = len.saturating_sub(1) / columns * columns;
+                match last_row_start + column {
+                    position if position < len => position,
+                    position => position.saturating_sub(columns),
+                }
+            }
+            false => column,
+        };
+
+        let new_position = self.closest_enabled_position(new_position, upwards, true);
         self.update_cursor_position(new_position)
     }
 
@@ -117,6 +514,38 @@ where
         }
     }
 
+    /// Moves the cursor to the clicked option, or submits it when it is
+    /// already highlighted.
+    fn click_option(&mut self, index: usize) -> ActionResult {
+        let position = match self.scored_options.iter().position(|i| *i == index) {
+            Some(position) => position,
+            None => return ActionResult::Clean,
+        };
+
+        if self.is_disabled(position) {
+            self.error = self.disabled_option_error(position);
+            return ActionResult::NeedsRedraw;
+        }
+
+        match position == self.cursor_index {
+            true => ActionResult::NeedsSubmit,
+            false => self.update_cursor_position(position),
+        }
+    }
+
+    fn has_single_selectable_option(&self) -> bool {
+        self.scored_options.len() == 1 && !self.is_disabled(0)
+    }
+
+    fn disabled_option_error(&self, position: usize) -> Option<ErrorMessage> {
+        let index = self.scored_options.get(position)?;
+        let reason = self.disabled_options.get(index)?;
+
+        Some(ErrorMessage::Custom(
+            reason.unwrap_or(DISABLED_OPTION_ERROR_MESSAGE).to_string(),
+        ))
+    }
+
     fn has_answer_highlighted(&mut self) -> bool {
         self.scored_options.get(self.cursor_index).is_some()
     }
@@ -130,6 +559,160 @@ where
 
         ListOption::new(index, value)
     }
+
+    fn render_list<B: SelectBackend>(&self, backend: &mut B) -> InquireResult<()> {
+        let rows;
+        let page = if self.group_headers.is_empty() {
+            // only the rows of the page are built, as the list can be large.
+            let positions = paginate(
+                self.config.page_size,
+                &self.scored_options,
+                Some(self.cursor_index),
+            );
+
+            rows = positions
+                .content
+                .iter()
+                .map(|i| {
+                    OptionRow::Option(ListOption::new(*i, self.string_options.get(*i).unwrap()))
+                })
+                .collect::<Vec<OptionRow<'_, &String>>>();
+
+            Page {
+                first: positions.first,
+                last: positions.last,
+                content: &rows,
+                start: positions.start,
+                cursor: positions.cursor,
+                total: positions.total,
+            }
+        } else {
+            // headers are displayed whenever the group changes between rows,
+            // so groups without visible options have their headers hidden.
+            let mut all_rows = vec![];
+            let mut cursor_row = 0;
+            let mut last_group = None;
+
+            for (position, index) in self.scored_options.iter().cloned().enumerate() {
+                let group = self.group_of(index);
+                if let Some((start, header)) = group {
+                    if last_group != Some(start) {
+                        all_rows.push(OptionRow::Header(header));
+                    }
+                }
+                last_group = group.map(|(start, _)| start);
+
+                if position == self.cursor_index {
+                    cursor_row = all_rows.len();
+                }
+                all_rows.push(OptionRow::Option(ListOption::new(
+                    index,
+                    self.string_options.get(index).unwrap(),
+                )));
+            }
+
+            rows = all_rows;
+            paginate(self.config.page_size, &rows, Some(cursor_row))
+        };
+
+        let matches = page
+            .content
+            .iter()
+            .map(|row| match row {
+                OptionRow::Option(option) => self.highlight_option(option.index),
+                OptionRow::Header(_) => vec![],
+            })
+            .collect::<Vec<Vec<usize>>>();
+
+        let aliases = page
+            .content
+            .iter()
+            .map(|row| match row {
+                OptionRow::Option(option) => self.matched_alias(option.index),
+                OptionRow::Header(_) => None,
+            })
+            .collect::<Vec<Option<&str>>>();
+
+        let disabled = page
+            .content
+            .iter()
+            .filter_map(|row| match row {
+                OptionRow::Option(option) => Some(option.index),
+                OptionRow::Header(_) => None,
+            })
+            .filter(|index| self.disabled_options.contains_key(index))
+            .collect::<BTreeSet<usize>>();
+
+        backend.render_options(page, &matches, &aliases, &disabled)?;
+
+        Ok(())
+    }
+
+    fn render_inline<B: SelectBackend>(&self, backend: &mut B) -> InquireResult<()> {
+        let options = self
+            .scored_options
+            .iter()
+            .map(|i| ListOption::new(*i, self.string_options.get(*i).unwrap()))
+            .collect::<Vec<ListOption<&String>>>();
+
+        let disabled = self
+            .disabled_options
+            .keys()
+            .copied()
+            .collect::<BTreeSet<usize>>();
+
+        backend.render_inline_options(self.message, &options, self.cursor_index, &disabled)?;
+
+        Ok(())
+    }
+
+    fn render_grid<B: SelectBackend>(&self, backend: &mut B, columns: usize) -> InquireResult<()> {
+        let chunks = self
+            .scored_options
+            .chunks(columns)
+            .collect::<Vec<&[usize]>>();
+        let chunks = paginate(
+            self.config.page_size,
+            &chunks,
+            Some(self.cursor_index / columns),
+        );
+
+        let rows = chunks
+            .content
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|i| ListOption::new(*i, self.string_options.get(*i).unwrap()))
+                    .collect::<Vec<ListOption<&String>>>()
+            })
+            .collect::<Vec<Vec<ListOption<&String>>>>();
+
+        let page = Page {
+            first: chunks.first,
+            last: chunks.last,
+            content: &rows,
+            start: chunks.start,
+            cursor: chunks.cursor,
+            total: chunks.total,
+        };
+
+        let disabled = page
+            .content
+            .iter()
+            .flatten()
+            .map(|option| option.index)
+            .filter(|index| self.disabled_options.contains_key(index))
+            .collect::<BTreeSet<usize>>();
+
+        backend.render_option_grid(
+            page,
+            self.cursor_index % columns,
+            self.column_width,
+            &disabled,
+        )?;
+
+        Ok(())
+    }
 }
 
 impl<'a, Backend, T> Prompt<Backend> for SelectPrompt<'a, T>
@@ -150,10 +733,50 @@ where
     }
 
     fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+        let filter = self.input.content();
+
+        if let Some(formatter) = self.filtered_formatter {
+            return formatter(answer.as_ref(), filter);
+        }
+
+        let formatted = (self.formatter)(answer.as_ref());
+        match self.show_filter_in_answer && !filter.is_empty() {
+            true => format!("{formatted} (filtered: \"{filter}\")"),
+            false => formatted,
+        }
+    }
+
+    fn submit_on_start(&self) -> bool {
+        self.config.submit_if_single_option
+            && self.options.len() == 1
+            && self.has_single_selectable_option()
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.input.is_empty() && !self.config.vim_filtering {
+            return Ok(true);
+        }
+
+        // the first press clears the filter, the second one cancels the prompt.
+        self.config.vim_filtering = false;
+        self.input.clear();
+        self.refresh_scored_options();
+
+        // there is no frame to display the loading message in this hook.
+        if self.needs_load {
+            self.needs_load = false;
+            self.load_options();
+        }
+
+        Ok(false)
     }
 
     fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        if let Some(error) = self.disabled_option_error(self.cursor_index) {
+            self.error = Some(error);
+            return Ok(None);
+        }
+
         let answer = match self.has_answer_highlighted() {
             true => Some(self.get_final_answer()),
             false => None,
@@ -163,53 +786,201 @@ where
     }
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
+        let last_letter = self.last_letter.take();
+        let had_error = self.error.take().is_some();
+
         let result = match action {
-            SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
-            SelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
-            SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            SelectPromptAction::QuickSelect(position) => match position.checked_sub(1) {
+                Some(index) if self.is_disabled(index) => {
+                    self.error = self.disabled_option_error(index);
+                    ActionResult::NeedsRedraw
+                }
+                Some(index) if index < self.scored_options.len() => {
+                    let _ = self.update_cursor_position(index);
+                    match self.config.quick_select_submit {
+                        true => ActionResult::NeedsSubmit,
+                        false => ActionResult::NeedsRedraw,
+                    }
+                }
+                _ => ActionResult::Clean,
+            },
+            SelectPromptAction::MoveToLetter(letter) => {
+                self.last_letter = Some(letter);
+                self.move_cursor_to_letter(letter, last_letter == Some(letter))
+            }
+            SelectPromptAction::MoveUp => self.move_cursor_vertically(true),
+            SelectPromptAction::MoveDown => self.move_cursor_vertically(false),
+            SelectPromptAction::MoveLeft | SelectPromptAction::MoveRight
+                if self.grid_columns() == 1 =>
+            {
+                // while filtering, the arrow keys move the cursor of the input.
+                let direction = match action {
+                    SelectPromptAction::MoveLeft => LineDirection::Left,
+                    _ => LineDirection::Right,
+                };
+                self.input
+                    .handle(InputAction::MoveCursor(Magnitude::Char, direction))
+                    .into()
+            }
+            SelectPromptAction::MoveLeft => self.move_cursor_up(1, self.config.wrap_around),
+            SelectPromptAction::MoveRight => self.move_cursor_down(1, self.config.wrap_around),
+            SelectPromptAction::PageUp => {
+                self.move_cursor_up(self.config.page_size * self.grid_columns(), false)
+            }
+            SelectPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size * self.grid_columns(), false)
+            }
+            SelectPromptAction::HalfPageUp => {
+                let rows = (self.config.page_size / 2).max(1);
+                self.move_cursor_up(rows * self.grid_columns(), false)
+            }
+            SelectPromptAction::HalfPageDown => {
+                let rows = (self.config.page_size / 2).max(1);
+                self.move_cursor_down(rows * self.grid_columns(), false)
+            }
+            SelectPromptAction::StartFiltering => {
+                self.config.vim_filtering = true;
+                ActionResult::NeedsRedraw
+            }
+            // clicks are resolved in `handle_with_backend`, which knows where
+            // the options were rendered.
+            SelectPromptAction::Click { .. } => ActionResult::Clean,
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
             SelectPromptAction::FilterInput(input_action) => {
                 let result = self.input.handle(input_action);
 
                 if let InputActionResult::ContentChanged = result {
-                    let mut options = self.score_options();
-                    options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
-
-                    self.scored_options = options.into_iter().map(|(idx, _)| idx).collect();
-                    if self.config.reset_cursor {
-                        let _ = self.update_cursor_position(0);
-                    } else if self.scored_options.len() <= self.cursor_index {
-                        let _ = self
-                            .update_cursor_position(self.scored_options.len().saturating_sub(1));
+                    self.refresh_scored_options();
+                }
+
+                let last_single_match = self.single_match.take();
+
+                match input_action {
+                    InputAction::Write(_)
+                        if self.config.auto_select_single
+                            && self.options.len() > 1
+                            && result.needs_redraw()
+                            && self.has_single_selectable_option() =>
+                    {
+                        // the option is rendered alone before being submitted,
+                        // so a prefix momentarily matching it does not.
+                        let index = self.scored_options[0];
+                        if last_single_match == Some(index) {
+                            ActionResult::NeedsSubmit
+                        } else {
+                            self.single_match = Some(index);
+                            result.into()
+                        }
                     }
+                    _ => result.into(),
                 }
+            }
+            SelectPromptAction::AcceptSingle => {
+                if !self.input.content().is_empty() && self.has_single_selectable_option() {
+                    self.cursor_index = 0;
+                    ActionResult::NeedsSubmit
+                } else {
+                    ActionResult::Clean
+                }
+            }
+        };
+
+        if self.is_near_end_of_loaded_options() {
+            self.needs_load = true;
+        }
+
+        match (result, had_error) {
+            (ActionResult::Clean, true) => Ok(ActionResult::NeedsRedraw),
+            _ => Ok(result),
+        }
+    }
 
-                result.into()
+    fn handle_with_backend(
+        &mut self,
+        action: SelectPromptAction,
+        backend: &mut Backend,
+    ) -> InquireResult<ActionResult> {
+        let result = match action {
+            SelectPromptAction::Click { column, row } => {
+                let had_error = self.error.take().is_some();
+                let result = match backend.option_at(column, row)? {
+                    Some(index) => self.click_option(index),
+                    None => ActionResult::Clean,
+                };
+
+                match (result, had_error) {
+                    (ActionResult::Clean, true) => ActionResult::NeedsRedraw,
+                    _ => result,
+                }
             }
+            _ => Prompt::<Backend>::handle(self, action)?,
         };
 
-        Ok(result)
+        if !self.needs_load {
+            return Ok(result);
+        }
+
+        // the provider is synchronous, so the loading message is rendered
+        // before calling it.
+        self.loading = true;
+        backend.frame_setup()?;
+        self.render(backend)?;
+        backend.frame_finish()?;
+
+        self.load_options();
+        self.needs_load = false;
+        self.loading = false;
+
+        match result {
+            ActionResult::NeedsSubmit => Ok(result),
+            _ => Ok(ActionResult::NeedsRedraw),
+        }
     }
 
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        backend.render_select_prompt(prompt, &self.input)?;
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
 
-        let choices = self
-            .scored_options
-            .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+        if self.config.inline {
+            self.render_inline(backend)?;
+        } else {
+            backend.render_select_prompt(prompt, &self.input)?;
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+            let columns = self.grid_columns();
+            if columns > 1 {
+                self.render_grid(backend, columns)?;
+            } else {
+                self.render_list(backend)?;
+            }
+        }
+
+        if let Some(description) = self
+            .scored_options
+            .get(self.cursor_index)
+            .and_then(|index| self.descriptions.get(index))
+        {
+            backend.render_option_description(description)?;
+        }
 
-        backend.render_options(page)?;
+        if let Some(previewer) = self.previewer {
+            if let Some(option) = self
+                .scored_options
+                .get(self.cursor_index)
+                .and_then(|index| self.options.get(*index))
+            {
+                backend.render_option_preview(&previewer(option), self.preview_height)?;
+            }
+        }
 
-        if let Some(help_message) = self.help_message {
+        if self.loading {
+            backend.render_help_message(LOADING_OPTIONS_MESSAGE)?;
+        } else if self.scored_options.is_empty() {
+            backend.render_help_message(NO_MATCHES_HELP_MESSAGE)?;
+        } else if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
         }
 
@@ -2,10 +2,12 @@ use crate::{
     formatter::OptionFormatter,
     list_option::ListOption,
     terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
-    Select,
+    ui::{Attributes, Backend, RenderConfig, StyleSheet},
+    CaseMatching, InquireError, Select, SelectableVariants,
+};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
 };
-use crossterm::event::{KeyCode, KeyEvent};
 
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
@@ -93,3 +95,2406 @@ fn down_arrow_on_empty_list_does_not_panic() {
 
     assert_eq!(ListOption::new(0, 1), ans);
 }
+
+#[test]
+fn custom_types_are_returned_by_value() {
+    // deliberately not Clone, the selected option is moved out of the list.
+    #[derive(Debug, PartialEq)]
+    struct Server {
+        name: &'static str,
+        port: u16,
+    }
+
+    impl std::fmt::Display for Server {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}:{}", self.name, self.port)
+        }
+    }
+
+    let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec![
+        Server {
+            name: "alpha",
+            port: 8080,
+        },
+        Server {
+            name: "beta",
+            port: 9090,
+        },
+    ];
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Server:", options)
+            .with_formatter(&|option| option.value.name.to_uppercase())
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(
+        ListOption::new(
+            1,
+            Server {
+                name: "beta",
+                port: 9090
+            }
+        ),
+        ans
+    );
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("alpha:8080"));
+    assert!(output.contains("Server: BETA"));
+}
+
+#[test]
+fn raw_prompt_index_refers_to_original_options_when_filtering() {
+    let mut read: Vec<KeyEvent> = "an"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Down));
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec!["Apple", "Banana", "Cherry", "Mango", "Orange"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the second row of the filtered list, "Banana", "Mango" and "Orange".
+    assert_eq!(ListOption::new(3, "Mango"), ans);
+}
+
+#[test]
+fn esc_clears_the_filter_before_cancelling() {
+    let mut read: Vec<KeyEvent> = "an"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Esc));
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec!["Apple", "Banana", "Cherry", "Mango", "Orange"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Apple"), ans);
+}
+
+#[test]
+fn esc_with_empty_filter_cancels_the_prompt() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('a'), KeyCode::Esc, KeyCode::Esc]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["Apple", "Banana", "Cherry"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn hint_is_shown_when_no_options_match_the_filter() {
+    let mut read: Vec<KeyEvent> = "xyz"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    read.push(KeyEvent::from(KeyCode::Backspace));
+    read.push(KeyEvent::from(KeyCode::Backspace));
+    read.push(KeyEvent::from(KeyCode::Backspace));
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec!["Apple", "Banana", "Cherry"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Fruit:", options)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(0, "Apple"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("No options match the filter, press ESC to clear it"));
+}
+
+#[test]
+fn filter_function_can_match_on_hidden_option_data() {
+    struct Country {
+        name: &'static str,
+        code: &'static str,
+    }
+
+    impl std::fmt::Display for Country {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+    }
+
+    let mut read: Vec<KeyEvent> = "de"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec![
+        Country {
+            name: "Brazil",
+            code: "br",
+        },
+        Country {
+            name: "Germany",
+            code: "de",
+        },
+        Country {
+            name: "Sweden",
+            code: "se",
+        },
+    ];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Country:", options)
+        .with_filter(&|input, option, _string_value, _idx| option.code == input)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // "Sweden" contains "de" in its name, but only the code is matched.
+    assert_eq!(1, ans.index);
+    assert_eq!("Germany", ans.value.name);
+}
+
+#[test]
+fn cursor_moves_to_best_match_when_scores_reorder_options() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Char('a'),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = vec!["a1", "a2", "a3", "a4"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Option:", options)
+        .with_reset_cursor(false)
+        .with_scorer(&|input, _option, _string_value, idx| match input {
+            "" => Some(0),
+            _ => Some(idx as i64),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, "a4"), ans);
+}
+
+#[test]
+fn cursor_is_kept_when_filtering_preserves_order() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Char('a'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["a1", "b2", "a3", "a4"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Option:", options)
+        .with_reset_cursor(false)
+        .with_filter(&|input, _option, string_value, _idx| string_value.contains(input))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "a3"), ans);
+}
+
+#[test]
+#[cfg(feature = "fuzzy")]
+fn fuzzy_scorer_returns_original_index_of_best_match() {
+    let mut read: Vec<KeyEvent> = "fbr"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec!["baz", "bar-foo", "foo-bar", "qux"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Option:", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "foo-bar"), ans);
+}
+
+#[test]
+fn matched_characters_are_highlighted() {
+    let mut read: Vec<KeyEvent> = "ang"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec!["Apple", "Mango"];
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let render_config =
+            RenderConfig::empty().with_filter_match(StyleSheet::new().with_attr(Attributes::BOLD));
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        Select::new("Fruit:", options)
+            .with_highlighter(
+                &|input, _option, string_value, _idx| match string_value.find(input) {
+                    Some(start) => (start..start + input.len()).collect(),
+                    None => vec![],
+                },
+            )
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("M\u{1b}[1mang\u{1b}[0mo"));
+}
+
+#[test]
+fn highlighting_does_not_split_graphemes() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('x'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    // "e" followed by a combining acute accent, in a single grapheme.
+    let options = vec!["cafe\u{301}s"];
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let render_config =
+            RenderConfig::empty().with_filter_match(StyleSheet::new().with_attr(Attributes::BOLD));
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        Select::new("Word:", options)
+            .with_filter(&|_input, _option, _string_value, _idx| true)
+            .with_highlighter(&|_input, _option, _string_value, _idx| vec![4])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("caf\u{1b}[1me\u{301}\u{1b}[0ms"));
+}
+
+#[test]
+fn only_a_page_of_options_is_rendered_around_the_cursor() {
+    let mut read: Vec<KeyEvent> = std::iter::repeat(KeyCode::Down)
+        .take(10)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = (0..200)
+        .map(|i| format!("option {i:03}"))
+        .collect::<Vec<String>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Option:", options)
+            .with_page_size(5)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(10, ans.index);
+
+    let output = String::from_utf8(write).unwrap();
+    // the last frame displays the options 8 to 12, with the cursor centered.
+    assert!(output.contains("option 012"));
+    assert!(!output.contains("option 013"));
+    assert!(!output.contains("option 199"));
+}
+
+#[test]
+fn zero_page_size_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Option:", vec!["a", "b"])
+        .with_page_size(0)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn scroll_indicators_are_displayed_when_options_do_not_fit() {
+    let read: Vec<KeyEvent> = vec![KeyCode::End, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..10)
+        .map(|i| format!("item {i}"))
+        .collect::<Vec<String>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Item:", options)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    // first page, with options below it.
+    assert!(output.contains("v item 2"));
+    // last page, with options above it.
+    assert!(output.contains("^ item 7"));
+}
+
+#[test]
+fn scroll_indicators_are_hidden_when_all_options_fit() {
+    let read: Vec<KeyEvent> = vec![KeyCode::End, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..3).map(|i| format!("item {i}")).collect::<Vec<String>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Item:", options)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(!output.contains("v item"));
+    assert!(!output.contains("^ item"));
+}
+
+#[test]
+fn starting_cursor_selects_its_option_on_enter() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Number:", options)
+        .with_starting_cursor(5)
+        .with_page_size(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(5, 5), ans);
+}
+
+#[test]
+fn out_of_range_starting_cursor_is_clamped_to_last_option() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let options = vec!["a", "b", "c"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Letter:", options)
+        .with_starting_cursor(10)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+#[test]
+fn up_from_the_first_option_wraps_to_the_last_when_wrap_around_is_enabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Number:", options)
+            .with_wrap_around(true)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(19, 19), ans);
+
+    // the last page is displayed along with the cursor.
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("> 19"));
+}
+
+#[test]
+fn up_from_the_first_option_stays_when_wrap_around_is_disabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Select::new("Number:", options)
+        .with_wrap_around(false)
+        .with_page_size(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, 0), ans);
+}
+
+#[test]
+fn typing_j_filters_when_vim_mode_is_disabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('j'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "jackfruit"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .with_vim_mode(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "jackfruit"), ans);
+}
+
+#[test]
+fn vim_mode_navigates_with_jkg_keys() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('G'),
+        KeyCode::Char('k'),
+        KeyCode::Char('k'),
+        KeyCode::Char('x'),
+        KeyCode::Char('j'),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = (0..10).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Number:", options)
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // unbound characters such as 'x' do not filter the options.
+    assert_eq!(ListOption::new(8, 8), ans);
+}
+
+#[test]
+fn vim_mode_moves_half_a_page_with_ctrl_d_and_ctrl_u() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Number:", options)
+        .with_vim_mode(true)
+        .with_page_size(6)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, 3), ans);
+}
+
+#[test]
+fn vim_mode_filters_after_slash() {
+    let mut read: Vec<KeyEvent> = "/ja"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "jackfruit"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "jackfruit"), ans);
+}
+
+#[test]
+fn vim_mode_esc_leaves_the_filter_before_cancelling() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('/'),
+        KeyCode::Esc,
+        KeyCode::Char('j'),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "jackfruit"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .with_vim_mode(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "banana"), ans);
+}
+
+#[test]
+fn page_down_stops_at_the_end_of_the_filtered_list() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('1'),
+        KeyCode::PageDown,
+        KeyCode::PageDown,
+        KeyCode::PageDown,
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Number:", options)
+        .with_wrap_around(true)
+        .with_page_size(5)
+        .with_filter(&|input, _option, string_value, _idx| string_value.contains(input))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(19, 19), ans);
+}
+
+#[test]
+fn end_moves_to_the_end_of_the_filtered_list() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('1'), KeyCode::End, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = (0..20).collect::<Vec<i32>>();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Number:", options)
+        .with_filter(&|input, _option, string_value, _idx| string_value.contains(input))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(19, 19), ans);
+}
+
+#[test]
+fn letter_navigation_cycles_through_options_starting_with_letter() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('a'), KeyCode::Char('a'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "avocado"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .without_filtering()
+        .with_letter_navigation(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "avocado"), ans);
+}
+
+#[test]
+fn letter_navigation_ignores_case_and_unmatched_letters() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('B'), KeyCode::Char('z'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "avocado"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .without_filtering()
+        .with_letter_navigation(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "banana"), ans);
+}
+
+#[test]
+fn typing_does_not_filter_when_filtering_is_disabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('b'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["apple", "banana", "avocado"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", options)
+        .without_filtering()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "apple"), ans);
+}
+
+#[test]
+fn letter_navigation_along_with_filtering_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", vec!["apple", "banana"])
+        .with_letter_navigation(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn quick_select_digit_moves_cursor_to_option() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('3'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let select = Select::new("Action:", vec!["Deploy", "Rollback", "Restart", "Cancel"])
+        .without_filtering()
+        .with_quick_select_digits(true);
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let render_config = select.prompt_render_config();
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        select.prompt_with_backend(&mut backend).unwrap()
+    };
+
+    assert_eq!(ListOption::new(2, "Restart"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("1) Deploy"));
+    assert!(output.contains("4) Cancel"));
+}
+
+#[test]
+fn quick_select_digit_submits_option_when_enabled() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('2')]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Action:", vec!["Deploy", "Rollback"])
+        .without_filtering()
+        .with_quick_select_digits(true)
+        .with_quick_select_submit(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Rollback"), ans);
+}
+
+#[test]
+fn quick_select_digits_beyond_option_count_are_ignored() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('5'), KeyCode::Char('0'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Action:", vec!["Deploy", "Rollback"])
+        .without_filtering()
+        .with_quick_select_digits(true)
+        .with_quick_select_submit(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Deploy"), ans);
+}
+
+#[test]
+fn quick_select_digits_along_with_filtering_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Action:", vec!["Deploy", "Rollback"])
+        .with_quick_select_digits(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn cursor_skips_disabled_options() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Letter:", vec!["a", "b", "c"])
+        .with_disabled_indices(&[1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+#[test]
+fn wrapping_up_skips_disabled_options() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Letter:", vec!["a", "b", "c"])
+        .with_disabled_indices(&[2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn cursor_does_not_start_on_disabled_option() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Letter:", vec!["a", "b", "c"])
+        .with_disabled_indices(&[0])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn submitting_disabled_option_shows_its_reason() {
+    let mut read: Vec<KeyEvent> = "prod"
+        .chars()
+        .map(KeyCode::Char)
+        .map(KeyEvent::from)
+        .collect();
+    read.push(KeyEvent::from(KeyCode::Enter));
+    read.push(KeyEvent::from(KeyCode::Esc));
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Environment:", vec!["Development", "Staging", "Production"])
+            .with_disabled_option(2, "Deploying to production requires approval")
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(0, "Development"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    // disabled matches are still displayed.
+    assert!(output.contains("> Production"));
+    assert!(output.contains("Deploying to production requires approval"));
+}
+
+#[test]
+fn all_options_disabled_is_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Letter:", vec!["a", "b"])
+        .with_disabled_indices(&[0, 1])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn group_headers_are_rendered_and_skipped_by_cursor() {
+    let read: Vec<KeyEvent> = [KeyCode::Down, KeyCode::Down, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new_grouped(
+            "Host:",
+            vec![
+                ("Production", vec!["prod-1", "prod-2"]),
+                ("Staging", vec!["staging-1"]),
+            ],
+        )
+        .prompt_with_backend(&mut backend)
+        .unwrap()
+    };
+
+    assert_eq!(ListOption::new(2, "staging-1"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("  Production"));
+    assert!(output.contains("  Staging"));
+    assert!(output.contains("> staging-1"));
+}
+
+#[test]
+fn group_headers_without_matches_are_hidden_when_filtering() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('s')),
+        KeyEvent::from(KeyCode::Char('t')),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Host:", vec!["prod-1", "prod-2", "staging-1"])
+            .with_group_header(0, "Production")
+            .with_group_header(2, "Staging")
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(2, "staging-1"), ans);
+
+    // the last frame before submission only contains the staging group.
+    let output = String::from_utf8(write).unwrap();
+    let last_frame = output.rsplit("Host:").nth(1).unwrap();
+    assert!(last_frame.contains("Staging"));
+    assert!(!last_frame.contains("Production"));
+}
+
+#[test]
+fn group_headers_take_rows_of_the_page() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Host:", vec!["prod-1", "prod-2", "staging-1"])
+            .with_group_header(0, "Production")
+            .with_group_header(2, "Staging")
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("prod-2"));
+    assert!(!output.contains("staging-1"));
+}
+
+#[test]
+fn description_of_highlighted_option_is_rendered() {
+    let read: Vec<KeyEvent> = [KeyCode::Down, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Merge:", vec!["merge", "squash", "rebase"])
+            .with_descriptions(&[
+                "Keep all commits and add a merge commit",
+                "Combine all commits into one",
+            ])
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(1, "squash"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    let frames = output.split("Merge:").collect::<Vec<&str>>();
+    assert!(frames[1].contains("Keep all commits and add a merge commit"));
+    assert!(!frames[1].contains("Combine all commits into one"));
+    assert!(frames[2].contains("Combine all commits into one"));
+    assert!(!frames[2].contains("Keep all commits and add a merge commit"));
+}
+
+#[test]
+fn option_without_description_renders_no_description() {
+    let read: Vec<KeyEvent> = [KeyCode::Up, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Merge:", vec!["merge", "squash", "rebase"])
+            .with_description(0, "Keep all commits and add a merge commit")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    let frames = output.split("Merge:").collect::<Vec<&str>>();
+    assert!(frames[1].contains("Keep all commits"));
+    assert!(!frames[2].contains("Keep all commits"));
+}
+
+#[test]
+fn options_are_laid_out_in_columns() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Country:", vec!["BR", "CA", "DE", "FRA", "JP"])
+            .with_columns(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("> BR     CA     DE\r\n"));
+    assert!(output.contains("  FRA    JP\r\n"));
+}
+
+#[test]
+fn arrow_keys_navigate_the_grid() {
+    let read: Vec<KeyEvent> = [KeyCode::Right, KeyCode::Down, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Country:", vec!["BR", "CA", "DE", "FR", "JP", "PT", "US"])
+        .with_columns(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(4, "JP"), ans);
+}
+
+#[test]
+fn moving_up_from_first_row_wraps_to_the_same_column() {
+    let read: Vec<KeyEvent> = [KeyCode::Right, KeyCode::Right, KeyCode::Up, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    // the last row has no option in the third column, so the cursor
+    // lands on the row above it.
+    let ans = Select::new("Country:", vec!["BR", "CA", "DE", "FR", "JP", "PT", "US"])
+        .with_columns(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(5, "PT"), ans);
+}
+
+#[test]
+fn grid_falls_back_to_single_column_while_filtering() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('r')),
+        KeyEvent::from(KeyCode::Down),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Country:", vec!["BR", "CA", "DE", "FR", "JP"])
+            .with_columns(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(3, "FR"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("  BR\r\n> FR\r\n"));
+}
+
+#[test]
+fn columns_along_with_group_headers_are_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Country:", vec!["BR", "CA"])
+        .with_group_header(0, "Americas")
+        .with_columns(2)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn options_are_loaded_from_provider_as_the_cursor_moves() {
+    let calls = std::cell::RefCell::new(vec![]);
+    let provider = |filter: &str, offset: usize, limit: usize| {
+        calls.borrow_mut().push((filter.to_string(), offset, limit));
+        (offset..(offset + limit).min(250))
+            .map(|n| format!("row-{n}"))
+            .collect()
+    };
+
+    let mut read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::End); 4];
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::from_provider("Row:", &provider)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(249, "row-249".to_string()), ans);
+    assert_eq!(
+        vec![
+            (String::new(), 0, 100),
+            (String::new(), 100, 100),
+            (String::new(), 200, 100),
+        ],
+        *calls.borrow()
+    );
+}
+
+#[test]
+fn options_are_loaded_from_provider_when_filter_changes() {
+    let provider = |filter: &str, offset: usize, limit: usize| {
+        (0..1000)
+            .map(|n| format!("row-{n}"))
+            .filter(|row| row.contains(filter))
+            .skip(offset)
+            .take(limit)
+            .collect()
+    };
+
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('9')),
+        KeyEvent::from(KeyCode::Char('9')),
+        KeyEvent::from(KeyCode::Char('9')),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::from_provider("Row:", &provider)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    // the index refers to the options loaded for the submitted filter.
+    assert_eq!(ListOption::new(0, "row-999".to_string()), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("[Loading…]"));
+}
+
+#[test]
+fn provider_without_options_renders_no_matches() {
+    let provider = |_filter: &str, _offset: usize, _limit: usize| Vec::<String>::new();
+
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter), KeyEvent::from(KeyCode::Esc)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::from_provider("Row:", &provider).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn deleting_characters_scores_all_options_again() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('b')),
+        KeyEvent::from(KeyCode::Backspace),
+        KeyEvent::from(KeyCode::Char('g')),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", vec!["apple", "banana", "grape"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "grape"), ans);
+}
+
+#[test]
+fn scorers_matching_aliases_work_without_incremental_filtering() {
+    let aliases = ["nyc", "la"];
+    let read: Vec<KeyEvent> = "nyc"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(std::iter::once(KeyCode::Enter))
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    // "ny" matches neither option, so narrowing would have dropped New York.
+    let ans = Select::new("City:", vec!["New York", "Los Angeles"])
+        .with_scorer(&|input, option, _string_value, idx| {
+            (option.to_lowercase().contains(input) || aliases[idx] == input).then_some(0)
+        })
+        .with_incremental_filtering(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "New York"), ans);
+}
+
+#[test]
+fn options_can_be_any_iterable() {
+    let read: Vec<KeyEvent> = [KeyCode::Down, KeyCode::Down, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Port:", 8080..=8085)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 8082), ans);
+}
+
+#[test]
+fn typing_that_leaves_a_single_option_submits_it() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('a')),
+        KeyEvent::from(KeyCode::Backspace),
+        KeyEvent::from(KeyCode::Char('c')),
+        KeyEvent::from(KeyCode::Char('h')),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", vec!["apple", "banana", "cherry"])
+        .with_auto_select_single(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "cherry"), ans);
+}
+
+#[test]
+fn single_option_left_by_typing_is_rendered_before_being_submitted() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('c')),
+        KeyEvent::from(KeyCode::Backspace),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", vec!["apple", "banana", "cherry"])
+        .with_auto_select_single(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "apple"), ans);
+}
+
+#[test]
+fn tab_submits_the_single_option_left_by_typing() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Tab),
+        KeyEvent::from(KeyCode::Char('b')),
+        KeyEvent::from(KeyCode::Tab),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", vec!["apple", "banana", "cherry"])
+        .with_auto_select_single(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "banana"), ans);
+}
+
+#[test]
+fn single_option_lists_are_not_auto_selected_by_typing() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::from(KeyCode::Char('o')),
+        KeyEvent::from(KeyCode::Char('x')),
+        KeyEvent::from(KeyCode::Backspace),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Fruit:", vec!["orange"])
+            .with_auto_select_single(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(0, "orange"), ans);
+
+    // the "x" was typed, so "o" did not submit the option.
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Fruit: ox"));
+}
+
+#[test]
+fn single_option_is_submitted_on_start_when_enabled() {
+    let read: Vec<KeyEvent> = vec![];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Fruit:", vec!["orange"])
+        .with_submit_if_single_option(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "orange"), ans);
+}
+
+#[test]
+fn empty_options_are_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Option:", Vec::<&str>::new()).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn zero_columns_are_invalid() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Option:", vec!["a", "b"])
+        .with_columns(0)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+fn prompt_skippable_with_keys(
+    keys: Vec<KeyEvent>,
+) -> crate::error::InquireResult<Option<&'static str>> {
+    let mut read = keys.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    Select::new("Template:", vec!["rust", "python", "go"])
+        .prompt_skippable_with_backend(&mut backend)
+}
+
+#[test]
+fn skippable_prompt_returns_some_on_submit() {
+    let ans = prompt_skippable_with_keys(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyEvent::from(KeyCode::Enter),
+    ]);
+
+    assert_eq!(Some("python"), ans.unwrap());
+}
+
+#[test]
+fn skippable_prompt_returns_none_on_esc() {
+    let ans = prompt_skippable_with_keys(vec![KeyEvent::from(KeyCode::Esc)]);
+
+    assert_eq!(None, ans.unwrap());
+}
+
+#[test]
+fn skippable_prompt_clears_the_filter_before_skipping() {
+    // the first esc clears the filter, so enter then submits the first option.
+    let ans = prompt_skippable_with_keys(vec![
+        KeyEvent::from(KeyCode::Char('g')),
+        KeyEvent::from(KeyCode::Esc),
+        KeyEvent::from(KeyCode::Enter),
+    ]);
+
+    assert_eq!(Some("rust"), ans.unwrap());
+
+    let ans = prompt_skippable_with_keys(vec![
+        KeyEvent::from(KeyCode::Char('g')),
+        KeyEvent::from(KeyCode::Esc),
+        KeyEvent::from(KeyCode::Esc),
+    ]);
+
+    assert_eq!(None, ans.unwrap());
+}
+
+#[test]
+fn skippable_prompt_returns_error_on_interrupt() {
+    let ans = prompt_skippable_with_keys(vec![KeyEvent::new(
+        KeyCode::Char('c'),
+        KeyModifiers::CONTROL,
+    )]);
+
+    assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+}
+
+#[test]
+fn formatter_receives_index_and_value_of_the_answer() {
+    let read: Vec<KeyEvent> = [KeyCode::Down, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let cities = vec!["Amsterdam", "Frankfurt", "Lisbon"];
+    let total = cities.len();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Region:", cities)
+            .with_formatter(&|option| {
+                format!("option {} of {}: {}", option.index + 1, total, option.value)
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Region: option 2 of 3: Frankfurt"));
+}
+
+#[test]
+fn preview_of_highlighted_option_is_rendered_and_capped() {
+    let read: Vec<KeyEvent> = [KeyCode::Down, KeyCode::Enter]
+        .iter()
+        .map(|c| KeyEvent::from(*c))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Branch:", vec!["main", "feature"])
+            .with_preview(&|branch| format!("branch: {branch}\nline 2\nline 3\nline 4"))
+            .with_preview_height(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    let frames = output.split("Branch:").collect::<Vec<&str>>();
+    assert!(frames[1].contains("  branch: main\r\n  line 2\r\n  line 3\r\n"));
+    assert!(!frames[1].contains("line 4"));
+    assert!(frames[2].contains("  branch: feature\r\n"));
+}
+
+#[test]
+fn long_preview_lines_are_truncated_to_the_terminal_width() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Branch:", vec!["main"])
+            .with_preview(&|_| "x".repeat(2000))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    // the width of the test terminal depends on the environment, but it is
+    // never wider than the fallback of 1000 columns.
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("  xxx"));
+    assert!(!output.contains(&"x".repeat(1000)));
+}
+
+#[test]
+fn filter_text_is_displayed_along_with_the_answer_when_enabled() {
+    let run = |show_filter: bool| {
+        let read: Vec<KeyEvent> = "frank"
+            .chars()
+            .map(KeyCode::Char)
+            .chain(std::iter::once(KeyCode::Enter))
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        {
+            let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+            let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+            Select::new("Host:", vec!["web-amsterdam-01", "web-frankfurt-03"])
+                .with_filter_in_answer(show_filter)
+                .prompt_with_backend(&mut backend)
+                .unwrap();
+        }
+        String::from_utf8(write).unwrap()
+    };
+
+    assert!(run(true).contains("Host: web-frankfurt-03 (filtered: \"frank\")"));
+    assert!(!run(false).contains("(filtered:"));
+}
+
+#[test]
+fn filtered_formatter_receives_the_filter_text() {
+    let read: Vec<KeyEvent> = "ams"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(std::iter::once(KeyCode::Enter))
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::new("Host:", vec!["web-amsterdam-01", "web-frankfurt-03"])
+            .with_filtered_formatter(&|option, filter| format!("{} <- {}", option.value, filter))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Host: web-amsterdam-01 <- ams"));
+}
+
+fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn scroll(kind: MouseEventKind) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn prompt_with_events(
+    select: Select<'_, &'static str>,
+    events: Vec<Event>,
+) -> crate::error::InquireResult<ListOption<&'static str>> {
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_events(&mut write, Box::new(events.into_iter()));
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    select.prompt_with_backend(&mut backend)
+}
+
+#[test]
+fn click_moves_the_cursor_and_click_on_highlighted_option_submits() {
+    // the prompt is rendered on the first row, followed by the options.
+    let select = Select::new("Fruit:", vec!["apple", "banana", "cherry"]).with_mouse(true);
+    let ans = prompt_with_events(select, vec![click(4, 3), click(4, 2), click(10, 2)]);
+
+    assert_eq!(ListOption::new(1, "banana"), ans.unwrap());
+}
+
+#[test]
+fn clicks_outside_of_the_options_are_ignored() {
+    let select = Select::new("Fruit:", vec!["apple", "banana", "cherry"]).with_mouse(true);
+    let events = vec![
+        click(4, 0),
+        click(4, 4),
+        Event::Key(KeyEvent::from(KeyCode::Enter)),
+    ];
+    let ans = prompt_with_events(select, events);
+
+    assert_eq!(ListOption::new(0, "apple"), ans.unwrap());
+}
+
+#[test]
+fn clicks_on_a_scrolled_page_select_the_displayed_option() {
+    let options = vec!["a", "b", "c", "d", "e", "f"];
+    let select = Select::new("Letter:", options)
+        .with_page_size(3)
+        .with_starting_cursor(4)
+        .with_mouse(true);
+    // "d" is displayed on the first row of the page, then the page scrolls to
+    // keep the cursor in the middle and "d" moves to the second row.
+    let ans = prompt_with_events(select, vec![click(2, 1), click(2, 2)]);
+
+    assert_eq!(ListOption::new(3, "d"), ans.unwrap());
+}
+
+#[test]
+fn clicks_follow_the_frame_when_the_terminal_scrolls() {
+    let select = Select::new("Fruit:", vec!["apple", "banana", "cherry"]).with_mouse(true);
+    // the frame takes six rows, so a four rows tall terminal scrolls its first
+    // two rows out of the screen, leaving "cherry" on the second row.
+    let ans = prompt_with_events(select, vec![Event::Resize(80, 4), click(4, 1), click(4, 1)]);
+
+    assert_eq!(ListOption::new(2, "cherry"), ans.unwrap());
+}
+
+#[test]
+fn clicks_on_grid_cells_select_the_clicked_column() {
+    let select = Select::new("Country:", vec!["BR", "CA", "DE", "FR"])
+        .with_columns(2)
+        .with_mouse(true);
+    // row 1 is "> BR  CA", with "CA" starting at the sixth column.
+    let ans = prompt_with_events(select, vec![click(6, 1), click(6, 1)]);
+
+    assert_eq!(ListOption::new(1, "CA"), ans.unwrap());
+}
+
+#[test]
+fn mouse_wheel_moves_the_cursor() {
+    let select = Select::new("Fruit:", vec!["apple", "banana", "cherry"]).with_mouse(true);
+    let events = vec![
+        scroll(MouseEventKind::ScrollDown),
+        scroll(MouseEventKind::ScrollDown),
+        scroll(MouseEventKind::ScrollUp),
+        Event::Key(KeyEvent::from(KeyCode::Enter)),
+    ];
+    let ans = prompt_with_events(select, events);
+
+    assert_eq!(ListOption::new(1, "banana"), ans.unwrap());
+}
+
+#[test]
+fn mouse_events_are_ignored_when_mouse_is_disabled() {
+    let select = Select::new("Fruit:", vec!["apple", "banana", "cherry"]);
+    let events = vec![
+        click(4, 2),
+        click(4, 2),
+        scroll(MouseEventKind::ScrollDown),
+        Event::Key(KeyEvent::from(KeyCode::Enter)),
+    ];
+    let ans = prompt_with_events(select, events);
+
+    assert_eq!(ListOption::new(0, "apple"), ans.unwrap());
+}
+
+fn output_with_events(select: Select<'_, &'static str>, events: Vec<Event>) -> String {
+    let mut write: Vec<u8> = Vec::new();
+    {
+        let terminal = CrosstermTerminal::new_with_events(&mut write, Box::new(events.into_iter()));
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        select.prompt_with_backend(&mut backend).unwrap();
+    }
+
+    String::from_utf8(write).unwrap()
+}
+
+#[test]
+fn frame_rendered_wide_is_cleared_from_its_rewrapped_first_row_when_narrowed() {
+    // "? Pick a fruit to eat today: " takes 29 columns, so the cursor at the end
+    // of the prompt line is on its third row once wrapped at 10 columns.
+    let select = Select::new("Pick a fruit to eat today:", vec!["apple", "banana"]);
+    let events = vec![
+        Event::Resize(40, 20),
+        Event::Resize(10, 20),
+        Event::Key(KeyEvent::from(KeyCode::Enter)),
+    ];
+    let output = output_with_events(select, events);
+
+    #[cfg(unix)]
+    assert!(output.contains("\x1B[2A\x1B[1G\x1B[J"));
+    assert!(output.contains("? Pick a fruit to eat today: apple\r\n"));
+}
+
+#[test]
+fn frame_rendered_narrow_is_cleared_from_its_unwrapped_first_row_when_widened() {
+    let select = Select::new("Pick a fruit to eat today:", vec!["apple", "banana"]);
+    let events = vec![
+        Event::Resize(10, 20),
+        Event::Resize(80, 20),
+        Event::Key(KeyEvent::from(KeyCode::Down)),
+        Event::Key(KeyEvent::from(KeyCode::Enter)),
+    ];
+    let output = output_with_events(select, events);
+
+    // the frame of 10 columns spans several rows, but a single one at 80.
+    #[cfg(unix)]
+    {
+        let frames: Vec<&str> = output.split("\x1B[J").collect();
+        assert_eq!(3, frames.len());
+        assert!(frames[1].ends_with("\x1B[1G"));
+        assert!(!frames[1].ends_with("A\x1B[1G"));
+    }
+    assert!(output.contains("? Pick a fruit to eat today: banana\r\n"));
+}
+
+fn region_select() -> Select<'static, &'static str> {
+    let regions = vec![
+        "Frankfurt (eu-central-1)",
+        "Amsterdam (eu-west-4)",
+        "Singapore (ap-southeast-1)",
+    ];
+    let aliases = vec![
+        vec!["fra1".to_string()],
+        vec!["ams3".to_string(), "nl".to_string()],
+    ];
+
+    Select::new("Region:", regions).with_aliases(aliases)
+}
+
+#[test]
+fn filter_input_matches_aliases_of_options() {
+    let read: Vec<KeyEvent> = "ams3"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(vec![KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        region_select().prompt_with_backend(&mut backend).unwrap()
+    };
+
+    assert_eq!(ListOption::new(1, "Amsterdam (eu-west-4)"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    let last_frame = output.rsplit("Region:").nth(1).unwrap();
+    assert!(last_frame.contains("> Amsterdam (eu-west-4) (ams3)"));
+    assert!(!last_frame.contains("Frankfurt"));
+}
+
+#[test]
+fn aliases_are_not_displayed_when_the_string_value_matches_better() {
+    let read: Vec<KeyEvent> = "sing"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(vec![KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        region_select()
+            .with_aliases(vec![vec![], vec![], vec!["sg".to_string()]])
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(2, "Singapore (ap-southeast-1)"), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    let last_frame = output.rsplit("Region:").nth(1).unwrap();
+    assert!(last_frame.contains("> Singapore (ap-southeast-1)"));
+    assert!(!last_frame.contains("(sg)"));
+}
+
+#[test]
+fn filter_function_receives_aliases_as_string_values() {
+    let read: Vec<KeyEvent> = "nl"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(vec![KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = region_select()
+        .with_filter(&|input, _, string_value, _| string_value == input)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Amsterdam (eu-west-4)"), ans);
+}
+
+fn prompt_sorted_fruits(
+    select: Select<'static, &'static str>,
+    keys: Vec<KeyCode>,
+) -> ListOption<&'static str> {
+    let read: Vec<KeyEvent> = keys.into_iter().map(KeyEvent::from).collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    select
+        .with_sorter(&|a, b| a.cmp(b))
+        .prompt_with_backend(&mut backend)
+        .unwrap()
+}
+
+fn fruits() -> Select<'static, &'static str> {
+    Select::new("Fruit:", vec!["pear", "apple", "fig", "banana"])
+}
+
+#[test]
+fn first_displayed_option_after_sorting_returns_its_original_index() {
+    let ans = prompt_sorted_fruits(fruits(), vec![KeyCode::Enter]);
+
+    assert_eq!(ListOption::new(1, "apple"), ans);
+}
+
+#[test]
+fn pages_operate_on_the_sorted_options() {
+    let ans = prompt_sorted_fruits(
+        fruits().with_page_size(2),
+        vec![KeyCode::PageDown, KeyCode::Enter],
+    );
+
+    assert_eq!(ListOption::new(2, "fig"), ans);
+
+    let ans = prompt_sorted_fruits(fruits(), vec![KeyCode::End, KeyCode::Enter]);
+
+    assert_eq!(ListOption::new(0, "pear"), ans);
+}
+
+#[test]
+fn filtered_options_keep_their_sorted_order() {
+    let select = fruits().with_filter(&|input, _, string_value, _| string_value.contains(input));
+    let ans = prompt_sorted_fruits(
+        select,
+        vec![KeyCode::Char('a'), KeyCode::Down, KeyCode::Enter],
+    );
+
+    assert_eq!(ListOption::new(3, "banana"), ans);
+}
+
+#[test]
+fn disabled_options_refer_to_the_original_indexes_when_sorted() {
+    let ans = prompt_sorted_fruits(
+        fruits().with_disabled_option(1, "Sold out"),
+        vec![KeyCode::Enter],
+    );
+
+    assert_eq!(ListOption::new(3, "banana"), ans);
+}
+
+#[test]
+fn sorter_can_not_be_used_along_with_group_headers() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = fruits()
+        .with_group_header(0, "Fruits")
+        .with_sorter(&|a, b| a.cmp(b))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+fn prompt_with_enter(select: Select<'static, &'static str>) -> (ListOption<&'static str>, String) {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        select.prompt_with_backend(&mut backend).unwrap()
+    };
+
+    (ans, String::from_utf8(write).unwrap())
+}
+
+fn environments() -> Select<'static, &'static str> {
+    let options = vec!["dev", "qa", "uat", "staging", "canary", "production"];
+    Select::new("Environment:", options)
+}
+
+#[test]
+fn starting_choice_is_highlighted_in_the_first_page() {
+    let (ans, output) = prompt_with_enter(
+        environments()
+            .with_page_size(2)
+            .with_starting_choice(&"production"),
+    );
+
+    assert_eq!(ListOption::new(5, "production"), ans);
+
+    let first_frame = output.split("Environment:").nth(1).unwrap();
+    assert!(first_frame.contains("> production"));
+}
+
+#[test]
+fn starting_choice_by_predicate() {
+    let (ans, _) = prompt_with_enter(environments().with_starting_choice_by(|env| env.len() == 7));
+
+    assert_eq!(ListOption::new(3, "staging"), ans);
+}
+
+#[test]
+fn missing_starting_choice_starts_at_the_first_option() {
+    let (ans, _) = prompt_with_enter(
+        environments()
+            .with_starting_cursor(2)
+            .with_starting_choice(&"prod"),
+    );
+
+    assert_eq!(ListOption::new(0, "dev"), ans);
+}
+
+#[test]
+fn starting_choice_follows_the_option_when_sorted() {
+    let (ans, _) = prompt_with_enter(
+        environments()
+            .with_sorter(&|a, b| a.cmp(b))
+            .with_starting_choice(&"qa"),
+    );
+
+    assert_eq!(ListOption::new(1, "qa"), ans);
+}
+
+fn prompt_with_filter(
+    select: Select<'static, &'static str>,
+    filter: &str,
+) -> ListOption<&'static str> {
+    let read: Vec<KeyEvent> = filter
+        .chars()
+        .map(KeyCode::Char)
+        .chain(std::iter::once(KeyCode::Enter))
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    select.prompt_with_backend(&mut backend).unwrap()
+}
+
+fn pods() -> Select<'static, &'static str> {
+    Select::new("Pod:", vec!["pod/Nginx", "pod/nginx", "svc/Nginx"])
+}
+
+#[test]
+fn filter_is_case_insensitive_by_default() {
+    let select = Select::new("Pod:", vec!["pod/redis", "svc/Nginx"]);
+
+    assert_eq!(
+        ListOption::new(1, "svc/Nginx"),
+        prompt_with_filter(select, "ngin")
+    );
+}
+
+#[test]
+fn case_sensitive_filter() {
+    let select = pods().with_filter_case(CaseMatching::Sensitive);
+
+    assert_eq!(
+        ListOption::new(1, "pod/nginx"),
+        prompt_with_filter(select, "ngin")
+    );
+}
+
+#[test]
+fn smart_case_filter_ignores_case_of_lowercase_input() {
+    let select =
+        Select::new("Pod:", vec!["pod/redis", "svc/Nginx"]).with_filter_case(CaseMatching::Smart);
+
+    assert_eq!(
+        ListOption::new(1, "svc/Nginx"),
+        prompt_with_filter(select, "ngin")
+    );
+}
+
+#[test]
+fn smart_case_filter_respects_case_of_uppercase_input() {
+    let select =
+        Select::new("Pod:", vec!["pod/nginx", "svc/Nginx"]).with_filter_case(CaseMatching::Smart);
+
+    assert_eq!(
+        ListOption::new(1, "svc/Nginx"),
+        prompt_with_filter(select, "Ngin")
+    );
+}
+
+#[test]
+fn case_sensitive_filter_applies_to_aliases() {
+    let select = || {
+        Select::new("Kind:", vec!["Deployment", "dep-tool"])
+            .with_aliases(vec![vec![String::from("DEP")], vec![]])
+            .with_filter_case(CaseMatching::Sensitive)
+    };
+
+    assert_eq!(
+        ListOption::new(1, "dep-tool"),
+        prompt_with_filter(select(), "dep")
+    );
+    assert_eq!(
+        ListOption::new(0, "Deployment"),
+        prompt_with_filter(select(), "DEP")
+    );
+}
+
+fn prompt_inline(
+    select: Select<'static, &'static str>,
+    keys: Vec<KeyCode>,
+) -> (ListOption<&'static str>, String) {
+    let read: Vec<KeyEvent> = keys.into_iter().map(KeyEvent::from).collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        select.prompt_with_backend(&mut backend).unwrap()
+    };
+
+    (ans, String::from_utf8(write).unwrap())
+}
+
+fn file_exists() -> Select<'static, &'static str> {
+    Select::new("File exists:", vec!["overwrite", "skip", "abort"])
+}
+
+#[test]
+fn inline_options_are_rendered_on_the_prompt_line() {
+    let (ans, output) = prompt_inline(
+        file_exists().with_inline(true),
+        vec![KeyCode::Right, KeyCode::Enter],
+    );
+
+    assert_eq!(ListOption::new(1, "skip"), ans);
+    assert!(output.contains("? File exists: overwrite / skip / abort\r\n"));
+    assert!(!output.contains("> overwrite"));
+    assert!(!output.contains("to move"));
+}
+
+#[test]
+fn inline_options_are_cycled_with_arrows_and_tab() {
+    let (ans, _) = prompt_inline(
+        file_exists().with_inline(true),
+        vec![KeyCode::Left, KeyCode::Enter],
+    );
+    assert_eq!(ListOption::new(2, "abort"), ans);
+
+    let (ans, _) = prompt_inline(
+        file_exists().with_inline(true),
+        vec![KeyCode::Tab, KeyCode::Tab, KeyCode::BackTab, KeyCode::Enter],
+    );
+    assert_eq!(ListOption::new(1, "skip"), ans);
+}
+
+#[test]
+fn inline_options_are_cycled_without_wrap_around() {
+    let (ans, _) = prompt_inline(
+        file_exists().with_inline(true).with_wrap_around(false),
+        vec![
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Enter,
+        ],
+    );
+    assert_eq!(ListOption::new(0, "overwrite"), ans);
+
+    let (ans, _) = prompt_inline(
+        file_exists().with_inline(true).with_wrap_around(false),
+        vec![KeyCode::BackTab, KeyCode::Enter],
+    );
+    assert_eq!(ListOption::new(2, "abort"), ans);
+}
+
+#[test]
+fn inline_options_are_not_filtered() {
+    let (ans, output) = prompt_inline(
+        file_exists().with_inline(true),
+        vec![KeyCode::Char('a'), KeyCode::Tab, KeyCode::Enter],
+    );
+
+    assert_eq!(ListOption::new(1, "skip"), ans);
+    assert!(!output.contains("File exists: a"));
+}
+
+#[test]
+fn inline_answer_is_rendered_as_in_list_mode() {
+    let (_, inline) = prompt_inline(
+        file_exists().with_inline(true),
+        vec![KeyCode::Tab, KeyCode::Enter],
+    );
+    let (_, list) = prompt_inline(file_exists(), vec![KeyCode::Down, KeyCode::Enter]);
+
+    assert_eq!(
+        list.rsplit("File exists:").next(),
+        inline.rsplit("File exists:").next()
+    );
+}
+
+#[test]
+fn inline_options_can_not_be_laid_out_in_columns() {
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = file_exists()
+        .with_inline(true)
+        .with_columns(2)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[derive(Debug, PartialEq)]
+enum Conflict {
+    Overwrite,
+    // left out of the listed variants.
+    #[allow(dead_code)]
+    Skip,
+    Abort,
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl SelectableVariants for Conflict {
+    fn variants() -> Vec<Self> {
+        vec![Self::Overwrite, Self::Abort]
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Abort => String::from("Abort the copy"),
+            _ => self.to_string(),
+        }
+    }
+}
+
+#[test]
+fn new_from_enum_returns_the_selected_variant() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Select::<Conflict>::new_from_enum("File exists:")
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!(ListOption::new(1, Conflict::Abort), ans);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("  Overwrite\r\n"));
+    assert!(output.contains("> Abort the copy\r\n"));
+    assert!(!output.contains("Skip"));
+    assert!(output.contains("? File exists: Abort the copy"));
+}
+
+#[test]
+fn new_from_enum_filters_by_label() {
+    let read: Vec<KeyEvent> = "copy"
+        .chars()
+        .map(KeyCode::Char)
+        .chain(std::iter::once(KeyCode::Enter))
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Select::<Conflict>::new_from_enum("File exists:")
+        .with_filter_case(CaseMatching::Sensitive)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, Conflict::Abort), ans);
+}
@@ -1,4 +1,5 @@
 use crate::{
+    prompts::action::Action,
     ui::{Key, KeyModifiers},
     InnerAction, InputAction,
 };
@@ -21,12 +22,22 @@ pub enum TextPromptAction {
     MoveToSuggestionPageDown,
     /// When a suggestion list exists, autocompletes the text input with the current suggestion.
     UseCurrentSuggestion,
+    /// When a suggestion list exists, moves the cursor to the previous suggestion,
+    /// wrapping around to the last one, and autocompletes the text input with it.
+    UsePreviousSuggestion,
+    /// Reverts the last edit of the text input.
+    Undo,
+    /// Reapplies the last edit reverted with undo.
+    Redo,
+    /// Inserts the text last removed by a word, line or whole input deletion at the
+    /// cursor.
+    Yank,
 }
 
 impl InnerAction for TextPromptAction {
     type Config = TextConfig;
 
-    fn from_key(key: Key, _config: &TextConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &TextConfig) -> Option<Self> {
         let action = match key {
             Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
                 Self::MoveToSuggestionAbove
@@ -39,6 +50,21 @@ impl InnerAction for TextPromptAction {
             Key::PageDown => Self::MoveToSuggestionPageDown,
 
             Key::Tab => Self::UseCurrentSuggestion,
+            Key::BackTab => Self::UsePreviousSuggestion,
+
+            // most terminals send ctrl+_ as ctrl+7
+            Key::Char('z' | '_' | '7', KeyModifiers::CONTROL) => Self::Undo,
+            // many terminals send ctrl+shift+z as ctrl+z, so alt+z redoes as well
+            Key::Char('z' | 'Z', m) if m == KeyModifiers::CONTROL | KeyModifiers::SHIFT => {
+                Self::Redo
+            }
+            Key::Char('z', KeyModifiers::ALT) => Self::Redo,
+            Key::Char('y', KeyModifiers::CONTROL) => Self::Yank,
+
+            Key::Char('\n' | '\r', _) if config.multiline => {
+                Self::ValueInput(InputAction::Write('\n'))
+            }
+            Key::Char('\n' | '\r', _) if config.strip_pasted_newlines => return None,
 
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::ValueInput(action),
@@ -48,4 +74,18 @@ impl InnerAction for TextPromptAction {
 
         Some(action)
     }
+
+    fn override_key(key: Key, config: &TextConfig) -> Option<Action<Self>> {
+        if !config.multiline {
+            return None;
+        }
+
+        let action = match key {
+            Key::Enter => Action::Inner(Self::ValueInput(InputAction::Write('\n'))),
+            Key::Char('d', KeyModifiers::CONTROL) => Action::Submit,
+            _ => return None,
+        };
+
+        Some(action)
+    }
 }
@@ -5,12 +5,18 @@ use crate::Text;
 pub struct TextConfig {
     /// Page size of the suggestion list, if it exists.
     pub page_size: usize,
+    /// Whether `enter` inserts a new line instead of submitting the input.
+    pub multiline: bool,
+    /// Whether pasted new lines are removed instead of converted to spaces.
+    pub strip_pasted_newlines: bool,
 }
 
 impl From<&Text<'_>> for TextConfig {
     fn from(value: &Text<'_>) -> Self {
         Self {
             page_size: value.page_size,
+            multiline: value.multiline,
+            strip_pasted_newlines: value.strip_pasted_newlines,
         }
     }
 }
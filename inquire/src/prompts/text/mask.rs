@@ -0,0 +1,112 @@
+/// Single position of an input mask.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Slot {
+    /// Accepts an ASCII digit, represented by `#`.
+    Digit,
+    /// Accepts an alphabetic character, represented by `A`.
+    Letter,
+    /// Literal character, inserted automatically.
+    Literal(char),
+}
+
+impl Slot {
+    fn from_char(c: char) -> Self {
+        match c {
+            '#' => Self::Digit,
+            'A' => Self::Letter,
+            c => Self::Literal(c),
+        }
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Literal(l) => *l == c,
+        }
+    }
+}
+
+/// Input mask used to type structured values, such as phone numbers.
+///
+/// The content is always a prefix of a complete value, filled from left to
+/// right, with literal characters inserted as soon as the next fillable
+/// position is typed. Literals ending the mask are inserted as soon as the
+/// last fillable position is typed, completing the value.
+#[derive(Copy, Clone, Debug)]
+pub struct Mask<'a> {
+    pattern: &'a str,
+}
+
+impl<'a> Mask<'a> {
+    pub fn new(pattern: &'a str) -> Self {
+        Self { pattern }
+    }
+
+    pub fn pattern(&self) -> &'a str {
+        self.pattern
+    }
+
+    fn slots(&self) -> impl Iterator<Item = Slot> + 'a {
+        self.pattern.chars().map(Slot::from_char)
+    }
+
+    /// Returns the characters to append to `content` when `c` is typed, including
+    /// any literals preceding the next fillable position, or `None` if `c` does not
+    /// fit the mask. When `c` fills the last fillable position, the literals ending
+    /// the mask are appended as well.
+    pub fn write(&self, content: &str, c: char) -> Option<String> {
+        let mut appended = String::new();
+        let mut slots = self.slots().skip(content.chars().count());
+
+        loop {
+            match slots.next()? {
+                slot if slot.accepts(c) => {
+                    appended.push(c);
+                    break;
+                }
+                Slot::Literal(l) => appended.push(l),
+                Slot::Digit | Slot::Letter => return None,
+            }
+        }
+
+        let trailing_literals = slots
+            .map(|slot| match slot {
+                Slot::Literal(l) => Some(l),
+                Slot::Digit | Slot::Letter => None,
+            })
+            .collect::<Option<String>>();
+
+        if let Some(trailing_literals) = trailing_literals {
+            appended.push_str(&trailing_literals);
+        }
+
+        Some(appended)
+    }
+
+    /// Returns how many characters to remove from the end of `content` on a backspace,
+    /// removing the last filled position along with the literals around it.
+    pub fn backspace(&self, content: &str) -> usize {
+        let slots = self
+            .slots()
+            .take(content.chars().count())
+            .collect::<Vec<Slot>>();
+        let is_literal = |slot: &&Slot| matches!(slot, Slot::Literal(_));
+
+        // literals ending the mask are skipped to reach the last filled position.
+        let trailing_literals = slots.iter().rev().take_while(is_literal).count();
+        if trailing_literals == slots.len() {
+            return trailing_literals;
+        }
+
+        let filled = slots.len() - trailing_literals - 1;
+        let preceding_literals = slots[..filled].iter().rev().take_while(is_literal).count();
+
+        trailing_literals + 1 + preceding_literals
+    }
+
+    /// Returns whether every position of the mask is filled.
+    pub fn is_complete(&self, content: &str) -> bool {
+        content.chars().count() == self.pattern.chars().count()
+    }
+}
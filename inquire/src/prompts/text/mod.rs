@@ -1,5 +1,6 @@
 mod action;
 mod config;
+mod mask;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
@@ -8,12 +9,14 @@ mod test;
 pub use action::*;
 
 use crate::{
-    autocompletion::Autocomplete,
+    autocompletion::{Autocomplete, SuggestionFilter},
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    history::History,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
+    terminal::{get_default_terminal, line::LineTerminal, stdin_is_tty},
+    type_aliases::{CharFilter, CharTransformer},
     ui::{Backend, RenderConfig, TextBackend},
     validator::StringValidator,
 };
@@ -30,13 +33,23 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// ## Configuration options
 ///
 /// - **Prompt message**: Main message when prompting the user for input, `"What is your name?"` in the example below.
-/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Help message**: Message displayed at the line below the prompt, optionally only while the input is empty.
 /// - **Default value**: Default value returned when the user submits an empty response.
 /// - **Initial value**: Initial value of the prompt's text input, in case you want to display the prompt with something already filled in.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **Inline suggestion**: Whether the remainder of the top suggestion is displayed after the cursor, fish-style.
+/// - **Character filter**: Function that decides whether each typed character is accepted into the input.
+/// - **Input transformer**: Function that transforms each typed character before it is inserted, e.g. forcing lowercase.
+/// - **Trim**: Whether whitespace around the answer is removed before validation.
+/// - **Numeric**: Whether only numbers can be typed, optionally negative or decimal ones.
+/// - **Mask**: Structure of the input, e.g. `(###) ###-####`, with literal characters inserted automatically.
+/// - **Max length**: Maximum amount of characters accepted in the input, optionally displayed in a live counter.
+/// - **Multi-line**: Whether `enter` inserts a new line, with `ctrl+d` submitting the answer instead.
+/// - **Standard input fallback**: Whether the answer is read as a single line from the standard input when it is not a terminal, e.g. when piped, instead of failing.
+/// - **History**: List of previous answers that the user can recall with the up and down arrow keys, shared between consecutive prompts.
 ///
 /// ## Default behaviors
 ///
@@ -73,7 +86,6 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 ///     Err(_) => println!("An error happened when asking for your name, try again later."),
 /// }
 /// ```
-#[derive(Clone)]
 pub struct Text<'a> {
     /// Message to be presented to the user.
     pub message: &'a str,
@@ -94,12 +106,33 @@ pub struct Text<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Whether the help message is only displayed while the input is empty.
+    pub help_message_on_empty_only: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
+    /// Static list of suggestions set with [`with_suggestions`](Self::with_suggestions),
+    /// used as the autocompleter when no other one is set.
+    pub suggestions: Option<Vec<String>>,
+
+    /// Strategy used to match the static list of suggestions set with
+    /// [`with_suggestions`](Self::with_suggestions) against the input.
+    pub suggestion_filter: SuggestionFilter,
+
+    /// Whether to render the remainder of the top suggestion starting with the
+    /// current input inline after the cursor, accepted with `right` or `end`.
+    pub inline_suggestion: bool,
+
+    /// History of previous answers, recalled with the up and down arrow keys
+    /// when there are no suggestions being displayed.
+    ///
+    /// Submitted non-empty answers are appended to it.
+    pub history: Option<&'a mut History>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -111,6 +144,54 @@ pub struct Text<'a> {
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Filter applied to each character typed or pasted into the input.
+    /// Rejected characters are not inserted.
+    pub char_filter: Option<CharFilter<'a>>,
+
+    /// Transformation applied to each character typed or pasted into the input,
+    /// before the character filter. Characters mapped to `None` are not inserted.
+    pub input_transformer: Option<CharTransformer<'a>>,
+
+    /// Mask describing the structure of the input, where `#` accepts a digit,
+    /// `A` accepts a letter and any other character is inserted automatically.
+    pub mask: Option<&'a str>,
+
+    /// Whether answers that do not fill the whole mask can be submitted.
+    pub partial_mask: bool,
+
+    /// Whether new lines pasted into the input are removed. By default, they are
+    /// converted to spaces. Ignored in multi-line mode.
+    pub strip_pasted_newlines: bool,
+
+    /// Maximum amount of graphemes accepted in the input. Further keystrokes are ignored.
+    pub max_length: Option<usize>,
+
+    /// Whether to display a live counter of the input length, along with the
+    /// maximum length if set, e.g. 12/80.
+    pub length_counter: bool,
+
+    /// Whether leading and trailing whitespace is trimmed from the answer
+    /// before it is validated and returned.
+    pub trim: bool,
+
+    /// Whether only digits can be typed, with answers required to be numbers.
+    pub numeric: bool,
+
+    /// Whether a leading minus sign is accepted in numeric mode.
+    pub allow_negative: bool,
+
+    /// Whether a single decimal point is accepted in numeric mode.
+    pub allow_decimal: bool,
+
+    /// Whether the input spans multiple lines, in which case `enter` inserts
+    /// a new line and `ctrl+d` submits the answer.
+    pub multiline: bool,
+
+    /// Whether to read the answer as a single line from the standard input when it
+    /// is not a terminal, e.g. when piped, instead of failing with
+    /// [InquireError::NotTTY].
+    pub stdin_fallback: bool,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -143,10 +224,28 @@ impl<'a> Text<'a> {
             initial_value: None,
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            help_message_on_empty_only: false,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
             page_size: Self::DEFAULT_PAGE_SIZE,
+            multiline: false,
+            stdin_fallback: false,
+            strip_pasted_newlines: false,
+            char_filter: None,
+            input_transformer: None,
+            mask: None,
+            partial_mask: false,
+            max_length: None,
+            length_counter: false,
+            trim: false,
+            numeric: false,
+            allow_negative: false,
+            allow_decimal: false,
             autocompleter: None,
+            suggestions: None,
+            suggestion_filter: SuggestionFilter::default(),
+            inline_suggestion: false,
+            history: None,
             render_config: get_configuration(),
         }
     }
@@ -157,6 +256,13 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets whether the help message is only displayed while the input is empty,
+    /// disappearing once the user starts typing.
+    pub fn with_help_message_on_empty_only(mut self, on_empty_only: bool) -> Self {
+        self.help_message_on_empty_only = on_empty_only;
+        self
+    }
+
     /// Sets the initial value of the prompt's text input.
     ///
     /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
@@ -185,6 +291,75 @@ impl<'a> Text<'a> {
         AC: Autocomplete + 'static,
     {
         self.autocompleter = Some(Box::new(ac));
+        self.suggestions = None;
+        self
+    }
+
+    /// Sets a static list of suggestions as the autocompleter.
+    ///
+    /// Suggestions matching the current input are displayed below the prompt, by default
+    /// those starting with it, ignoring case. Pressing `tab` completes the input to the
+    /// first matching suggestion, and pressing it again cycles through the remaining matches.
+    pub fn with_suggestions<S>(mut self, suggestions: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.suggestions = Some(suggestions.iter().map(|s| s.as_ref().to_owned()).collect());
+        self.autocompleter = None;
+        self
+    }
+
+    /// Sets how the static list of suggestions is matched against the input, e.g. by
+    /// prefix or substring, and whether case is ignored.
+    ///
+    /// The filter is also used to pick the inline suggestion, see
+    /// [`with_inline_suggestion`](Self::with_inline_suggestion).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{autocompletion::SuggestionFilter, Text};
+    ///
+    /// let fruit = Text::new("Fruit:")
+    ///     .with_suggestions(&["Apple", "Pineapple", "Banana"])
+    ///     .with_suggestion_filter(SuggestionFilter::ContainsCaseInsensitive)
+    ///     .prompt();
+    /// ```
+    pub fn with_suggestion_filter(mut self, suggestion_filter: SuggestionFilter) -> Self {
+        self.suggestion_filter = suggestion_filter;
+        self
+    }
+
+    /// Sets whether to render the remainder of the top suggestion inline, after
+    /// the cursor, in a dimmed style.
+    ///
+    /// The inline suggestion is the first suggestion returned by the autocompleter
+    /// that starts with the current input, ignoring case unless the
+    /// [suggestion filter](Self::with_suggestion_filter) is case-sensitive. Pressing
+    /// `right` or `end` with the cursor at the end of the input replaces the input
+    /// with it, and typing anything else recomputes it.
+    pub fn with_inline_suggestion(mut self, inline_suggestion: bool) -> Self {
+        self.inline_suggestion = inline_suggestion;
+        self
+    }
+
+    /// Sets the history of previous answers, recalled with the up and down arrow keys.
+    ///
+    /// Editing a recalled entry leaves the history untouched. When the answer is
+    /// submitted, it is appended to the history, making it available to the next
+    /// prompts it is passed to.
+    pub fn with_history(mut self, history: &'a mut History) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Sets whether to read the answer as a single line from the standard input when
+    /// it is not a terminal, e.g. when the program is run as `echo "answer" | program`.
+    ///
+    /// In that case, nothing is rendered, while validators and the formatter still run.
+    /// If the line is rejected by a validator, an [InquireError::IO] error is returned.
+    pub fn with_stdin_fallback(mut self, stdin_fallback: bool) -> Self {
+        self.stdin_fallback = stdin_fallback;
         self
     }
 
@@ -200,6 +375,162 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets a filter applied to each character typed or pasted into the input.
+    ///
+    /// Characters rejected by the filter are simply not inserted. Unlike validators,
+    /// which are only checked when the answer is submitted, the filter prevents
+    /// unwanted characters from being entered at all.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let code = Text::new("OTP code:")
+    ///     .with_char_filter(&|c| c.is_ascii_digit())
+    ///     .prompt();
+    /// ```
+    pub fn with_char_filter(mut self, char_filter: CharFilter<'a>) -> Self {
+        self.char_filter = Some(char_filter);
+        self
+    }
+
+    /// Sets a transformation applied to each character typed or pasted into the input.
+    ///
+    /// The transformed character is the one inserted in the input, so the user sees
+    /// exactly what will be returned, and editing operates on the transformed content.
+    /// Characters mapped to `None` are not inserted. Unlike the formatter, which only
+    /// affects how the submitted answer is displayed, the transformer changes the answer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let username = Text::new("Username:")
+    ///     .with_input_transformer(&|c| c.to_lowercase().next())
+    ///     .prompt();
+    /// ```
+    pub fn with_input_transformer(mut self, input_transformer: CharTransformer<'a>) -> Self {
+        self.input_transformer = Some(input_transformer);
+        self
+    }
+
+    /// Sets a mask describing the structure of the input, such as phone numbers or dates.
+    ///
+    /// In the mask, `#` accepts a digit, `A` accepts a letter, and any other character
+    /// is a literal inserted automatically as the user types, with literals ending the
+    /// mask inserted along with the last fillable position. Backspace skips back over
+    /// literals to the previous fillable position. The answer is the formatted string,
+    /// and it can only be submitted once the whole mask is filled, unless partial answers
+    /// are allowed with [`with_partial_mask`](Self::with_partial_mask).
+    ///
+    /// When no placeholder is set, the mask itself is displayed as the placeholder.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let phone = Text::new("Phone number:")
+    ///     .with_mask("(###) ###-####")
+    ///     .prompt();
+    /// ```
+    pub fn with_mask(mut self, mask: &'a str) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets whether answers that do not fill the whole mask can be submitted.
+    pub fn with_partial_mask(mut self, partial_mask: bool) -> Self {
+        self.partial_mask = partial_mask;
+        self
+    }
+
+    /// Sets whether new lines pasted into the input are removed.
+    ///
+    /// By default, pasted new lines and tabs are converted to spaces, so that pasting
+    /// multi-line text does not submit the prompt halfway. Ignored in multi-line mode,
+    /// where pasted new lines are kept.
+    pub fn with_strip_pasted_newlines(mut self, strip_pasted_newlines: bool) -> Self {
+        self.strip_pasted_newlines = strip_pasted_newlines;
+        self
+    }
+
+    /// Sets the maximum amount of graphemes accepted in the input.
+    ///
+    /// Keystrokes that would grow the input past this limit are silently ignored.
+    /// Emojis and other multi-codepoint characters count as one.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets whether to display a live counter of the input length to the right
+    /// of the input, e.g. 12/80 when a maximum length is set.
+    pub fn with_length_counter(mut self, length_counter: bool) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
+    /// Sets whether leading and trailing whitespace, including new lines, is trimmed
+    /// from the answer.
+    ///
+    /// The raw content is displayed while editing, but validators, the formatter and
+    /// the returned answer all receive the trimmed value. An input made only of
+    /// whitespace is treated as empty, submitting the default value if set.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether the input only accepts numbers.
+    ///
+    /// In numeric mode, only digits can be typed, and submitting anything that is not a
+    /// number shows an error. The answer is still returned as a string, to be parsed by
+    /// the caller. Negative and decimal numbers can be allowed with
+    /// [`with_allow_negative`](Self::with_allow_negative) and
+    /// [`with_allow_decimal`](Self::with_allow_decimal).
+    ///
+    /// For a prompt that parses the answer into a typed value, see [CustomType](crate::CustomType).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let port = Text::new("Port:")
+    ///     .with_numeric(true)
+    ///     .prompt()
+    ///     .map(|port| port.parse::<u16>());
+    /// ```
+    pub fn with_numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets whether a leading minus sign is accepted in numeric mode.
+    pub fn with_allow_negative(mut self, allow_negative: bool) -> Self {
+        self.allow_negative = allow_negative;
+        self
+    }
+
+    /// Sets whether a single decimal point is accepted in numeric mode.
+    pub fn with_allow_decimal(mut self, allow_decimal: bool) -> Self {
+        self.allow_decimal = allow_decimal;
+        self
+    }
+
+    /// Sets whether the input spans multiple lines.
+    ///
+    /// When enabled, pressing `enter` inserts a new line and `ctrl+d` submits
+    /// the answer. The final rendering of the answer only shows its first line,
+    /// followed by an ellipsis when there are more.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the user's answer, such as
     /// defining a limit of characters.
@@ -238,6 +569,20 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Adds the validators to the collection of validators in the order they are given,
+    /// taking ownership of them. Useful when the list is built at runtime.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the user's answer, such as defining a limit of characters.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_owned_validators(mut self, validators: Vec<Box<dyn StringValidator>>) -> Self {
+        self.validators.extend(validators);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -261,25 +606,86 @@ impl<'a> Text<'a> {
     /// Meanwhile, if the user does submit an answer, the method wraps the return
     /// type with `Some`.
     pub fn prompt_skippable(self) -> InquireResult<Option<String>> {
-        match self.prompt() {
-            Ok(answer) => Ok(Some(answer)),
-            Err(InquireError::OperationCanceled) => Ok(None),
-            Err(err) => Err(err),
+        if self.stdin_fallback && !stdin_is_tty() {
+            let mut backend = Backend::new(LineTerminal::from_stdin()?, self.render_config)?;
+            return self.prompt_skippable_with_backend(&mut backend);
         }
+
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_skippable_with_backend(&mut backend)
     }
 
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
+        if self.stdin_fallback && !stdin_is_tty() {
+            let mut backend = Backend::new(LineTerminal::from_stdin()?, self.render_config)?;
+            return self.prompt_with_backend(&mut backend);
+        }
+
         let terminal = get_default_terminal()?;
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Along with the answer, returns whether it is the default value, submitted
+    /// when the user pressed `enter` on an empty input.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let (host, used_default) = Text::new("Database host:")
+    ///     .with_default("localhost")
+    ///     .prompt_with_default_flag()
+    ///     .unwrap();
+    ///
+    /// if used_default {
+    ///     println!("Using default host {}", host);
+    /// }
+    /// ```
+    pub fn prompt_with_default_flag(self) -> InquireResult<(String, bool)> {
+        if self.stdin_fallback && !stdin_is_tty() {
+            let mut backend = Backend::new(LineTerminal::from_stdin()?, self.render_config)?;
+            return self.prompt_with_default_flag_with_backend(&mut backend);
+        }
+
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_default_flag_with_backend(&mut backend)
+    }
+
+    pub(crate) fn prompt_skippable_with_backend<B: TextBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Option<String>> {
+        match self.prompt_with_backend(backend) {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     pub(crate) fn prompt_with_backend<B: TextBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
-        TextPrompt::from(self).prompt(backend)
+        TextPrompt::from(self)
+            .prompt(backend)
+            .map(|answer| answer.value)
+    }
+
+    pub(crate) fn prompt_with_default_flag_with_backend<B: TextBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(String, bool)> {
+        TextPrompt::from(self)
+            .prompt(backend)
+            .map(|answer| (answer.value, answer.used_default))
     }
 }
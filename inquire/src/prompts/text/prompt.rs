@@ -1,38 +1,83 @@
 use std::cmp::min;
 
 use crate::{
-    autocompletion::{NoAutoCompletion, Replacement},
+    autocompletion::{NoAutoCompletion, Replacement, StaticAutoCompletion, SuggestionFilter},
     error::InquireResult,
     formatter::StringFormatter,
-    input::{Input, InputActionResult},
+    history::History,
+    input::{Input, InputActionResult, LineDirection, Magnitude},
     list_option::ListOption,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::{CharFilter, CharTransformer},
     ui::TextBackend,
     utils::paginate,
     validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    Autocomplete, InputAction, InquireError, Text,
 };
 
-use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
+use super::{
+    action::TextPromptAction, config::TextConfig, mask::Mask, DEFAULT_HELP_MESSAGE_WITH_AC,
+};
+
+/// Answer submitted to a TextPrompt.
+pub struct TextAnswer {
+    pub value: String,
+    /// Whether the value is the default one, submitted on an empty input.
+    pub used_default: bool,
+}
+
+/// Maximum amount of snapshots kept in the undo stack.
+const UNDO_CAPACITY: usize = 50;
+
+/// Kind of the last edit, used to group consecutive keystrokes into a single
+/// undo step.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+}
 
 pub struct TextPrompt<'a> {
     message: &'a str,
     config: TextConfig,
     default: Option<&'a str>,
     help_message: Option<&'a str>,
+    help_message_on_empty_only: bool,
     input: Input,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete>,
+    /// Whether the autocompleter is built from a static list of suggestions,
+    /// whose highlighted suggestion follows the input completed to it.
+    static_suggestions: bool,
     suggested_options: Vec<String>,
     suggestion_cursor_index: Option<usize>,
+    inline_suggestion: bool,
+    suggestion_filter: SuggestionFilter,
+    length_counter: bool,
+    char_filter: Option<CharFilter<'a>>,
+    input_transformer: Option<CharTransformer<'a>>,
+    mask: Option<Mask<'a>>,
+    partial_mask: bool,
+    trim: bool,
+    numeric: bool,
+    allow_negative: bool,
+    allow_decimal: bool,
+    history: Option<&'a mut History>,
+    history_index: Option<usize>,
+    stashed_input: Option<Input>,
+    undo_stack: Vec<Input>,
+    redo_stack: Vec<Input>,
+    last_edit: Option<EditKind>,
+    kill_buffer: Option<String>,
 }
 
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
     fn from(so: Text<'a>) -> Self {
-        let input = Input::new_with(so.initial_value.unwrap_or_default());
-        let input = if let Some(placeholder) = so.placeholder {
+        let input =
+            Input::new_with(so.initial_value.unwrap_or_default()).with_max_length(so.max_length);
+        let input = if let Some(placeholder) = so.placeholder.or(so.mask) {
             input.with_placeholder(placeholder)
         } else {
             input
@@ -43,15 +88,39 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
             config: (&so).into(),
             default: so.default,
             help_message: so.help_message,
+            help_message_on_empty_only: so.help_message_on_empty_only,
             formatter: so.formatter,
-            autocompleter: so
-                .autocompleter
-                .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
+            static_suggestions: so.autocompleter.is_none() && so.suggestions.is_some(),
+            autocompleter: match (so.autocompleter, so.suggestions) {
+                (Some(autocompleter), _) => autocompleter,
+                (None, Some(suggestions)) => Box::new(
+                    StaticAutoCompletion::new(&suggestions).with_filter(so.suggestion_filter),
+                ),
+                (None, None) => Box::<NoAutoCompletion>::default(),
+            },
             input,
             error: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
+            inline_suggestion: so.inline_suggestion,
+            suggestion_filter: so.suggestion_filter,
             validators: so.validators,
+            length_counter: so.length_counter,
+            char_filter: so.char_filter,
+            input_transformer: so.input_transformer,
+            mask: so.mask.map(Mask::new),
+            partial_mask: so.partial_mask,
+            trim: so.trim,
+            numeric: so.numeric,
+            allow_negative: so.allow_negative,
+            allow_decimal: so.allow_decimal,
+            history: so.history,
+            history_index: None,
+            stashed_input: None,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            last_edit: None,
+            kill_buffer: None,
         }
     }
 }
@@ -79,6 +148,198 @@ impl<'a> TextPrompt<'a> {
         }
     }
 
+    /// First suggestion starting with the current input, along with the byte index
+    /// where the matched prefix ends. Case is ignored unless the suggestion filter
+    /// is case-sensitive.
+    fn get_inline_match(&self) -> Option<(&str, usize)> {
+        if !self.inline_suggestion
+            || self.input.is_empty()
+            || self.input.cursor() != self.input.length()
+            || self.suggestion_cursor_index.is_some()
+        {
+            return None;
+        }
+
+        let content = self.input.content();
+        let filter = match self.suggestion_filter {
+            SuggestionFilter::Prefix | SuggestionFilter::Contains => SuggestionFilter::Prefix,
+            SuggestionFilter::PrefixCaseInsensitive | SuggestionFilter::ContainsCaseInsensitive => {
+                SuggestionFilter::PrefixCaseInsensitive
+            }
+        };
+
+        self.suggested_options.iter().find_map(|s| {
+            filter
+                .matched_range(s, content)
+                .filter(|range| range.end < s.len())
+                .map(|range| (s.as_str(), range.end))
+        })
+    }
+
+    /// Remainder of the inline match, displayed after the cursor when it is at the
+    /// end of the input.
+    fn get_inline_suggestion(&self) -> Option<&str> {
+        self.get_inline_match()
+            .map(|(suggestion, end)| &suggestion[end..])
+    }
+
+    fn accept_inline_suggestion(&mut self) -> InquireResult<ActionResult> {
+        let value = match self.get_inline_match() {
+            Some((suggestion, _)) => suggestion.to_owned(),
+            None => return Ok(ActionResult::Clean),
+        };
+
+        self.input.replace_content(value);
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Handles input actions while a mask is set. The cursor is kept at the end
+    /// of the input, so only typing, backspace and clearing are supported.
+    fn handle_masked_input(
+        &mut self,
+        mask: Mask<'_>,
+        action: InputAction,
+    ) -> InquireResult<ActionResult> {
+        let actions = match action {
+            InputAction::Write(c) => match mask.write(self.input.content(), c) {
+                Some(appended) => appended.chars().map(InputAction::Write).collect(),
+                None => vec![],
+            },
+            InputAction::Delete(Magnitude::Char, LineDirection::Left) => {
+                vec![action; mask.backspace(self.input.content())]
+            }
+            InputAction::Clear => vec![action],
+            _ => vec![],
+        };
+
+        let mut result = InputActionResult::Clean;
+        for action in actions {
+            if let InputActionResult::ContentChanged = self.input.handle(action) {
+                result = InputActionResult::ContentChanged;
+            }
+        }
+
+        if let InputActionResult::ContentChanged = result {
+            self.update_suggestions()?;
+        }
+
+        Ok(result.into())
+    }
+
+    /// Records the input before an edit in the undo stack. Consecutive insertions
+    /// within a word and consecutive character deletions are grouped in a single
+    /// step, while other edits always start a new one.
+    fn record_edit(&mut self, snapshot: Input, action: TextPromptAction) {
+        if snapshot.content() == self.input.content() {
+            if let TextPromptAction::ValueInput(InputAction::MoveCursor(_, _)) = action {
+                self.last_edit = None;
+            }
+            return;
+        }
+
+        let kind = match action {
+            TextPromptAction::ValueInput(InputAction::Write(c)) if !c.is_whitespace() => {
+                Some(EditKind::Insert)
+            }
+            TextPromptAction::ValueInput(InputAction::Delete(Magnitude::Char, _)) => {
+                Some(EditKind::Delete)
+            }
+            _ => None,
+        };
+
+        if kind.is_none() || kind != self.last_edit {
+            if self.undo_stack.len() == UNDO_CAPACITY {
+                self.undo_stack.remove(0);
+            }
+            self.undo_stack.push(snapshot);
+        }
+
+        self.last_edit = kind;
+        self.redo_stack.clear();
+    }
+
+    /// Stores the text removed by a kill, i.e. a deletion of a word, of the rest
+    /// of the line or of the whole input, in the kill buffer. Deleted spans are
+    /// delimited by the cursor, which is always at a grapheme boundary.
+    fn record_kill(&mut self, snapshot: &Input, action: TextPromptAction) {
+        let removed = snapshot
+            .content()
+            .len()
+            .saturating_sub(self.input.content().len());
+        if removed == 0 {
+            return;
+        }
+
+        let killed = match action {
+            TextPromptAction::ValueInput(InputAction::Clear) => snapshot.content(),
+            TextPromptAction::ValueInput(InputAction::Delete(
+                Magnitude::Word | Magnitude::Line,
+                LineDirection::Left,
+            )) => &snapshot.pre_cursor()[self.input.pre_cursor().len()..],
+            TextPromptAction::ValueInput(InputAction::Delete(
+                Magnitude::Word | Magnitude::Line,
+                LineDirection::Right,
+            )) => {
+                let start = snapshot.pre_cursor().len();
+                &snapshot.content()[start..start + removed]
+            }
+            _ => return,
+        };
+
+        self.kill_buffer = Some(killed.to_owned());
+    }
+
+    fn yank(&mut self) -> InquireResult<ActionResult> {
+        let killed = match &self.kill_buffer {
+            Some(killed) => killed.clone(),
+            None => return Ok(ActionResult::Clean),
+        };
+
+        let mut result = InputActionResult::Clean;
+        for c in killed.chars() {
+            if let InputActionResult::ContentChanged = self.input.handle(InputAction::Write(c)) {
+                result = InputActionResult::ContentChanged;
+            }
+        }
+
+        if let InputActionResult::ContentChanged = result {
+            self.update_suggestions()?;
+        }
+
+        Ok(result.into())
+    }
+
+    fn undo(&mut self) -> InquireResult<ActionResult> {
+        match self.undo_stack.pop() {
+            Some(previous) => {
+                let current = std::mem::replace(&mut self.input, previous);
+                self.redo_stack.push(current);
+                self.restore_edit()
+            }
+            None => Ok(ActionResult::Clean),
+        }
+    }
+
+    fn redo(&mut self) -> InquireResult<ActionResult> {
+        match self.redo_stack.pop() {
+            Some(next) => {
+                let current = std::mem::replace(&mut self.input, next);
+                self.undo_stack.push(current);
+                self.restore_edit()
+            }
+            None => Ok(ActionResult::Clean),
+        }
+    }
+
+    fn restore_edit(&mut self) -> InquireResult<ActionResult> {
+        self.last_edit = None;
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
         let new_cursor_index = match self.suggestion_cursor_index {
             None => None,
@@ -117,6 +378,51 @@ impl<'a> TextPrompt<'a> {
         }
     }
 
+    fn move_history_up(&mut self) -> InquireResult<ActionResult> {
+        let len = match &self.history {
+            Some(history) if !history.is_empty() => history.len(),
+            _ => return Ok(ActionResult::Clean),
+        };
+
+        let new_index = match self.history_index {
+            None => {
+                self.stashed_input = Some(self.input.clone());
+                len - 1
+            }
+            Some(0) => return Ok(ActionResult::Clean),
+            Some(index) => index - 1,
+        };
+
+        self.load_history_entry(Some(new_index))
+    }
+
+    fn move_history_down(&mut self) -> InquireResult<ActionResult> {
+        let (len, index) = match (&self.history, self.history_index) {
+            (Some(history), Some(index)) => (history.len(), index),
+            _ => return Ok(ActionResult::Clean),
+        };
+
+        let new_index = Some(index + 1).filter(|i| *i < len);
+
+        self.load_history_entry(new_index)
+    }
+
+    fn load_history_entry(&mut self, index: Option<usize>) -> InquireResult<ActionResult> {
+        let entry = index.and_then(|i| self.history.as_ref()?.get(i).map(str::to_owned));
+
+        match entry {
+            Some(entry) => self.input.replace_content(entry),
+            None => match self.stashed_input.take() {
+                Some(stashed_input) => self.input = stashed_input,
+                None => self.input.replace_content(""),
+            },
+        }
+        self.history_index = index;
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
         let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
         match self
@@ -124,13 +430,57 @@ impl<'a> TextPrompt<'a> {
             .get_completion(self.input.content(), suggestion)?
         {
             Replacement::Some(value) => {
-                self.input = Input::new_with(value);
+                let len = value.len();
+                self.input.replace_content(value);
+
+                if self.static_suggestions {
+                    // consecutive tab presses cycle from the completed suggestion.
+                    self.suggestion_cursor_index = self
+                        .suggested_options
+                        .iter()
+                        .position(|s| s == self.input.content());
+                } else if self.input.content().len() < len {
+                    // the highlighted suggestion would bypass the max length.
+                    self.suggestion_cursor_index = None;
+                }
                 Ok(ActionResult::NeedsRedraw)
             }
             Replacement::None => Ok(ActionResult::Clean),
         }
     }
 
+    fn use_previous_suggestion(&mut self) -> InquireResult<ActionResult> {
+        let last = match self.suggested_options.len() {
+            0 => return Ok(ActionResult::Clean),
+            len => len - 1,
+        };
+
+        self.suggestion_cursor_index = match self.suggestion_cursor_index {
+            None | Some(0) => Some(last),
+            Some(index) => Some(index - 1),
+        };
+
+        self.use_current_suggestion()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Whether the current answer is the default value, which happens when the
+    /// input is empty and no suggestion is highlighted.
+    fn uses_default(&self) -> bool {
+        self.get_highlighted_suggestion().is_none()
+            && self.get_current_content().is_empty()
+            && self.default.is_some()
+    }
+
+    /// Content of the input, trimmed if enabled.
+    fn get_current_content(&self) -> &str {
+        match self.trim {
+            true => self.input.content().trim(),
+            false => self.input.content(),
+        }
+    }
+
     fn get_current_answer(&self) -> &str {
         // If there is a highlighted suggestion, assume user wanted it as
         // the answer.
@@ -138,17 +488,69 @@ impl<'a> TextPrompt<'a> {
             return suggestion;
         }
 
+        let content = self.get_current_content();
+
         // Empty input with default values override any validators.
-        if self.input.content().is_empty() {
+        if content.is_empty() {
             if let Some(val) = self.default {
                 return val;
             }
         }
 
-        self.input.content()
+        content
+    }
+
+    /// Whether `c` can be typed at the cursor in numeric mode. The minus sign is
+    /// only accepted at the start, and nothing can be typed before it.
+    fn accepts_numeric_char(&self, c: char) -> bool {
+        let content = self.input.content();
+        let at_start = self.input.cursor() == 0;
+
+        if at_start && content.starts_with('-') {
+            return false;
+        }
+
+        match c {
+            '-' => self.allow_negative && at_start,
+            '.' => self.allow_decimal && !content.contains('.'),
+            c => c.is_ascii_digit(),
+        }
+    }
+
+    fn is_number(&self, value: &str) -> bool {
+        let value = match value.strip_prefix('-') {
+            Some(value) if self.allow_negative => value,
+            _ => value,
+        };
+
+        let (integer, fraction) = match value.split_once('.') {
+            Some((integer, fraction)) if self.allow_decimal => (integer, fraction),
+            _ => (value, ""),
+        };
+
+        let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+        !(integer.is_empty() && fraction.is_empty()) && digits(integer) && digits(fraction)
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        let uses_default = self.uses_default();
+
+        if let Some(mask) = self.mask.filter(|_| !self.partial_mask && !uses_default) {
+            if !mask.is_complete(self.get_current_answer()) {
+                return Ok(Validation::Invalid(ErrorMessage::Custom(format!(
+                    "Incomplete input, expected format {}",
+                    mask.pattern()
+                ))));
+            }
+        }
+
+        if self.numeric && !uses_default && !self.is_number(self.get_current_answer()) {
+            return Ok(Validation::Invalid(ErrorMessage::Custom(
+                "Please enter a number".into(),
+            )));
+        }
+
         for validator in &self.validators {
             match validator.validate(self.get_current_answer()) {
                 Ok(Validation::Valid) => {}
@@ -167,7 +569,7 @@ where
 {
     type Config = TextConfig;
     type InnerAction = TextPromptAction;
-    type Output = String;
+    type Output = TextAnswer;
 
     fn message(&self) -> &str {
         self.message
@@ -177,17 +579,31 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &String) -> String {
-        (self.formatter)(answer)
+    fn format_answer(&self, answer: &TextAnswer) -> String {
+        let formatted = (self.formatter)(&answer.value);
+
+        match formatted.split_once('\n') {
+            Some((first_line, _)) if self.config.multiline => format!("{first_line}…"),
+            _ => formatted,
+        }
     }
 
     fn setup(&mut self) -> InquireResult<()> {
         self.update_suggestions()
     }
 
-    fn submit(&mut self) -> InquireResult<Option<String>> {
+    fn submit(&mut self) -> InquireResult<Option<TextAnswer>> {
         let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
+            Validation::Valid => {
+                let value = self.get_current_answer().to_owned();
+                if let Some(history) = &mut self.history {
+                    history.record(&value);
+                }
+                Some(TextAnswer {
+                    value,
+                    used_default: self.uses_default(),
+                })
+            }
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
                 None
@@ -198,15 +614,60 @@ where
     }
 
     fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
+        let action = match (action, self.input_transformer) {
+            (TextPromptAction::ValueInput(InputAction::Write(c)), Some(transformer)) => {
+                match transformer(c) {
+                    Some(c) => TextPromptAction::ValueInput(InputAction::Write(c)),
+                    None => return Ok(ActionResult::Clean),
+                }
+            }
+            (action, _) => action,
+        };
+
+        // history navigation keeps its own stash of the typed input
+        let snapshot = match action {
+            TextPromptAction::ValueInput(_)
+            | TextPromptAction::UseCurrentSuggestion
+            | TextPromptAction::UsePreviousSuggestion
+            | TextPromptAction::Yank => Some(self.input.clone()),
+            _ => None,
+        };
+
         let result = match action {
+            TextPromptAction::ValueInput(InputAction::Write(c))
+                if !self.char_filter.map(|f| f(c)).unwrap_or(true) =>
+            {
+                ActionResult::Clean
+            }
+            TextPromptAction::ValueInput(InputAction::Write(c))
+                if self.numeric && !self.accepts_numeric_char(c) =>
+            {
+                ActionResult::Clean
+            }
+            TextPromptAction::ValueInput(InputAction::MoveCursor(
+                Magnitude::Char | Magnitude::Line,
+                LineDirection::Right,
+            )) if self.mask.is_none() && self.get_inline_suggestion().is_some() => {
+                self.accept_inline_suggestion()?
+            }
             TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
+                if let Some(mask) = self.mask {
+                    self.handle_masked_input(mask, input_action)?
+                } else {
+                    let result = self.input.handle(input_action);
 
-                if let InputActionResult::ContentChanged = result {
-                    self.update_suggestions()?;
-                }
+                    if let InputActionResult::ContentChanged = result {
+                        self.update_suggestions()?;
+                    }
 
-                result.into()
+                    result.into()
+                }
+            }
+            TextPromptAction::MoveToSuggestionAbove if self.suggested_options.is_empty() => {
+                self.move_history_up()?
+            }
+            TextPromptAction::MoveToSuggestionBelow if self.suggested_options.is_empty() => {
+                self.move_history_down()?
             }
             TextPromptAction::MoveToSuggestionAbove => self.move_cursor_up(1),
             TextPromptAction::MoveToSuggestionBelow => self.move_cursor_down(1),
@@ -215,8 +676,17 @@ where
                 self.move_cursor_down(self.config.page_size)
             }
             TextPromptAction::UseCurrentSuggestion => self.use_current_suggestion()?,
+            TextPromptAction::UsePreviousSuggestion => self.use_previous_suggestion()?,
+            TextPromptAction::Undo => self.undo()?,
+            TextPromptAction::Redo => self.redo()?,
+            TextPromptAction::Yank => self.yank()?,
         };
 
+        if let Some(snapshot) = snapshot {
+            self.record_kill(&snapshot, action);
+            self.record_edit(snapshot, action);
+        }
+
         Ok(result)
     }
 
@@ -227,7 +697,16 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        // the default value hint disappears once the user types anything
+        let default = self.default.filter(|_| self.input.is_empty());
+
+        backend.render_prompt(
+            prompt,
+            default,
+            &self.input,
+            self.get_inline_suggestion(),
+            self.length_counter,
+        )?;
 
         let choices = self
             .suggested_options
@@ -245,7 +724,9 @@ where
         backend.render_suggestions(page)?;
 
         if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
+            if !self.help_message_on_empty_only || self.input.is_empty() {
+                backend.render_help_message(message)?;
+            }
         } else if !choices.is_empty() {
             backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
         }
@@ -1,18 +1,28 @@
 use super::Text;
 use crate::{
+    autocompletion::{Replacement, StaticAutoCompletion, SuggestionFilter},
+    history::History,
+    input::Input,
+    prompts::action::InnerAction,
     terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
+    ui::{Backend, Key, RenderConfig},
     validator::{ErrorMessage, Validation},
+    Autocomplete, CustomUserError, InquireError, TextPromptAction,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 fn default<'a>() -> Text<'a> {
     Text::new("Question?")
 }
 
+// new lines are sent as enter key presses, a `KeyCode::Char('\n')` being
+// handled as a pasted character instead.
 macro_rules! text_to_events {
     ($text:expr) => {{
-        $text.chars().map(KeyCode::Char)
+        $text.chars().map(|c| match c {
+            '\n' => KeyCode::Enter,
+            c => KeyCode::Char(c),
+        })
     }};
 }
 
@@ -114,3 +124,1721 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+text_test!(
+    insert_in_the_middle_after_moving_left,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("helo").collect());
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Char('l'));
+        events.push(KeyCode::Enter);
+        events
+    },
+    "hello"
+);
+
+text_test!(
+    backspace_in_the_middle_of_emoji,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("🌍🍞x🚗").collect());
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Backspace);
+        events.push(KeyCode::Right);
+        events.push(KeyCode::Char('!'));
+        events.push(KeyCode::Enter);
+        events
+    },
+    "🌍x!🚗"
+);
+
+text_test!(
+    home_and_end_move_to_line_edges,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("ello").collect());
+        events.push(KeyCode::Home);
+        events.push(KeyCode::Char('h'));
+        events.push(KeyCode::End);
+        events.push(KeyCode::Char('!'));
+        events.push(KeyCode::Enter);
+        events
+    },
+    "hello!"
+);
+
+text_test!(
+    delete_removes_grapheme_after_cursor,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("a🍞b").collect());
+        events.push(KeyCode::Delete);
+        events.push(KeyCode::Home);
+        events.push(KeyCode::Delete);
+        events.push(KeyCode::Delete);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "b"
+);
+
+text_test!(
+    ctrl_w_deletes_previous_words,
+    {
+        let mut events = vec![];
+        events.append(
+            &mut text_to_events!("send 🍞, to the moon")
+                .map(KeyEvent::from)
+                .collect(),
+        );
+        events.push(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        events.push(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        events.push(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("home\n").map(KeyEvent::from).collect());
+        events
+    },
+    "send 🍞, home"
+);
+
+text_test!(
+    ctrl_w_in_the_middle_keeps_suffix,
+    {
+        let mut events = vec![];
+        events.append(
+            &mut text_to_events!("hello big world")
+                .map(KeyEvent::from)
+                .collect(),
+        );
+        for _ in 0..6 {
+            events.push(KeyEvent::from(KeyCode::Left));
+        }
+        events.push(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        events.push(KeyEvent::from(KeyCode::Enter));
+        events
+    },
+    "hello  world"
+);
+
+text_test!(
+    ctrl_u_clears_the_input,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("garbage").map(KeyEvent::from).collect());
+        events.push(KeyEvent::from(KeyCode::Left));
+        events.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("answer\n").map(KeyEvent::from).collect());
+        events
+    },
+    "answer"
+);
+
+text_test!(
+    word_movement_with_ctrl_and_alt_aliases,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("big   world").map(KeyEvent::from).collect());
+        events.push(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+        events.push(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT));
+        events.push(KeyEvent::from(KeyCode::Char('a')));
+        events.push(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::ALT));
+        events.push(KeyEvent::from(KeyCode::Char('!')));
+        events.push(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL));
+        events.push(KeyEvent::from(KeyCode::Char('?')));
+        events.push(KeyEvent::from(KeyCode::Enter));
+        events
+    },
+    "abig!   world?"
+);
+
+text_test!(
+    tab_completes_to_first_matching_suggestion,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("fo").collect());
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "foo",
+    Text::new("Question?").with_suggestions(&["bar", "foo", "baz", "foobar"])
+);
+
+text_test!(
+    tab_cycles_through_matching_suggestions,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("fo").collect());
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "foobar",
+    Text::new("Question?").with_suggestions(&["bar", "foo", "baz", "foobar"])
+);
+
+text_test!(
+    tab_cycling_wraps_around,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("fo").collect());
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "foo",
+    Text::new("Question?").with_suggestions(&["bar", "foo", "baz", "foobar"])
+);
+
+text_test!(
+    suggestions_follow_typed_input,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("fo").collect());
+        events.push(KeyCode::Tab);
+        events.append(&mut text_to_events!("b").collect());
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "foobar",
+    Text::new("Question?").with_suggestions(&["bar", "foo", "baz", "foobar"])
+);
+
+text_test!(
+    enter_accepts_the_buffer_over_suggestions,
+    text_to_events!("ba\n"),
+    "ba",
+    Text::new("Question?").with_suggestions(&["bar", "foo", "baz", "foobar"])
+);
+
+text_test!(
+    tab_without_suggestions_is_noop,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("abc").collect());
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "abc",
+    Text::new("Question?").with_suggestions::<&str>(&[])
+);
+
+#[derive(Clone, Default)]
+struct UsernameCompleter {
+    last_input: Option<String>,
+    cached: Vec<String>,
+}
+
+impl Autocomplete for UsernameCompleter {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        if self.last_input.as_deref() != Some(input) {
+            self.cached = ["mikael", "mike", "mila"]
+                .iter()
+                .filter(|u| u.starts_with(input))
+                .map(|u| String::from(*u))
+                .collect();
+            self.last_input = Some(input.to_owned());
+        }
+
+        Ok(self.cached.clone())
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(highlighted_suggestion)
+    }
+}
+
+text_test!(
+    autocomplete_down_and_tab_replaces_input,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("mi").collect());
+        events.push(KeyCode::Down);
+        events.push(KeyCode::Down);
+        events.push(KeyCode::Tab);
+        events.append(&mut text_to_events!("!\n").collect());
+        events
+    },
+    "mike!",
+    Text::new("User:").with_autocomplete(UsernameCompleter::default())
+);
+
+text_test!(
+    autocomplete_up_moves_highlight_back,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("mi").collect());
+        events.push(KeyCode::Down);
+        events.push(KeyCode::Down);
+        events.push(KeyCode::Down);
+        events.push(KeyCode::Up);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "mike",
+    Text::new("User:").with_autocomplete(UsernameCompleter::default())
+);
+
+#[derive(Clone)]
+struct DirectoryCompleter;
+
+impl Autocomplete for DirectoryCompleter {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(vec![String::from("docs"), String::from("src")])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(highlighted_suggestion.map(|s| format!("{s}/")))
+    }
+}
+
+text_test!(
+    autocomplete_tab_keeps_the_highlight_of_custom_completers,
+    vec![KeyCode::Down, KeyCode::Tab, KeyCode::Down, KeyCode::Enter],
+    "src",
+    Text::new("Path:").with_autocomplete(DirectoryCompleter)
+);
+
+text_test!(
+    autocomplete_tab_without_highlight_is_noop,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("mi").collect());
+        events.push(KeyCode::Tab);
+        events.push(KeyCode::Enter);
+        events
+    },
+    "mi",
+    Text::new("User:").with_autocomplete(UsernameCompleter::default())
+);
+
+text_test!(
+    default_is_returned_on_empty_input,
+    vec![KeyCode::Enter],
+    "Lisbon",
+    Text::new("City:").with_default("Lisbon")
+);
+
+text_test!(
+    typed_input_overrides_default,
+    text_to_events!("Porto\n"),
+    "Porto",
+    Text::new("City:").with_default("Lisbon")
+);
+
+text_test!(
+    validators_run_against_default,
+    {
+        let mut events = vec![KeyCode::Enter];
+        events.append(&mut text_to_events!("Porto\n").collect());
+        events
+    },
+    "Porto",
+    Text::new("City:")
+        .with_default("Rio")
+        .with_validator(|ans: &str| match ans.len() {
+            len if len > 3 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid(ErrorMessage::Default)),
+        })
+);
+
+text_test!(
+    initial_value_is_returned_untouched,
+    vec![KeyCode::Enter],
+    "mikaelmello",
+    Text::new("Username:").with_initial_value("mikaelmello")
+);
+
+text_test!(
+    initial_value_is_editable,
+    {
+        let mut events = vec![KeyCode::Backspace, KeyCode::Backspace];
+        events.append(&mut text_to_events!("ow\n").collect());
+        events
+    },
+    "mikaelmelow",
+    Text::new("Username:").with_initial_value("mikaelmello")
+);
+
+text_test!(
+    initial_value_is_not_a_default,
+    {
+        let mut events = vec![KeyCode::Backspace; 11];
+        events.push(KeyCode::Enter);
+        events
+    },
+    "",
+    Text::new("Username:").with_initial_value("mikaelmello")
+);
+
+text_test!(
+    placeholder_is_never_returned,
+    vec![KeyCode::Enter],
+    "",
+    Text::new("E-mail:").with_placeholder("e.g. john@example.com")
+);
+
+#[test]
+fn placeholder_is_hidden_once_input_is_typed() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('a'),
+        KeyCode::Backspace,
+        KeyCode::Char('b'),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("E-mail:")
+            .with_placeholder("hint")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("b", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    // rendered on the first frame and after deleting back to an empty input
+    assert_eq!(2, output.matches("hint").count());
+}
+
+#[test]
+fn validation_error_persists_after_clearing_input() {
+    let mut read: Vec<KeyEvent> = vec![];
+    read.append(&mut text_to_events!("x\n").map(KeyEvent::from).collect());
+    read.push(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    read.push(KeyEvent::from(KeyCode::Enter));
+    read.append(&mut text_to_events!("valid\n").map(KeyEvent::from).collect());
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Question?")
+            .with_validator(|ans: &str| match ans.len() {
+                len if len > 3 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid("too short".into())),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("valid", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    // rendered after the first submit, after clearing the input, after the
+    // second submit and after each of the 5 typed characters
+    assert_eq!(8, output.matches("too short").count());
+}
+
+#[test]
+fn suggestions_are_filtered_live() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Char('a'), KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Fruit:")
+            .with_suggestions(&["apple", "banana"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("a", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    // only rendered on the first frame, before anything was typed
+    assert_eq!(1, output.matches("banana").count());
+    assert_eq!(2, output.matches("apple").count());
+}
+
+#[test]
+fn only_a_page_of_suggestions_is_rendered() {
+    let suggestions: Vec<String> = (0..100).map(|i| format!("item{i:02}")).collect();
+
+    let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Item:")
+            .with_suggestions(&suggestions)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("item00"));
+    assert!(output.contains("item02"));
+    assert!(!output.contains("item03"));
+}
+
+#[test]
+fn suggestion_page_follows_the_highlighted_candidate() {
+    let suggestions: Vec<String> = (0..100).map(|i| format!("item{i:02}")).collect();
+
+    let mut read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Down); 10];
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Item:")
+            .with_suggestions(&suggestions)
+            .with_page_size(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("item09", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("item10"));
+    assert!(!output.contains("item11"));
+}
+
+fn prompt_with_events(prompt: Text<'_>, events: Vec<KeyEvent>) -> String {
+    let mut read = events.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap()
+}
+
+fn events_of(text: &str) -> Vec<KeyEvent> {
+    text_to_events!(text).map(KeyEvent::from).collect()
+}
+
+#[test]
+fn history_is_shared_between_prompts() {
+    let mut history = History::new();
+
+    let ans = prompt_with_events(
+        Text::new("Host:").with_history(&mut history),
+        events_of("alpha\n"),
+    );
+    assert_eq!("alpha", ans);
+
+    let ans = prompt_with_events(
+        Text::new("Host:").with_history(&mut history),
+        events_of("beta\n"),
+    );
+    assert_eq!("beta", ans);
+
+    let mut events = vec![KeyEvent::from(KeyCode::Up), KeyEvent::from(KeyCode::Up)];
+    events.push(KeyEvent::from(KeyCode::Enter));
+    let ans = prompt_with_events(Text::new("Host:").with_history(&mut history), events);
+    assert_eq!("alpha", ans);
+
+    assert_eq!(vec!["alpha", "beta", "alpha"], history.entries());
+}
+
+#[test]
+fn editing_recalled_entry_does_not_mutate_history() {
+    let mut history = History::from(vec!["host1"]);
+
+    let mut events = vec![
+        KeyEvent::from(KeyCode::Up),
+        KeyEvent::from(KeyCode::Backspace),
+    ];
+    events.append(&mut events_of("2\n"));
+    let ans = prompt_with_events(Text::new("Host:").with_history(&mut history), events);
+
+    assert_eq!("host2", ans);
+    assert_eq!(vec!["host1", "host2"], history.entries());
+}
+
+#[test]
+fn history_restores_typed_buffer_past_newest_entry() {
+    let mut history = History::from(vec!["one", "two"]);
+
+    let mut events = events_of("draft");
+    events.push(KeyEvent::from(KeyCode::Up));
+    events.push(KeyEvent::from(KeyCode::Up));
+    events.push(KeyEvent::from(KeyCode::Up));
+    events.push(KeyEvent::from(KeyCode::Down));
+    events.push(KeyEvent::from(KeyCode::Down));
+    events.push(KeyEvent::from(KeyCode::Down));
+    events.push(KeyEvent::from(KeyCode::Enter));
+    let ans = prompt_with_events(Text::new("Host:").with_history(&mut history), events);
+
+    assert_eq!("draft", ans);
+}
+
+#[test]
+fn empty_answers_are_not_recorded_in_history() {
+    let mut history = History::new();
+
+    let ans = prompt_with_events(
+        Text::new("Host:").with_history(&mut history),
+        vec![KeyEvent::from(KeyCode::Enter)],
+    );
+
+    assert_eq!("", ans);
+    assert!(history.is_empty());
+}
+
+fn ctrl_d() -> KeyEvent {
+    KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)
+}
+
+#[test]
+fn multiline_enter_inserts_new_lines() {
+    let mut events = events_of("first line\nsecond line");
+    events.push(ctrl_d());
+
+    let ans = prompt_with_events(Text::new("Message:").with_multiline(true), events);
+
+    assert_eq!("first line\nsecond line", ans);
+}
+
+#[test]
+fn multiline_backspace_at_line_start_joins_lines() {
+    let mut events = events_of("foo\nbar");
+    events.append(&mut vec![KeyEvent::from(KeyCode::Left); 3]);
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(KeyEvent::from(KeyCode::Char(' ')));
+    events.push(ctrl_d());
+
+    let ans = prompt_with_events(Text::new("Message:").with_multiline(true), events);
+
+    assert_eq!("foo bar", ans);
+}
+
+#[test]
+fn ctrl_d_is_ignored_without_multiline() {
+    let mut events = events_of("abc");
+    events.push(ctrl_d());
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Message:"), events);
+
+    assert_eq!("abc", ans);
+}
+
+#[test]
+fn multiline_input_is_rendered_across_lines() {
+    let mut read = events_of("one\ntwo");
+    read.push(ctrl_d());
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Message:")
+            .with_multiline(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("one\ntwo", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("one\r\ntwo"));
+    assert!(output.contains("Message: one…"));
+}
+
+#[test]
+fn max_length_drops_extra_keystrokes() {
+    let ans = prompt_with_events(
+        Text::new("Tweet:").with_max_length(5),
+        events_of("hi 🧘🏻‍♂️🌍 there\n"),
+    );
+
+    assert_eq!("hi 🧘🏻‍♂️🌍", ans);
+}
+
+#[test]
+fn recalled_and_completed_answers_are_truncated_to_max_length() {
+    let mut history = History::from(vec!["production"]);
+
+    let mut events = vec![KeyEvent::from(KeyCode::Up)];
+    events.push(KeyEvent::from(KeyCode::Enter));
+    let ans = prompt_with_events(
+        Text::new("Host:")
+            .with_history(&mut history)
+            .with_max_length(4),
+        events,
+    );
+    assert_eq!("prod", ans);
+
+    let mut events = events_of("mi");
+    events.push(KeyEvent::from(KeyCode::Down));
+    events.push(KeyEvent::from(KeyCode::Tab));
+    events.push(KeyEvent::from(KeyCode::Enter));
+    let ans = prompt_with_events(
+        Text::new("User:")
+            .with_autocomplete(UsernameCompleter::default())
+            .with_max_length(3),
+        events,
+    );
+    assert_eq!("mik", ans);
+}
+
+#[test]
+fn length_counter_is_rendered() {
+    let mut read = events_of("ab");
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Tweet:")
+            .with_max_length(80)
+            .with_length_counter(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("ab", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("0/80"));
+    assert!(output.contains("1/80"));
+    assert!(output.contains("ab 2/80"));
+}
+
+fn type_keys(prompt: &Text<'_>, text: &str) -> String {
+    let config = prompt.into();
+    let mut input = Input::new();
+
+    for c in text.chars() {
+        let key = Key::Char(c, crate::ui::KeyModifiers::NONE);
+        if let Some(TextPromptAction::ValueInput(action)) = TextPromptAction::from_key(key, &config)
+        {
+            input.handle(action);
+        }
+    }
+
+    input.content().to_owned()
+}
+
+#[test]
+fn pasted_new_lines_and_tabs_become_spaces() {
+    let content = type_keys(&Text::new("Question?"), "first\nsecond\rthird\tfourth");
+
+    assert_eq!("first second third fourth", content);
+}
+
+#[test]
+fn pasted_new_lines_can_be_stripped() {
+    let prompt = Text::new("Question?").with_strip_pasted_newlines(true);
+
+    let content = type_keys(&prompt, "first\nsecond\tthird");
+
+    assert_eq!("firstsecond third", content);
+}
+
+#[test]
+fn pasted_new_lines_are_kept_in_multiline_mode() {
+    let prompt = Text::new("Question?").with_multiline(true);
+
+    let content = type_keys(&prompt, "first\nsecond\rthird");
+
+    assert_eq!("first\nsecond\nthird", content);
+}
+
+#[test]
+fn char_filter_rejects_characters_at_entry() {
+    let ans = prompt_with_events(
+        Text::new("OTP:").with_char_filter(&|c| c.is_ascii_digit()),
+        events_of("12a3 4🌍5\n"),
+    );
+
+    assert_eq!("12345", ans);
+}
+
+#[test]
+fn char_filter_rejects_whitespace() {
+    let filter = |c: char| !c.is_whitespace();
+
+    let ans = prompt_with_events(
+        Text::new("Slug:").with_char_filter(&filter),
+        events_of("my slug here\n"),
+    );
+
+    assert_eq!("myslughere", ans);
+}
+
+#[test]
+fn input_transformer_lowercases_typed_characters() {
+    let ans = prompt_with_events(
+        Text::new("Username:").with_input_transformer(&|c| c.to_lowercase().next()),
+        events_of("HeLLo\n"),
+    );
+
+    assert_eq!("hello", ans);
+}
+
+#[test]
+fn input_transformer_can_drop_characters() {
+    let slug = |c: char| match c {
+        ' ' => Some('-'),
+        c if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+        _ => None,
+    };
+
+    let ans = prompt_with_events(
+        Text::new("Slug:").with_input_transformer(&slug),
+        events_of("My Café Post!\n"),
+    );
+
+    assert_eq!("my-caf-post", ans);
+}
+
+#[test]
+fn backspace_operates_on_transformed_content() {
+    let mut events = events_of("ABC");
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(KeyEvent::from(KeyCode::Left));
+    events.append(&mut events_of("X\n"));
+
+    let ans = prompt_with_events(
+        Text::new("Code:").with_input_transformer(&|c| c.to_lowercase().next()),
+        events,
+    );
+
+    assert_eq!("axb", ans);
+}
+
+#[test]
+fn char_filter_sees_transformed_characters() {
+    let ans = prompt_with_events(
+        Text::new("Code:")
+            .with_input_transformer(&|c| c.to_lowercase().next())
+            .with_char_filter(&|c| c.is_ascii_lowercase()),
+        events_of("AbC1\n"),
+    );
+
+    assert_eq!("abc", ans);
+}
+
+fn prompt_skippable_with_events(
+    prompt: Text<'_>,
+    events: Vec<KeyEvent>,
+) -> crate::error::InquireResult<Option<String>> {
+    let mut read = events.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    prompt.prompt_skippable_with_backend(&mut backend)
+}
+
+#[test]
+fn skippable_prompt_returns_some_on_submit() {
+    let ans = prompt_skippable_with_events(Text::new("Nickname:"), events_of("mike\n"));
+
+    assert_eq!(Some(String::from("mike")), ans.unwrap());
+}
+
+#[test]
+fn skippable_prompt_returns_none_on_escape() {
+    let mut events = events_of("mike");
+    events.push(KeyEvent::from(KeyCode::Esc));
+
+    let ans = prompt_skippable_with_events(Text::new("Nickname:"), events);
+
+    assert_eq!(None, ans.unwrap());
+}
+
+#[test]
+fn skippable_prompt_is_still_interrupted_by_ctrl_c() {
+    let mut events = events_of("mike");
+    events.push(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    let ans = prompt_skippable_with_events(Text::new("Nickname:"), events);
+
+    assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+}
+
+#[test]
+fn skippable_prompt_submits_default_on_empty_input() {
+    let ans = prompt_skippable_with_events(
+        Text::new("Nickname:").with_default("anon"),
+        vec![KeyEvent::from(KeyCode::Enter)],
+    );
+
+    assert_eq!(Some(String::from("anon")), ans.unwrap());
+}
+
+fn fruit_prompt<'a>() -> Text<'a> {
+    Text::new("Fruit:")
+        .with_suggestions(&["apple", "apricot", "banana"])
+        .with_inline_suggestion(true)
+}
+
+#[test]
+fn right_arrow_accepts_inline_suggestion() {
+    let mut events = events_of("ap");
+    events.push(KeyEvent::from(KeyCode::Right));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    assert_eq!("apple", prompt_with_events(fruit_prompt(), events));
+}
+
+#[test]
+fn end_accepts_inline_suggestion() {
+    let mut events = events_of("b");
+    events.push(KeyEvent::from(KeyCode::End));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    assert_eq!("banana", prompt_with_events(fruit_prompt(), events));
+}
+
+#[test]
+fn diverging_input_recomputes_inline_suggestion() {
+    let mut events = events_of("apr");
+    events.push(KeyEvent::from(KeyCode::Right));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    assert_eq!("apricot", prompt_with_events(fruit_prompt(), events));
+}
+
+#[test]
+fn right_arrow_only_moves_cursor_without_inline_suggestion() {
+    let mut events = events_of("ap");
+    events.push(KeyEvent::from(KeyCode::Right));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let prompt = fruit_prompt().with_inline_suggestion(false);
+
+    assert_eq!("ap", prompt_with_events(prompt, events));
+}
+
+#[test]
+fn accepted_inline_suggestion_is_validated() {
+    let mut events = events_of("ap");
+    events.push(KeyEvent::from(KeyCode::Right));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let prompt = fruit_prompt().with_validator(|ans: &str| match ans.len() {
+        5 => Ok(Validation::Valid),
+        _ => Ok(Validation::Invalid(ErrorMessage::Default)),
+    });
+
+    assert_eq!("apple", prompt_with_events(prompt, events));
+}
+
+#[test]
+fn inline_suggestion_is_rendered_after_input() {
+    let mut read = events_of("ap");
+    read.push(KeyEvent::from(KeyCode::Enter));
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = fruit_prompt().prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!("ap", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("Fruit: apple"));
+    assert!(output.contains("Fruit: ap\r\n"));
+}
+
+fn phone_prompt<'a>() -> Text<'a> {
+    Text::new("Phone:").with_mask("(###) ###-####")
+}
+
+#[test]
+fn mask_inserts_literals_automatically() {
+    let ans = prompt_with_events(phone_prompt(), events_of("5551234567\n"));
+
+    assert_eq!("(555) 123-4567", ans);
+}
+
+#[test]
+fn mask_accepts_typed_literals_and_rejects_mismatches() {
+    let ans = prompt_with_events(phone_prompt(), events_of("(55a5) 12-3x4567\n"));
+
+    assert_eq!("(555) 123-4567", ans);
+}
+
+#[test]
+fn mask_letter_slots_accept_only_letters() {
+    let ans = prompt_with_events(
+        Text::new("Postcode:").with_mask("A#A #A#"),
+        events_of("K1AA0B1\n"),
+    );
+
+    assert_eq!("K1A 0B1", ans);
+}
+
+#[test]
+fn mask_backspace_skips_literals() {
+    let mut events = events_of("5551");
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.append(&mut events_of("9551234567\n"));
+
+    let ans = prompt_with_events(phone_prompt(), events);
+
+    assert_eq!("(559) 551-2345", ans);
+}
+
+#[test]
+fn mask_trailing_literals_complete_the_value() {
+    let prompt = || Text::new("Area code:").with_mask("(###)");
+
+    let ans = prompt_with_events(prompt(), events_of("555\n"));
+    assert_eq!("(555)", ans);
+
+    let mut events = events_of("555");
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.append(&mut events_of("9\n"));
+    let ans = prompt_with_events(prompt(), events);
+    assert_eq!("(559)", ans);
+}
+
+#[test]
+fn incomplete_mask_blocks_submit() {
+    let read: Vec<KeyEvent> = events_of("555\n1234567\n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = phone_prompt().prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!("(555) 123-4567", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("Incomplete input, expected format (###) ###-####"));
+}
+
+#[test]
+fn partial_mask_can_be_submitted() {
+    let ans = prompt_with_events(phone_prompt().with_partial_mask(true), events_of("555\n"));
+
+    assert_eq!("(555", ans);
+}
+
+#[test]
+fn mask_is_displayed_as_placeholder() {
+    let read: Vec<KeyEvent> = events_of("5551234567\n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        phone_prompt().prompt_with_backend(&mut backend).unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("Phone: (###) ###-####"));
+}
+
+fn prompt_with_default_flag_and_output(
+    prompt: Text<'_>,
+    events: Vec<KeyEvent>,
+) -> ((String, bool), String) {
+    let mut read = events.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        prompt
+            .prompt_with_default_flag_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    (ans, String::from_utf8(write).unwrap())
+}
+
+#[test]
+fn default_is_hinted_and_submitted_on_empty_input() {
+    let (ans, output) = prompt_with_default_flag_and_output(
+        Text::new("Database host:").with_default("localhost"),
+        events_of("\n"),
+    );
+
+    assert_eq!(("localhost".to_owned(), true), ans);
+    assert!(output.contains("Database host: (localhost)"));
+    assert!(output.contains("Database host: localhost\r\n"));
+}
+
+#[test]
+fn typed_input_hides_default_hint_and_wins() {
+    let (ans, output) = prompt_with_default_flag_and_output(
+        Text::new("Database host:").with_default("localhost"),
+        events_of("db\n"),
+    );
+
+    assert_eq!(("db".to_owned(), false), ans);
+    assert_eq!(1, output.matches("(localhost)").count());
+    assert!(output.contains("Database host: db\r\n"));
+}
+
+#[test]
+fn typing_the_default_value_is_not_flagged_as_default() {
+    let (ans, _) = prompt_with_default_flag_and_output(
+        Text::new("Database host:").with_default("localhost"),
+        events_of("localhost\n"),
+    );
+
+    assert_eq!(("localhost".to_owned(), false), ans);
+}
+
+#[test]
+fn validators_run_against_the_default_value() {
+    let mut events = events_of("\n");
+    events.append(&mut events_of("db\n"));
+
+    let (ans, output) = prompt_with_default_flag_and_output(
+        Text::new("Database host:")
+            .with_default("localhost")
+            .with_validator(|ans: &str| match ans {
+                "localhost" => Ok(Validation::Invalid("remote hosts only".into())),
+                _ => Ok(Validation::Valid),
+            }),
+        events,
+    );
+
+    assert_eq!(("db".to_owned(), false), ans);
+    assert!(output.contains("remote hosts only"));
+}
+
+#[test]
+fn trimmed_answer_is_validated_and_returned() {
+    let mut events = events_of("  hello  \n");
+    events.push(ctrl_d());
+
+    let ans = prompt_with_events(
+        Text::new("Word:")
+            .with_multiline(true)
+            .with_trim(true)
+            .with_validator(|ans: &str| match ans.len() {
+                5 => Ok(Validation::Valid),
+                _ => Ok(Validation::Invalid(ErrorMessage::Default)),
+            }),
+        events,
+    );
+
+    assert_eq!("hello", ans);
+}
+
+#[test]
+fn raw_input_is_returned_without_trim() {
+    let ans = prompt_with_events(Text::new("Word:"), events_of("  hello  \n"));
+
+    assert_eq!("  hello  ", ans);
+}
+
+#[test]
+fn whitespace_only_input_submits_default_with_trim() {
+    let ans = prompt_with_events(
+        Text::new("Word:").with_default("world").with_trim(true),
+        events_of("   \n"),
+    );
+
+    assert_eq!("world", ans);
+}
+
+#[test]
+fn trimmed_answer_is_rendered_after_submit() {
+    let read: Vec<KeyEvent> = events_of("  hello  \n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Text::new("Word:")
+            .with_trim(true)
+            .with_formatter(&|ans| format!("[{ans}]"))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("Word:   hello  "));
+    assert!(output.contains("Word: [hello]\r\n"));
+}
+
+#[test]
+fn ctrl_a_and_ctrl_e_move_to_input_edges() {
+    let mut events = events_of("world");
+    events.push(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    events.append(&mut events_of("hello "));
+    events.push(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL));
+    events.append(&mut events_of("!\n"));
+
+    let ans = prompt_with_events(Text::new("Greeting:"), events);
+
+    assert_eq!("hello world!", ans);
+}
+
+#[test]
+fn unhandled_control_combinations_are_not_written() {
+    let mut events = events_of("ab");
+    events.push(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL));
+    events.push(KeyEvent::new(KeyCode::Char('\u{1}'), KeyModifiers::NONE));
+    events.push(KeyEvent::new(
+        KeyCode::Char('@'),
+        KeyModifiers::CONTROL | KeyModifiers::ALT,
+    ));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("ab@", ans);
+}
+
+fn ctrl(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+fn alt(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT)
+}
+
+#[test]
+fn undo_restores_cleared_input() {
+    let mut events = events_of("hello world");
+    events.push(ctrl('u'));
+    events.push(ctrl('z'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("hello world", ans);
+}
+
+#[test]
+fn undo_reverts_typing_word_by_word() {
+    let mut events = events_of("hello big world");
+    events.push(ctrl('z'));
+    events.push(ctrl('z'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("hello big", ans);
+}
+
+#[test]
+fn redo_reapplies_undone_edit() {
+    let mut events = events_of("hello");
+    events.push(ctrl('w'));
+    events.push(ctrl('z'));
+    events.push(alt('z'));
+    events.append(&mut events_of("bye\n"));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("bye", ans);
+}
+
+#[test]
+fn ctrl_y_yanks_right_after_an_undo() {
+    let mut events = events_of("hello");
+    events.push(ctrl('w'));
+    events.append(&mut events_of("bye"));
+    events.push(ctrl('z'));
+    events.push(ctrl('y'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("hello", ans);
+}
+
+#[test]
+fn new_edit_discards_redo_history() {
+    let mut events = events_of("one two");
+    events.push(ctrl('z'));
+    events.append(&mut events_of("x"));
+    events.push(alt('z'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("one x", ans);
+}
+
+#[test]
+fn undo_survives_validation_errors() {
+    let mut events = events_of("hello");
+    events.push(ctrl('u'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+    events.push(ctrl('z'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(
+        Text::new("Text:").with_validator(|ans: &str| match ans.is_empty() {
+            true => Ok(Validation::Invalid(ErrorMessage::Default)),
+            false => Ok(Validation::Valid),
+        }),
+        events,
+    );
+
+    assert_eq!("hello", ans);
+}
+
+#[test]
+fn undo_stack_is_capped() {
+    let mut events = vec![];
+    for _ in 0..60 {
+        events.append(&mut events_of("a "));
+    }
+    events.append(&mut vec![ctrl('z'); 200]);
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert!(!ans.is_empty());
+}
+
+#[test]
+fn ctrl_w_kill_is_yanked_at_cursor() {
+    let mut events = events_of("hello world");
+    events.push(ctrl('w'));
+    events.push(ctrl('a'));
+    events.push(ctrl('y'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("worldhello ", ans);
+}
+
+#[test]
+fn ctrl_k_kills_to_end_of_line() {
+    let mut events = events_of("hello world");
+    events.append(&mut vec![KeyEvent::from(KeyCode::Left); 5]);
+    events.push(ctrl('k'));
+    events.push(ctrl('a'));
+    events.push(ctrl('y'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("worldhello ", ans);
+}
+
+#[test]
+fn ctrl_u_kill_can_be_yanked_repeatedly() {
+    let mut events = events_of("ab");
+    events.push(ctrl('u'));
+    events.push(ctrl('y'));
+    events.push(ctrl('y'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("abab", ans);
+}
+
+#[test]
+fn yank_keeps_grapheme_clusters_whole() {
+    let mut events = events_of("ab🧘🏻‍♂️");
+    events.push(KeyEvent::from(KeyCode::Left));
+    events.push(ctrl('k'));
+    events.push(ctrl('a'));
+    events.push(ctrl('y'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("🧘🏻‍♂️ab", ans);
+}
+
+#[test]
+fn yank_without_kill_does_nothing() {
+    let mut events = events_of("ab");
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.push(ctrl('y'));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(Text::new("Text:"), events);
+
+    assert_eq!("a", ans);
+}
+
+#[test]
+fn numeric_mode_only_accepts_digits() {
+    let ans = prompt_with_events(
+        Text::new("Port:").with_numeric(true),
+        events_of("80a-8.0\n"),
+    );
+
+    assert_eq!("8080", ans);
+}
+
+#[test]
+fn numeric_mode_accepts_negative_and_decimal_numbers_when_allowed() {
+    let mut events = events_of("3.1.4");
+    events.push(KeyEvent::from(KeyCode::Home));
+    events.append(&mut events_of("-"));
+    events.push(KeyEvent::from(KeyCode::Home));
+    events.append(&mut events_of("5-"));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(
+        Text::new("Offset:")
+            .with_numeric(true)
+            .with_allow_negative(true)
+            .with_allow_decimal(true),
+        events,
+    );
+
+    assert_eq!("-3.14", ans);
+}
+
+#[test]
+fn numeric_mode_rejects_answers_that_are_not_numbers() {
+    let mut events = events_of("-\n");
+    events.push(KeyEvent::from(KeyCode::Backspace));
+    events.append(&mut events_of(".\n"));
+    events.append(&mut events_of("5\n"));
+
+    let mut read = events.iter();
+    let mut write: Vec<u8> = Vec::new();
+
+    {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Text::new("Offset:")
+            .with_numeric(true)
+            .with_allow_negative(true)
+            .with_allow_decimal(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(".5", ans);
+    }
+
+    let output = String::from_utf8(write).unwrap();
+
+    assert!(output.contains("Please enter a number"));
+}
+
+#[test]
+fn numeric_mode_submits_default_on_empty_input() {
+    let ans = prompt_with_events(
+        Text::new("Port:").with_numeric(true).with_default("8080"),
+        events_of("\n"),
+    );
+
+    assert_eq!("8080", ans);
+}
+
+fn candidates(filter: SuggestionFilter, input: &str) -> Vec<String> {
+    let suggestions = ["Apple", "apricot", "Pineapple", "GRAPE", "banana"];
+
+    StaticAutoCompletion::new(&suggestions)
+        .with_filter(filter)
+        .get_suggestions(input)
+        .unwrap()
+}
+
+#[test]
+fn prefix_filter_is_case_sensitive() {
+    assert_eq!(vec!["apricot"], candidates(SuggestionFilter::Prefix, "ap"));
+}
+
+#[test]
+fn case_insensitive_prefix_filter_is_the_default() {
+    assert_eq!(
+        SuggestionFilter::PrefixCaseInsensitive,
+        SuggestionFilter::default()
+    );
+    assert_eq!(
+        vec!["Apple", "apricot"],
+        candidates(SuggestionFilter::PrefixCaseInsensitive, "aP")
+    );
+}
+
+#[test]
+fn contains_filter_is_case_sensitive() {
+    assert_eq!(
+        vec!["apricot", "Pineapple"],
+        candidates(SuggestionFilter::Contains, "ap")
+    );
+}
+
+#[test]
+fn case_insensitive_contains_filter_matches_anywhere() {
+    assert_eq!(
+        vec!["Apple", "apricot", "Pineapple", "GRAPE"],
+        candidates(SuggestionFilter::ContainsCaseInsensitive, "AP")
+    );
+}
+
+#[test]
+fn matched_range_covers_the_input() {
+    let filter = SuggestionFilter::ContainsCaseInsensitive;
+
+    assert_eq!(Some(2..4), filter.matched_range("GRAPE", "ap"));
+    assert_eq!(Some(5..7), filter.matched_range("🍞 Café", "ca"));
+    assert_eq!(Some(5..10), filter.matched_range("🍞 Café", "CAFÉ"));
+    assert_eq!(None, filter.matched_range("GRAPE", "pa"));
+}
+
+#[test]
+fn suggestion_filter_applies_to_text_suggestions() {
+    let mut events = events_of("ea");
+    events.push(KeyEvent::from(KeyCode::Down));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(
+        Text::new("Fruit:")
+            .with_suggestion_filter(SuggestionFilter::Contains)
+            .with_suggestions(&["Apple", "Pineapple", "Pear"]),
+        events,
+    );
+
+    assert_eq!("Pineapple", ans);
+}
+
+#[test]
+fn suggestion_filter_set_after_suggestions_is_applied() {
+    let mut events = events_of("ea");
+    events.push(KeyEvent::from(KeyCode::Down));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let ans = prompt_with_events(
+        Text::new("Fruit:")
+            .with_suggestions(&["Apple", "Pineapple", "Pear"])
+            .with_suggestion_filter(SuggestionFilter::Contains),
+        events,
+    );
+
+    assert_eq!("Pineapple", ans);
+}
+
+#[test]
+fn inline_suggestion_ignores_case_by_default() {
+    let mut events = events_of("pi");
+    events.push(KeyEvent::from(KeyCode::Right));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let prompt = Text::new("Fruit:")
+        .with_suggestions(&["Apple", "Pineapple", "Pear"])
+        .with_inline_suggestion(true);
+
+    assert_eq!("Pineapple", prompt_with_events(prompt, events));
+}
+
+#[test]
+fn inline_suggestion_follows_case_sensitive_filter() {
+    let mut events = events_of("pi");
+    events.push(KeyEvent::from(KeyCode::Right));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    let prompt = Text::new("Fruit:")
+        .with_suggestions(&["Pineapple", "pie"])
+        .with_suggestion_filter(SuggestionFilter::Prefix)
+        .with_inline_suggestion(true);
+
+    assert_eq!("pie", prompt_with_events(prompt, events));
+}
+
+fn greek_prompt<'a>() -> Text<'a> {
+    Text::new("Letter:").with_suggestions(&["alpha", "beta", "gamma"])
+}
+
+#[test]
+fn shift_tab_cycles_suggestions_backwards() {
+    let events = vec![
+        KeyEvent::from(KeyCode::Tab),
+        KeyEvent::from(KeyCode::Tab),
+        KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+
+    assert_eq!("alpha", prompt_with_events(greek_prompt(), events));
+}
+
+#[test]
+fn shift_tab_wraps_around_to_last_suggestion() {
+    let events = vec![
+        KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+
+    assert_eq!("gamma", prompt_with_events(greek_prompt(), events));
+}
+
+#[test]
+fn shift_tab_without_suggestions_is_noop() {
+    let mut events = events_of("delta");
+    events.push(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+    events.push(KeyEvent::from(KeyCode::Enter));
+
+    assert_eq!("delta", prompt_with_events(greek_prompt(), events));
+}
+
+#[test]
+fn stdin_fallback_reads_single_line() {
+    let terminal = crate::terminal::line::LineTerminal::new("  Ferris \n");
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Text::new("Name:")
+        .with_stdin_fallback(true)
+        .with_trim(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("Ferris", ans);
+}
+
+#[test]
+fn stdin_fallback_uses_default_on_empty_line() {
+    let terminal = crate::terminal::line::LineTerminal::new("\n");
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Text::new("Name:")
+        .with_stdin_fallback(true)
+        .with_default("Ferris")
+        .prompt_with_default_flag_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(("Ferris".to_owned(), true), ans);
+}
+
+#[test]
+fn help_message_on_empty_only_disappears_when_typing() {
+    let read: Vec<KeyEvent> = events_of("ab\n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Text::new("Name:")
+            .with_help_message("type your name")
+            .with_help_message_on_empty_only(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!("ab", ans);
+    assert_eq!(
+        1,
+        String::from_utf8(write)
+            .unwrap()
+            .matches("type your name")
+            .count()
+    );
+}
+
+#[test]
+fn owned_validators_built_at_runtime() {
+    use crate::validator::StringValidator;
+
+    let min_lengths = vec![2, 4];
+    let validators: Vec<Box<dyn StringValidator>> = min_lengths
+        .into_iter()
+        .map(|min| {
+            let validator: Box<dyn StringValidator> =
+                Box::new(move |input: &str| match input.chars().count() >= min {
+                    true => Ok(Validation::Valid),
+                    false => Ok(Validation::Invalid(format!("At least {}", min).into())),
+                });
+            validator
+        })
+        .collect();
+
+    let read: Vec<KeyEvent> = events_of("abc\nd\n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let ans = {
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Text::new("Name:")
+            .with_owned_validators(validators)
+            .prompt_with_backend(&mut backend)
+            .unwrap()
+    };
+
+    assert_eq!("abcd", ans);
+    assert!(String::from_utf8(write).unwrap().contains("At least 4"));
+}
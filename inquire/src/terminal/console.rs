@@ -75,6 +75,10 @@ impl Terminal for ConsoleTerminal {
         self.term.clear_line()
     }
 
+    fn clear_until_screen_end(&mut self) -> Result<()> {
+        self.term.clear_to_end_of_screen()
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         self.term.hide_cursor()
     }
@@ -150,6 +154,7 @@ impl From<Key> for crate::ui::Key {
             Key::Escape => Self::Escape,
             Key::Char('\n' | '\r') | Key::Enter => Self::Enter,
             Key::Char('\t') | Key::Tab => Self::Tab,
+            Key::BackTab => Self::BackTab,
             Key::Backspace => Self::Backspace,
             Key::Del => Self::Delete(KeyModifiers::empty()),
             Key::Home => Self::Home,
@@ -160,6 +165,10 @@ impl From<Key> for crate::ui::Key {
             Key::ArrowDown => Self::Down(KeyModifiers::empty()),
             Key::ArrowLeft => Self::Left(KeyModifiers::empty()),
             Key::ArrowRight => Self::Right(KeyModifiers::empty()),
+            // ctrl+letter combinations are read as ASCII control characters
+            Key::Char(c @ '\u{1}'..='\u{1a}') => {
+                Self::Char((c as u8 - 1 + b'a') as char, KeyModifiers::CONTROL)
+            }
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
             #[allow(deprecated)]
             _ => Self::Any,
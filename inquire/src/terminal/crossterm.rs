@@ -1,8 +1,14 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    collections::VecDeque,
+    io::{stderr, Result, Stderr, Write},
+};
 
 use crossterm::{
     cursor,
-    event::{self, KeyCode, KeyEvent, KeyModifiers},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
     queue,
     style::{Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, enable_raw_mode, ClearType},
@@ -22,7 +28,7 @@ enum IO<'a> {
     },
     #[allow(unused)]
     Custom {
-        r: &'a mut dyn Iterator<Item = &'a KeyEvent>,
+        r: Box<dyn Iterator<Item = Event> + 'a>,
         w: &'a mut (dyn Write),
     },
 }
@@ -30,6 +36,11 @@ enum IO<'a> {
 pub struct CrosstermTerminal<'a> {
     io: IO<'a>,
     in_memory_content: String,
+    pasted_keys: VecDeque<Key>,
+    mouse_capture: bool,
+    /// Size reported by the last resize event of a custom stream of events,
+    /// which takes precedence over the size of the actual terminal.
+    custom_size: Option<(u16, u16)>,
 }
 
 impl<'a> CrosstermTerminal<'a> {
@@ -39,10 +50,19 @@ impl<'a> CrosstermTerminal<'a> {
             _ => InquireError::from(e),
         })?;
 
-        Ok(Self {
+        let mut terminal = Self {
             io: IO::Std { w: stderr() },
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
-        })
+            pasted_keys: VecDeque::new(),
+            mouse_capture: false,
+            custom_size: None,
+        };
+
+        // not all terminals support bracketed paste, in which case pasted
+        // text is simply received as regular key presses.
+        let _unused = terminal.write_command(EnableBracketedPaste);
+
+        Ok(terminal)
     }
 
     /// # Errors
@@ -52,6 +72,16 @@ impl<'a> CrosstermTerminal<'a> {
     pub fn new_with_io<W: 'a + Write>(
         writer: &'a mut W,
         reader: &'a mut dyn Iterator<Item = &'a KeyEvent>,
+    ) -> Self {
+        Self::new_with_events(writer, Box::new(reader.map(|key| Event::Key(*key))))
+    }
+
+    /// Same as `new_with_io`, reading events of any kind, such as mouse events,
+    /// instead of key presses only.
+    #[allow(unused)]
+    pub fn new_with_events<W: 'a + Write>(
+        writer: &'a mut W,
+        reader: Box<dyn Iterator<Item = Event> + 'a>,
     ) -> Self {
         Self {
             io: IO::Custom {
@@ -59,6 +89,9 @@ impl<'a> CrosstermTerminal<'a> {
                 w: writer,
             },
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+            pasted_keys: VecDeque::new(),
+            mouse_capture: false,
+            custom_size: None,
         }
     }
 
@@ -120,26 +153,79 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
 
     fn read_key(&mut self) -> Result<Key> {
         loop {
-            match &mut self.io {
-                IO::Std { w: _ } => {
-                    if let event::Event::Key(key_event) = event::read()? {
-                        return Ok(key_event.into());
+            if let Some(key) = self.pasted_keys.pop_front() {
+                return Ok(key);
+            }
+
+            let event = match &mut self.io {
+                IO::Std { w: _ } => event::read()?,
+                IO::Custom { r, w: _ } => r.next().expect("Custom stream of characters has ended"),
+            };
+
+            match event {
+                Event::Key(key_event) => return Ok(key_event.into()),
+                Event::Mouse(mouse_event) => {
+                    if let Some(key) = mouse_key(mouse_event) {
+                        return Ok(key);
                     }
                 }
-                IO::Custom { r, w: _ } => {
-                    let key = r.next().expect("Custom stream of characters has ended");
-                    return Ok((*key).into());
+                Event::Paste(text) => self.pasted_keys.extend(pasted_keys(&text)),
+                Event::Resize(width, height) => {
+                    if let IO::Custom { r: _, w: _ } = self.io {
+                        self.custom_size = Some((width, height));
+                    }
+                    return Ok(Key::Resize);
                 }
+                _ => {}
             }
         }
     }
 
+    #[cfg(feature = "async")]
+    fn poll_key(&mut self, timeout: std::time::Duration) -> Result<Option<Key>> {
+        if let Some(key) = self.pasted_keys.pop_front() {
+            return Ok(Some(key));
+        }
+
+        let event = match &mut self.io {
+            IO::Std { w: _ } => match event::poll(timeout)? {
+                true => event::read()?,
+                false => return Ok(None),
+            },
+            IO::Custom { r, w: _ } => match r.next() {
+                Some(event) => event,
+                None => return Ok(None),
+            },
+        };
+
+        match event {
+            Event::Key(key_event) => Ok(Some(key_event.into())),
+            Event::Mouse(mouse_event) => Ok(mouse_key(mouse_event)),
+            Event::Paste(text) => {
+                self.pasted_keys.extend(pasted_keys(&text));
+                Ok(self.pasted_keys.pop_front())
+            }
+            Event::Resize(width, height) => {
+                if let IO::Custom { r: _, w: _ } = self.io {
+                    self.custom_size = Some((width, height));
+                }
+                Ok(Some(Key::Resize))
+            }
+            _ => Ok(None),
+        }
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.get_writer().flush()
     }
 
     fn get_size(&self) -> Result<super::TerminalSize> {
-        terminal::size().map(|(width, height)| super::TerminalSize { width, height })
+        let size = match self.custom_size {
+            Some(size) => Ok(size),
+            None => terminal::size(),
+        };
+
+        size.map(|(width, height)| super::TerminalSize { width, height })
     }
 
     fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
@@ -180,6 +266,10 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         self.write_command(terminal::Clear(ClearType::CurrentLine))
     }
 
+    fn clear_until_screen_end(&mut self) -> Result<()> {
+        self.write_command(terminal::Clear(ClearType::FromCursorDown))
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         self.write_command(cursor::Hide)
     }
@@ -188,6 +278,19 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         self.write_command(cursor::Show)
     }
 
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        self.write_command(EnableMouseCapture)?;
+        self.mouse_capture = true;
+        self.flush()
+    }
+
+    fn cursor_position(&mut self) -> Result<Option<(u16, u16)>> {
+        match self.io {
+            IO::Std { w: _ } => cursor::position().map(Some),
+            IO::Custom { r: _, w: _ } => Ok(None),
+        }
+    }
+
     fn get_in_memory_content(&self) -> &str {
         self.in_memory_content.as_ref()
     }
@@ -199,6 +302,16 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
 
 impl<'a> Drop for CrosstermTerminal<'a> {
     fn drop(&mut self) {
+        if let IO::Std { w: _ } = self.io {
+            let _unused = self.write_command(DisableBracketedPaste);
+        }
+
+        // also reached when unwinding from a panic, which would otherwise leave
+        // the terminal reporting mouse events to the shell.
+        if self.mouse_capture {
+            let _unused = self.write_command(DisableMouseCapture);
+        }
+
         let _unused = self.flush();
         let _unused = match self.io {
             IO::Std { w: _ } => terminal::disable_raw_mode(),
@@ -207,6 +320,17 @@ impl<'a> Drop for CrosstermTerminal<'a> {
     }
 }
 
+/// Converts text received from a bracketed paste into key presses.
+///
+/// New lines and tabs are sent as characters rather than as `Enter` and `Tab`
+/// keys, so that pasting multi-line text does not submit the prompt halfway.
+fn pasted_keys(text: &str) -> impl Iterator<Item = Key> + '_ {
+    text.split("\r\n")
+        .enumerate()
+        .flat_map(|(idx, line)| (idx > 0).then_some('\n').into_iter().chain(line.chars()))
+        .map(|c| Key::Char(c, crate::ui::KeyModifiers::NONE))
+}
+
 impl From<crate::ui::Color> for Color {
     fn from(c: crate::ui::Color) -> Self {
         use crate::ui::Color as C;
@@ -254,6 +378,19 @@ impl From<KeyModifiers> for crate::ui::KeyModifiers {
     }
 }
 
+/// Converts the mouse events handled by the prompts into keys.
+fn mouse_key(event: MouseEvent) -> Option<Key> {
+    match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => Some(Key::Click {
+            column: event.column,
+            row: event.row,
+        }),
+        MouseEventKind::ScrollUp => Some(Key::ScrollUp),
+        MouseEventKind::ScrollDown => Some(Key::ScrollDown),
+        _ => None,
+    }
+}
+
 impl From<KeyEvent> for Key {
     fn from(event: KeyEvent) -> Self {
         match event {
@@ -261,9 +398,18 @@ impl From<KeyEvent> for Key {
                 code: KeyCode::Esc, ..
             } => Self::Escape,
             KeyEvent {
-                code: KeyCode::Enter | KeyCode::Char('\n' | '\r'),
+                code: KeyCode::Enter,
                 ..
             } => Self::Enter,
+            KeyEvent {
+                code: KeyCode::BackTab,
+                ..
+            }
+            | KeyEvent {
+                code: KeyCode::Tab,
+                modifiers: KeyModifiers::SHIFT,
+                ..
+            } => Self::BackTab,
             KeyEvent {
                 code: KeyCode::Tab | KeyCode::Char('\t'),
                 ..
@@ -326,11 +472,44 @@ impl From<KeyEvent> for Key {
 #[cfg(test)]
 mod test {
     use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use crate::ui::{Color, Key, KeyModifiers};
 
     use super::Attributes;
     use super::CrosstermTerminal;
 
+    #[test]
+    fn pasted_text_keeps_new_lines_as_chars() {
+        let keys: Vec<Key> = super::pasted_keys("a\r\nb\nc\td").collect();
+
+        let expected: Vec<Key> = "a\nb\nc\td"
+            .chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE))
+            .collect();
+
+        assert_eq!(expected, keys);
+    }
+
+    #[test]
+    fn mouse_capture_is_disabled_when_dropped() {
+        let mut write: Vec<u8> = Vec::new();
+        let read = Vec::new();
+        let mut read = read.iter();
+
+        {
+            let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+
+            terminal.enable_mouse_capture().unwrap();
+        }
+
+        #[cfg(unix)]
+        {
+            let output = std::str::from_utf8(&write).unwrap();
+            let enabled = output.find("\x1B[?1000h").unwrap();
+            let disabled = output.find("\x1B[?1000l").unwrap();
+            assert!(enabled < disabled);
+        }
+    }
+
     #[test]
     fn writer() {
         let mut write: Vec<u8> = Vec::new();
@@ -0,0 +1,120 @@
+use std::{
+    collections::VecDeque,
+    io::{Error, ErrorKind, Result},
+};
+
+use crate::ui::{Key, KeyModifiers, Styled};
+
+use super::{Terminal, TerminalSize, INITIAL_IN_MEMORY_CAPACITY};
+
+/// Terminal used when the standard input is not a terminal, e.g. when piped, or
+/// when the terminal can't be put in raw mode.
+///
+/// It replays lines as if they had been typed by the user, each one followed by
+/// an enter key press, and discards everything rendered by the prompt.
+pub struct LineTerminal {
+    keys: VecDeque<Key>,
+    in_memory_content: String,
+}
+
+impl LineTerminal {
+    /// Reads a single line from the standard input, without its trailing new line.
+    pub fn from_stdin() -> Result<Self> {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+
+        Ok(Self::new(&line))
+    }
+
+    pub fn new(line: &str) -> Self {
+        Self::from_lines(&[line])
+    }
+
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Self {
+        let mut keys = VecDeque::new();
+
+        for line in lines {
+            let line = line.as_ref();
+            let line = line.strip_suffix('\n').unwrap_or(line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            keys.extend(line.chars().map(|c| Key::Char(c, KeyModifiers::NONE)));
+            keys.push_back(Key::Enter);
+        }
+
+        Self {
+            keys,
+            in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+        }
+    }
+}
+
+impl Terminal for LineTerminal {
+    fn cursor_up(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, _idx: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn read_key(&mut self) -> Result<Key> {
+        // once the lines are submitted, there is nothing else to read, e.g.
+        // when the answer is rejected by a validator.
+        self.keys.pop_front().ok_or_else(|| {
+            Error::new(
+                ErrorKind::UnexpectedEof,
+                "the lines read from the standard input were not accepted",
+            )
+        })
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_size(&self) -> Result<TerminalSize> {
+        Ok(TerminalSize {
+            width: 1000,
+            height: 1000,
+        })
+    }
+
+    fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
+        self.in_memory_content.push_str(&val.to_string());
+        Ok(())
+    }
+
+    fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
+        self.in_memory_content.push_str(&val.content.to_string());
+        Ok(())
+    }
+
+    fn clear_current_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn clear_until_screen_end(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_in_memory_content(&self) -> &str {
+        self.in_memory_content.as_ref()
+    }
+
+    fn clear_in_memory_content(&mut self) {
+        self.in_memory_content.clear();
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "async")]
+use std::time::Duration;
 use std::{fmt::Display, io::Result};
 
 use crate::{
@@ -19,6 +21,9 @@ pub mod termion;
 #[cfg_attr(docsrs, doc(cfg(feature = "console")))]
 pub mod console;
 
+pub mod line;
+pub mod no_echo;
+
 pub struct TerminalSize {
     pub width: u16,
     pub height: u16,
@@ -29,6 +34,17 @@ pub trait Terminal: Sized {
     fn cursor_down(&mut self, cnt: u16) -> Result<()>;
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
     fn read_key(&mut self) -> Result<Key>;
+
+    /// Waits up to `timeout` for a key press, returning `None` if there was none.
+    ///
+    /// Terminals that can't wait for a key press with a timeout only wait for the
+    /// timeout to elapse, or for the current thread to be unparked.
+    #[cfg(feature = "async")]
+    fn poll_key(&mut self, timeout: Duration) -> Result<Option<Key>> {
+        std::thread::park_timeout(timeout);
+        Ok(None)
+    }
+
     fn flush(&mut self) -> Result<()>;
 
     fn get_size(&self) -> Result<TerminalSize>;
@@ -40,9 +56,50 @@ pub trait Terminal: Sized {
     fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()>;
 
     fn clear_current_line(&mut self) -> Result<()>;
+    fn clear_until_screen_end(&mut self) -> Result<()>;
 
     fn cursor_hide(&mut self) -> Result<()>;
     fn cursor_show(&mut self) -> Result<()>;
+
+    /// Starts reporting mouse clicks and wheel scrolls as keys, until the
+    /// terminal is dropped.
+    ///
+    /// Terminals without mouse support ignore this.
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Position of the cursor on the screen as `(column, row)`, or `None` when
+    /// it can not be queried.
+    fn cursor_position(&mut self) -> Result<Option<(u16, u16)>> {
+        Ok(None)
+    }
+}
+
+/// Returns whether the standard input is attached to a terminal, as opposed to
+/// e.g. a pipe or a file.
+pub fn stdin_is_tty() -> bool {
+    #[cfg(feature = "crossterm")]
+    return ::crossterm::tty::IsTty::is_tty(&std::io::stdin());
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    return ::termion::is_tty(&std::io::stdin());
+
+    // console does not expose this check for the standard input, so we fall
+    // back to whether the prompt is rendered to a terminal.
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    return ::console::user_attended_stderr();
+
+    #[cfg(all(
+        not(feature = "crossterm"),
+        not(feature = "termion"),
+        not(feature = "console")
+    ))]
+    true
 }
 
 pub fn get_default_terminal() -> InquireResult<impl Terminal> {
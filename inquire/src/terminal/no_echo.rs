@@ -0,0 +1,100 @@
+//! Reading of lines from the standard input with echo disabled at the OS level,
+//! used by prompts for secrets when the terminal can't be put in raw mode.
+
+use std::io::{stdin, Error, ErrorKind, Result};
+
+/// Reads a line from the standard input without echoing it, restoring the
+/// original mode of the terminal afterwards, even if the read fails.
+///
+/// Fails if echo can't be disabled, e.g. when the standard input is not a
+/// terminal, or if the standard input has reached its end.
+pub fn read_line() -> Result<String> {
+    let _guard = EchoGuard::disable()?;
+
+    let mut line = String::new();
+    match stdin().read_line(&mut line)? {
+        0 => Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            "the standard input has reached its end",
+        )),
+        _ => Ok(line),
+    }
+}
+
+/// Restores the original mode of the terminal when dropped.
+#[cfg(unix)]
+struct EchoGuard {
+    original: rustix::termios::Termios,
+}
+
+#[cfg(unix)]
+impl EchoGuard {
+    fn disable() -> Result<Self> {
+        use rustix::termios::{tcgetattr, tcsetattr, LocalModes, OptionalActions};
+
+        let original = tcgetattr(stdin())?;
+
+        let mut no_echo = original.clone();
+        no_echo.local_modes.remove(LocalModes::ECHO);
+        tcsetattr(stdin(), OptionalActions::Now, &no_echo)?;
+
+        Ok(Self { original })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        let _unused = rustix::termios::tcsetattr(
+            stdin(),
+            rustix::termios::OptionalActions::Now,
+            &self.original,
+        );
+    }
+}
+
+/// Restores the original mode of the console when dropped.
+#[cfg(windows)]
+struct EchoGuard {
+    console_mode: crossterm_winapi::ConsoleMode,
+    original: u32,
+}
+
+#[cfg(windows)]
+impl EchoGuard {
+    // https://learn.microsoft.com/en-us/windows/console/setconsolemode
+    const ENABLE_ECHO_INPUT: u32 = 0x0004;
+
+    fn disable() -> Result<Self> {
+        use crossterm_winapi::{ConsoleMode, Handle};
+
+        let console_mode = ConsoleMode::from(Handle::current_in_handle()?);
+        let original = console_mode.mode()?;
+        console_mode.set_mode(original & !Self::ENABLE_ECHO_INPUT)?;
+
+        Ok(Self {
+            console_mode,
+            original,
+        })
+    }
+}
+
+#[cfg(windows)]
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        let _unused = self.console_mode.set_mode(self.original);
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+struct EchoGuard;
+
+#[cfg(not(any(unix, windows)))]
+impl EchoGuard {
+    fn disable() -> Result<Self> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "disabling echo is not supported on this platform",
+        ))
+    }
+}
@@ -4,8 +4,8 @@ use std::io::{stderr, stdin, Result, Stderr, Stdin, Write};
 use termion::{
     color::{self, Color},
     cursor,
-    event::Key,
-    input::{Keys, TermRead},
+    event::{Event, Key},
+    input::{Events, TermRead},
     raw::{IntoRawMode, RawTerminal},
     terminal_size,
 };
@@ -20,7 +20,7 @@ use super::{Terminal, INITIAL_IN_MEMORY_CAPACITY};
 enum IO<'a> {
     #[allow(unused)]
     Std {
-        r: Keys<Stdin>,
+        r: Events<Stdin>,
         w: RawTerminal<Stderr>,
     },
     #[allow(unused)]
@@ -47,7 +47,7 @@ impl<'a> TermionTerminal<'a> {
 
         Ok(Self {
             io: IO::Std {
-                r: stdin().keys(),
+                r: stdin().events(),
                 w: raw_mode?,
             },
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
@@ -126,11 +126,16 @@ impl<'a> Terminal for TermionTerminal<'a> {
     fn read_key(&mut self) -> Result<crate::ui::Key> {
         loop {
             match &mut self.io {
-                IO::Std { r, w: _ } => {
-                    if let Some(key) = r.next() {
-                        return key.map(|k| k.into());
+                IO::Std { r, w: _ } => match r.next() {
+                    Some(Ok(Event::Key(key))) => return Ok(key.into()),
+                    Some(Ok(Event::Unsupported(sequence))) => {
+                        if let Some(key) = decode_modified_arrow(&sequence) {
+                            return Ok(key);
+                        }
                     }
-                }
+                    Some(Ok(Event::Mouse(_))) | None => {}
+                    Some(Err(e)) => return Err(e),
+                },
                 IO::Custom { r, w: _ } => {
                     let key = r.next().expect("Custom stream of characters has ended");
                     return Ok((*key).into());
@@ -139,6 +144,26 @@ impl<'a> Terminal for TermionTerminal<'a> {
         }
     }
 
+    #[cfg(feature = "async")]
+    fn poll_key(&mut self, timeout: std::time::Duration) -> Result<Option<crate::ui::Key>> {
+        use rustix::event::{poll, PollFd, PollFlags};
+        use std::convert::TryFrom;
+
+        match &mut self.io {
+            IO::Std { r: _, w: _ } => {
+                let stdin = stdin();
+                let mut fds = [PollFd::new(&stdin, PollFlags::IN)];
+                let timeout = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+
+                match poll(&mut fds, timeout)? {
+                    0 => Ok(None),
+                    _ => self.read_key().map(Some),
+                }
+            }
+            IO::Custom { r, w: _ } => Ok(r.next().map(|key| (*key).into())),
+        }
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.get_writer().flush()
     }
@@ -185,12 +210,16 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "{}", termion::clear::CurrentLine)
     }
 
+    fn clear_until_screen_end(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", termion::clear::AfterCursor)
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
-        write!(self.get_writer(), "{}", termion::cursor::Hide)
+        write!(self.get_writer(), "{}", cursor::Hide)
     }
 
     fn cursor_show(&mut self) -> Result<()> {
-        write!(self.get_writer(), "{}", termion::cursor::Show)
+        write!(self.get_writer(), "{}", cursor::Show)
     }
 
     fn get_in_memory_content(&self) -> &str {
@@ -252,6 +281,7 @@ impl From<Key> for crate::ui::Key {
             Key::Esc => Self::Escape,
             Key::Char('\n' | '\r') => Self::Enter,
             Key::Char('\t') => Self::Tab,
+            Key::BackTab => Self::BackTab,
             Key::Backspace => Self::Backspace,
             Key::Delete => Self::Delete(KeyModifiers::empty()),
             Key::Home => Self::Home,
@@ -271,6 +301,38 @@ impl From<Key> for crate::ui::Key {
     }
 }
 
+/// Decodes arrow keys pressed along with modifiers, such as `ESC [ 1 ; 5 A` for
+/// Ctrl+Up, which termion reports as unsupported sequences.
+fn decode_modified_arrow(sequence: &[u8]) -> Option<crate::ui::Key> {
+    use crate::ui::{Key as K, KeyModifiers};
+
+    let (parameter, direction) = match sequence {
+        [b'\x1B', b'[', b'1', b';', parameter @ b'2'..=b'8', direction] => (parameter, direction),
+        _ => return None,
+    };
+
+    // the parameter is 1 plus the bits of shift (1), alt (2) and ctrl (4).
+    let bits = parameter - b'1';
+    let mut modifiers = KeyModifiers::empty();
+    if bits & 1 != 0 {
+        modifiers |= KeyModifiers::SHIFT;
+    }
+    if bits & 2 != 0 {
+        modifiers |= KeyModifiers::ALT;
+    }
+    if bits & 4 != 0 {
+        modifiers |= KeyModifiers::CONTROL;
+    }
+
+    match direction {
+        b'A' => Some(K::Up(modifiers)),
+        b'B' => Some(K::Down(modifiers)),
+        b'C' => Some(K::Right(modifiers)),
+        b'D' => Some(K::Left(modifiers)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::terminal::Terminal;
@@ -382,4 +444,30 @@ mod test {
             std::str::from_utf8(&write).unwrap()
         );
     }
+
+    #[test]
+    fn modified_arrows_are_decoded() {
+        use crate::ui::{Key, KeyModifiers};
+
+        use super::decode_modified_arrow;
+
+        assert_eq!(
+            Some(Key::Up(KeyModifiers::SHIFT)),
+            decode_modified_arrow(b"\x1B[1;2A")
+        );
+        assert_eq!(
+            Some(Key::Left(KeyModifiers::ALT)),
+            decode_modified_arrow(b"\x1B[1;3D")
+        );
+        assert_eq!(
+            Some(Key::Right(KeyModifiers::CONTROL)),
+            decode_modified_arrow(b"\x1B[1;5C")
+        );
+        assert_eq!(
+            Some(Key::Down(KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+            decode_modified_arrow(b"\x1B[1;6B")
+        );
+        assert_eq!(None, decode_modified_arrow(b"\x1B[1;5~"));
+        assert_eq!(None, decode_modified_arrow(b"\x1B[2;5A"));
+    }
 }
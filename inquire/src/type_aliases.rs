@@ -1,6 +1,8 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use std::cmp::Ordering;
+
+use crate::{error::CustomUserError, PasswordStrength};
 
 /// Type alias to represent the function used to Score and filter options.
 ///
@@ -46,8 +48,9 @@ use crate::error::CustomUserError;
 ///
 ///
 ///
-/// Default implementation for fuzzy search (almost)
-///```
+/// Default implementation for fuzzy search (almost), with the `fuzzy` feature:
+#[cfg_attr(feature = "fuzzy", doc = "```")]
+#[cfg_attr(not(feature = "fuzzy"), doc = "```ignore")]
 /// use inquire::type_aliases::Scorer;
 /// use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 ///
@@ -73,6 +76,70 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent the function used to filter options.
+///
+/// The function receives:
+/// - Current user input, filter value
+/// - Current option being evaluated, with type preserved
+/// - String value of the current option
+/// - Index of the current option in the original list
+///
+/// The return type should be whether the current option should be displayed to the user,
+/// `true` keeping it visible. Options that pass the filter keep their original order.
+///
+/// Since the option is received with its type preserved, the function may match on data
+/// that is not part of its string value.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Filter;
+///
+/// struct Language {
+///     name: &'static str,
+///     extension: &'static str,
+/// }
+///
+/// let filter: Filter<Language> = &|input, option, string_value, _idx| {
+///     let input = input.to_lowercase();
+///     string_value.to_lowercase().contains(&input) || option.extension == input
+/// };
+///
+/// let rust = Language { name: "Rust", extension: "rs" };
+/// assert!(filter("ru", &rust, rust.name, 0));
+/// assert!(filter("rs", &rust, rust.name, 0));
+/// assert!(!filter("py", &rust, rust.name, 0));
+/// ```
+pub type Filter<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> bool;
+
+/// Type alias to represent the function used to find which characters of an option
+/// match the filter input, so that they can be highlighted.
+///
+/// The function receives the same arguments as a [`Scorer`] and should return
+/// the indices of the matching characters (`char`s, not bytes) in the string value
+/// of the option. Characters belonging to the same grapheme are highlighted together.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Highlighter;
+///
+/// // Highlights the matching prefix of each option.
+/// let highlighter: Highlighter<str> = &|input, _option, string_value, _idx| {
+///     string_value
+///         .chars()
+///         .zip(input.chars())
+///         .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+///         .enumerate()
+///         .map(|(i, _)| i)
+///         .collect()
+/// };
+///
+/// assert_eq!(vec![0, 1], highlighter("sa", "San Diego", "San Diego", 0));
+/// assert_eq!(Vec::<usize>::new(), highlighter("sa", "Mesa", "Mesa", 1));
+/// ```
+pub type Highlighter<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Vec<usize>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -82,3 +149,112 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function used to filter characters typed into a text input.
+/// The function receives each character before it is inserted and returns whether it is accepted.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::CharFilter;
+///
+/// let digits_only: CharFilter = &|c| c.is_ascii_digit();
+///
+/// assert!(digits_only('4'));
+/// assert!(!digits_only('a'));
+/// ```
+pub type CharFilter<'a> = &'a dyn Fn(char) -> bool;
+
+/// Type alias to represent the function used to transform characters typed into a text input.
+/// The function receives each character before it is inserted and returns the character to
+/// insert in its place, or `None` to drop it.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::CharTransformer;
+///
+/// let lowercase: CharTransformer = &|c| c.to_lowercase().next();
+///
+/// assert_eq!(Some('a'), lowercase('A'));
+/// assert_eq!(Some('1'), lowercase('1'));
+/// ```
+pub type CharTransformer<'a> = &'a dyn Fn(char) -> Option<char>;
+
+/// Type alias to represent the function used to load the options of a select prompt
+/// on demand. The function receives the current filter input, the number of options
+/// already loaded for it, which is the offset of the requested batch, and the maximum
+/// number of options to return.
+///
+/// Returning less options than requested signals that there are no more options
+/// matching the filter.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionProvider;
+///
+/// let numbers: OptionProvider<u32> = &|filter, offset, limit| {
+///     (0..100_000)
+///         .filter(|n: &u32| n.to_string().starts_with(filter))
+///         .skip(offset)
+///         .take(limit)
+///         .collect()
+/// };
+///
+/// assert_eq!(vec![0, 1, 2], numbers("", 0, 3));
+/// assert_eq!(vec![42, 420, 421], numbers("42", 0, 3));
+/// assert_eq!(vec![422], numbers("42", 3, 1));
+/// ```
+pub type OptionProvider<'a, T> = &'a dyn Fn(&str, usize, usize) -> Vec<T>;
+
+/// Type alias to represent the function used to compute the preview of the
+/// highlighted option of a select prompt. The function receives the option
+/// and returns its details, which may span multiple lines.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Previewer;
+///
+/// let previewer: Previewer<(&str, u32)> =
+///     &|(name, port)| format!("name: {}\nport: {}", name, port);
+///
+/// assert_eq!("name: web\nport: 8080", previewer(&("web", 8080)));
+/// ```
+pub type Previewer<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to sort the options of a select
+/// prompt for display. The function receives two options and returns how they
+/// are ordered, without changing the indexes of the options.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use inquire::type_aliases::Sorter;
+///
+/// let sorter: Sorter<&str> = &|a, b| a.len().cmp(&b.len());
+///
+/// assert_eq!(Ordering::Less, sorter(&"fig", &"apple"));
+/// ```
+pub type Sorter<'a, T> = &'a dyn Fn(&T, &T) -> Ordering;
+
+/// Type alias to represent the function used to estimate the strength of a password input.
+/// The function receives the current input and returns its strength.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{type_aliases::PasswordStrengthFn, PasswordStrength};
+///
+/// let by_length: PasswordStrengthFn = &|input| match input.chars().count() {
+///     0..=7 => PasswordStrength::Weak,
+///     8..=15 => PasswordStrength::Fair,
+///     _ => PasswordStrength::Strong,
+/// };
+///
+/// assert_eq!(PasswordStrength::Weak, by_length("hunter2"));
+/// assert_eq!(PasswordStrength::Strong, by_length("correct horse battery staple"));
+/// ```
+pub type PasswordStrengthFn<'a> = &'a dyn Fn(&str) -> PasswordStrength;
@@ -1,23 +1,23 @@
 use crate::ansi::AnsiStrippable;
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{collections::BTreeSet, convert::TryFrom, fmt::Display, io::Result};
 
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{
-    error::InquireResult,
     input::Input,
     list_option::ListOption,
     terminal::{Terminal, TerminalSize},
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
+    ui::{IndexPrefix, Key, RenderConfig, StyleSheet, Styled},
     utils::{int_log10, Page},
     validator::ErrorMessage,
-    {Action, InnerAction},
+    PasswordStrength,
 };
 
-use super::InputReader;
-
 pub trait CommonBackend {
     fn read_key(&mut self) -> Result<Key>;
+    #[cfg(feature = "async")]
+    fn poll_key(&mut self, timeout: std::time::Duration) -> Result<Option<Key>>;
 
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self) -> Result<()>;
@@ -27,6 +27,12 @@ pub trait CommonBackend {
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+
+    fn enable_mouse_capture(&mut self) -> Result<()>;
+
+    /// Index of the option rendered in the current frame at the given position
+    /// of the terminal screen, if any.
+    fn option_at(&mut self, column: u16, row: u16) -> Result<Option<usize>>;
 }
 
 pub trait TextBackend: CommonBackend {
@@ -35,6 +41,8 @@ pub trait TextBackend: CommonBackend {
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        inline_suggestion: Option<&str>,
+        length_counter: bool,
     ) -> Result<()>;
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
 }
@@ -44,18 +52,62 @@ pub trait EditorBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()>;
 }
 
+/// Row of a list of options, which may be a header of a group of options.
+pub enum OptionRow<'a, D> {
+    Header(&'a str),
+    Option(ListOption<D>),
+}
+
+/// How many options of a group are selected, shown on the header of the group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GroupSelection {
+    None,
+    Partial,
+    All,
+}
+
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, OptionRow<'_, D>>,
+        matches: &[Vec<usize>],
+        aliases: &[Option<&str>],
+        disabled: &BTreeSet<usize>,
+    ) -> Result<()>;
+    fn render_option_grid<D: Display>(
+        &mut self,
+        page: Page<'_, Vec<ListOption<D>>>,
+        cursor_column: usize,
+        column_width: usize,
+        disabled: &BTreeSet<usize>,
+    ) -> Result<()>;
+    fn render_inline_options<D: Display>(
+        &mut self,
+        prompt: &str,
+        options: &[ListOption<D>],
+        cursor: usize,
+        disabled: &BTreeSet<usize>,
+    ) -> Result<()>;
+    fn render_option_description(&mut self, description: &str) -> Result<()>;
+    fn render_option_preview(&mut self, preview: &str, max_lines: usize) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: &Input,
+        selected_counter: Option<(usize, usize)>,
+    ) -> Result<()>;
     fn render_options<D: Display>(
         &mut self,
-        page: Page<'_, ListOption<D>>,
+        page: Page<'_, OptionRow<'_, D>>,
         checked: &BTreeSet<usize>,
+        disabled: &BTreeSet<usize>,
+        group_selections: &[Option<GroupSelection>],
     ) -> Result<()>;
+    fn render_page_indicator(&mut self, first: usize, last: usize, total: usize) -> Result<()>;
 }
 
 pub trait CustomTypeBackend: CommonBackend {
@@ -69,16 +121,54 @@ pub trait CustomTypeBackend: CommonBackend {
 
 pub trait PasswordBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str) -> Result<()>;
-    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_prompt_with_masked_input(
+        &mut self,
+        prompt: &str,
+        cur_input: &Input,
+        mask: Option<char>,
+        revealed_grapheme: Option<usize>,
+    ) -> Result<()>;
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_password_strength(&mut self, strength: PasswordStrength) -> Result<()>;
+    fn ring_bell(&mut self) -> Result<()>;
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Position {
     pub row: u16,
     pub col: u16,
 }
 
+impl Position {
+    /// Moves the position past a printed character, wrapping to the next row
+    /// when the character does not fit in the terminal width.
+    fn advance(&mut self, c: char, term_width: u16) {
+        let len = UnicodeWidthChar::width(c).unwrap_or(0) as u16;
+
+        if c == '\n' {
+            self.row = self.row.saturating_add(1);
+            self.col = 0;
+        } else {
+            let left = term_width - self.col;
+
+            if left >= len {
+                self.col = self.col.saturating_add(len);
+            } else {
+                self.row = self.row.saturating_add(1);
+                self.col = len;
+            }
+        }
+    }
+}
+
+/// Area of the frame where an option is rendered, from `start` up to, but not
+/// including, `end`.
+struct OptionArea {
+    start: Position,
+    end: Position,
+    index: usize,
+}
+
 pub struct Backend<'a, T>
 where
     T: Terminal,
@@ -88,6 +178,10 @@ where
     prompt_cursor_offset: Option<usize>,
     prompt_cursor_position: Option<Position>,
     show_cursor: bool,
+    option_areas: Vec<OptionArea>,
+    scanned_content_len: usize,
+    scanned_content_position: Position,
+    frame_top: Option<i32>,
     terminal: T,
     terminal_size: TerminalSize,
     render_config: RenderConfig<'a>,
@@ -110,6 +204,10 @@ where
             prompt_cursor_offset: None,
             prompt_cursor_position: None,
             show_cursor: false,
+            option_areas: vec![],
+            scanned_content_len: 0,
+            scanned_content_position: Position::default(),
+            frame_top: None,
             terminal,
             render_config,
             terminal_size,
@@ -127,24 +225,11 @@ where
         let mut cur_pos = Position::default();
 
         for (idx, c) in input.ansi_stripped_chars().enumerate() {
-            let len = UnicodeWidthChar::width(c).unwrap_or(0) as u16;
-
-            if c == '\n' {
-                cur_pos.row = cur_pos.row.saturating_add(1);
-                cur_pos.col = 0;
-            } else {
-                let left = term_width - cur_pos.col;
-
-                if left >= len {
-                    cur_pos.col = cur_pos.col.saturating_add(len);
-                } else {
-                    cur_pos.row = cur_pos.row.saturating_add(1);
-                    cur_pos.col = len;
-                }
-            }
+            cur_pos.advance(c, term_width);
 
             if let Some(prompt_cursor_offset) = self.prompt_cursor_offset {
                 if prompt_cursor_offset == idx {
+                    let len = UnicodeWidthChar::width(c).unwrap_or(0) as u16;
                     let mut cursor_position = cur_pos;
                     cursor_position.col = cursor_position.col.saturating_sub(len);
                     self.prompt_cursor_position = Some(cursor_position);
@@ -156,6 +241,28 @@ where
         self.prompt_end_position = cur_pos;
     }
 
+    /// Position of the frame where the next character will be printed.
+    ///
+    /// The content of the frame only grows until it is reset, so it is scanned
+    /// from where the previous call stopped.
+    fn content_position(&mut self) -> Position {
+        let content = self.terminal.get_in_memory_content();
+
+        for c in (&content[self.scanned_content_len..]).ansi_stripped_chars() {
+            self.scanned_content_position
+                .advance(c, self.terminal_size.width);
+        }
+        self.scanned_content_len = content.len();
+
+        self.scanned_content_position
+    }
+
+    /// Records that the option was rendered from `start` up to the current position.
+    fn mark_option_area(&mut self, start: Position, index: usize) {
+        let end = self.content_position();
+        self.option_areas.push(OptionArea { start, end, index });
+    }
+
     fn move_cursor_to_end_position(&mut self) -> Result<()> {
         if self.prompt_current_position.row != self.prompt_end_position.row {
             let diff = self
@@ -193,12 +300,50 @@ where
             self.terminal.clear_current_line()?;
         }
 
+        self.reset_prompt_state()
+    }
+
+    /// Clears the previous frame after the width of the terminal changed.
+    ///
+    /// The terminal wraps the lines of the previous frame again at the new
+    /// width, moving the cursor along with its content, so the rows it takes
+    /// are computed again from its content instead of from the positions
+    /// recorded when it was rendered.
+    fn reset_resized_prompt(&mut self) -> Result<()> {
+        let mut cursor_position = Position::default();
+
+        for (idx, c) in self
+            .terminal
+            .get_in_memory_content()
+            .ansi_stripped_chars()
+            .enumerate()
+        {
+            cursor_position.advance(c, self.terminal_size.width);
+
+            if self.prompt_cursor_offset == Some(idx) {
+                break;
+            }
+        }
+
+        if cursor_position.row > 0 {
+            self.terminal.cursor_up(cursor_position.row)?;
+        }
+        self.terminal.cursor_move_to_column(0)?;
+        self.terminal.clear_until_screen_end()?;
+
+        self.reset_prompt_state()
+    }
+
+    fn reset_prompt_state(&mut self) -> Result<()> {
         self.terminal.clear_in_memory_content();
 
         self.prompt_current_position = Position::default();
         self.prompt_end_position = Position::default();
         self.prompt_cursor_position = None;
         self.prompt_cursor_offset = None;
+        self.option_areas.clear();
+        self.scanned_content_len = 0;
+        self.scanned_content_position = Position::default();
 
         // let's default to false to catch any previous
         // default behaviors we didn't account for
@@ -208,10 +353,10 @@ where
         Ok(())
     }
 
-    fn print_option_prefix<D: Display>(
+    fn print_option_prefix<R>(
         &mut self,
         option_relative_index: usize,
-        page: &Page<'_, ListOption<D>>,
+        page: &Page<'_, R>,
     ) -> Result<()> {
         let empty_prefix = Styled::new(" ");
 
@@ -230,21 +375,71 @@ where
 
     fn print_option_value<D: Display>(
         &mut self,
-        option_relative_index: usize,
         option: &ListOption<D>,
-        page: &Page<'_, ListOption<D>>,
+        highlighted: bool,
+        matches: &[usize],
+        disabled: bool,
     ) -> Result<()> {
-        let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
-            match page.cursor {
-                Some(cursor) if cursor == option_relative_index => selected_option_style,
-                _ => self.render_config.option,
+        let stylesheet = if disabled {
+            self.render_config.disabled_option
+        } else if let Some(selected_option_style) = self.render_config.selected_option {
+            match highlighted {
+                true => selected_option_style,
+                false => self.render_config.option,
             }
         } else {
             self.render_config.option
         };
 
+        let filter_match = self.render_config.filter_match;
+        if matches.is_empty() || filter_match.is_empty() {
+            return self
+                .terminal
+                .write_styled(&Styled::new(&option.value).with_style_sheet(stylesheet));
+        }
+
+        let match_stylesheet = StyleSheet {
+            fg: filter_match.fg.or(stylesheet.fg),
+            bg: filter_match.bg.or(stylesheet.bg),
+            att: stylesheet.att | filter_match.att,
+        };
+
+        // matches are char indices, styled by whole graphemes so that
+        // multi-char sequences are never split.
+        let value = option.value.to_string();
+        let mut char_index = 0;
+        let mut segment_start = 0;
+        let mut segment_matched = false;
+
+        for (byte_index, grapheme) in value.grapheme_indices(true) {
+            let char_count = grapheme.chars().count();
+            let matched = (char_index..char_index + char_count).any(|i| matches.contains(&i));
+            char_index += char_count;
+
+            if matched != segment_matched {
+                if byte_index > segment_start {
+                    let style = if segment_matched {
+                        match_stylesheet
+                    } else {
+                        stylesheet
+                    };
+                    self.terminal.write_styled(
+                        &Styled::new(&value[segment_start..byte_index]).with_style_sheet(style),
+                    )?;
+                }
+
+                segment_start = byte_index;
+                segment_matched = matched;
+            }
+        }
+
+        let style = if segment_matched {
+            match_stylesheet
+        } else {
+            stylesheet
+        };
         self.terminal
-            .write_styled(&Styled::new(&option.value).with_style_sheet(stylesheet))
+            .write_styled(&Styled::new(&value[segment_start..]).with_style_sheet(style))
     }
 
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
@@ -291,25 +486,81 @@ where
         self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
     }
 
-    fn print_input(&mut self, input: &Input) -> Result<()> {
+    fn current_line_width(&self) -> usize {
+        let content = self.terminal.get_in_memory_content();
+        let current_line = content.rsplit('\n').next().unwrap_or_default();
+
+        current_line
+            .ansi_stripped_chars()
+            .map(|c| UnicodeWidthChar::width(c).unwrap_or(0))
+            .sum::<usize>()
+            % usize::from(self.terminal_size.width).max(1)
+    }
+
+    fn print_input(
+        &mut self,
+        input: &Input,
+        inline_suggestion: Option<&str>,
+        reserved_width: usize,
+    ) -> Result<()> {
         self.terminal.write(" ")?;
 
-        let cursor_offset = input.pre_cursor().chars().count();
+        // one column is left for the cursor when it is at the end of the input
+        let available_width = usize::from(self.terminal_size.width)
+            .saturating_sub(self.current_line_width())
+            .saturating_sub(reserved_width)
+            .saturating_sub(1);
+
+        // the inline suggestion is only rendered when it fits next to the whole input
+        let inline_suggestion = inline_suggestion.filter(|s| {
+            input.cursor() == input.length()
+                && !input.content().contains('\n')
+                && UnicodeWidthStr::width(input.content()) + UnicodeWidthStr::width(*s)
+                    <= available_width
+        });
+
+        let window = if input.content().contains('\n') {
+            input.window(usize::MAX)
+        } else {
+            input.window(available_width)
+        };
+
+        // terminals write new lines as "\r\n", taking one extra char each
+        let cursor_offset = usize::from(window.hidden_left)
+            + window.pre_cursor.chars().count()
+            + window.pre_cursor.matches('\n').count();
         self.mark_prompt_cursor_position(cursor_offset);
         self.show_cursor = true;
 
         if input.is_empty() {
             match input.placeholder() {
-                None => {}
-                Some(p) if p.is_empty() => {}
+                None | Some("") => {}
                 Some(p) => self.terminal.write_styled(
                     &Styled::new(p).with_style_sheet(self.render_config.placeholder),
                 )?,
             }
         } else {
+            if window.hidden_left {
+                self.terminal.write_styled(
+                    &Styled::new('<').with_style_sheet(self.render_config.placeholder),
+                )?;
+            }
+
             self.terminal.write_styled(
-                &Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
+                &Styled::new(window.content).with_style_sheet(self.render_config.text_input),
             )?;
+
+            if window.hidden_right {
+                self.terminal.write_styled(
+                    &Styled::new('>').with_style_sheet(self.render_config.placeholder),
+                )?;
+            }
+
+            if let Some(suggestion) = inline_suggestion {
+                self.terminal.write_styled(
+                    &Styled::new(suggestion).with_style_sheet(self.render_config.inline_suggestion),
+                )?;
+            }
         }
 
         // if cursor is at end of input, we need to add
@@ -322,11 +573,30 @@ where
         Ok(())
     }
 
+    fn length_counter(input: &Input) -> String {
+        match input.max_length() {
+            Some(max_length) => format!("{}/{}", input.length(), max_length),
+            None => input.length().to_string(),
+        }
+    }
+
+    /// Prints a counter to the right of the input, after print_input.
+    fn print_input_counter(&mut self, input: &Input, counter: Styled<String>) -> Result<()> {
+        // print_input already added a trailing space if the cursor is at the end
+        if input.cursor() != input.length() {
+            self.terminal.write(' ')?;
+        }
+
+        self.terminal.write_styled(&counter)
+    }
+
     fn print_prompt_with_input(
         &mut self,
         prompt: &str,
         default: Option<&str>,
         input: &Input,
+        inline_suggestion: Option<&str>,
+        counter: Option<Styled<String>>,
     ) -> Result<()> {
         self.print_prompt(prompt)?;
 
@@ -335,7 +605,16 @@ where
             self.print_default_value(default)?;
         }
 
-        self.print_input(input)?;
+        let reserved_width = counter
+            .as_ref()
+            .map(|c| UnicodeWidthStr::width(c.content.as_str()) + 1)
+            .unwrap_or(0);
+
+        self.print_input(input, inline_suggestion, reserved_width)?;
+
+        if let Some(counter) = counter {
+            self.print_input_counter(input, counter)?;
+        }
 
         self.new_line()?;
 
@@ -359,15 +638,32 @@ where
     T: Terminal,
 {
     fn frame_setup(&mut self) -> Result<()> {
+        // the terminal might have been resized since the last frame
+        let rendered_width = self.terminal_size.width;
+        if let Ok(terminal_size) = self.terminal.get_size() {
+            self.terminal_size = terminal_size;
+        }
+
         self.terminal.cursor_hide()?;
         self.terminal.flush()?;
 
-        self.reset_prompt()
+        match self.terminal_size.width == rendered_width {
+            true => self.reset_prompt(),
+            false => self.reset_resized_prompt(),
+        }
     }
 
     fn frame_finish(&mut self) -> Result<()> {
         self.update_position_info();
 
+        // the terminal scrolls up when the frame goes past its last row, which
+        // might leave the top of the frame above the screen.
+        if let Some(top) = self.frame_top {
+            let last_top =
+                i32::from(self.terminal_size.height) - 1 - i32::from(self.prompt_end_position.row);
+            self.frame_top = Some(top.min(last_top));
+        }
+
         if let Some(prompt_cursor_position) = self.prompt_cursor_position {
             let row_diff = self.prompt_current_position.row - prompt_cursor_position.row;
 
@@ -413,6 +709,48 @@ where
         self.terminal.read_key()
     }
 
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        self.terminal.enable_mouse_capture()?;
+
+        // the cursor is on a known row of the frame, which anchors the frame on
+        // the screen. terminals that don't track the cursor render the frame at
+        // the top of the screen, while clicks are ignored when the query fails,
+        // e.g. when the terminal does not answer it.
+        self.frame_top = match self.terminal.cursor_position() {
+            Ok(Some((_, cursor_row))) => {
+                Some(i32::from(cursor_row) - i32::from(self.prompt_current_position.row))
+            }
+            Ok(None) => Some(0),
+            Err(_) => None,
+        };
+
+        Ok(())
+    }
+
+    fn option_at(&mut self, column: u16, row: u16) -> Result<Option<usize>> {
+        let row = match self
+            .frame_top
+            .and_then(|top| u16::try_from(i32::from(row) - top).ok())
+        {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+        let clicked = Position { row, col: column };
+
+        let option = self
+            .option_areas
+            .iter()
+            .find(|area| area.start <= clicked && clicked < area.end)
+            .map(|area| area.index);
+
+        Ok(option)
+    }
+
+    #[cfg(feature = "async")]
+    fn poll_key(&mut self, timeout: std::time::Duration) -> Result<Option<Key>> {
+        self.terminal.poll_key(timeout)
+    }
+
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
         self.terminal
             .write_styled(&self.render_config.error_message.prefix)?;
@@ -460,8 +798,15 @@ where
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        inline_suggestion: Option<&str>,
+        length_counter: bool,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        let counter = length_counter.then(|| {
+            Styled::new(Self::length_counter(cur_input))
+                .with_style_sheet(self.render_config.length_counter)
+        });
+
+        self.print_prompt_with_input(prompt, default, cur_input, inline_suggestion, counter)
     }
 
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
@@ -469,7 +814,7 @@ where
             self.print_option_prefix(idx, &page)?;
 
             self.terminal.write(" ")?;
-            self.print_option_value(idx, option, &page)?;
+            self.print_option_value(option, page.cursor == Some(idx), &[], false)?;
 
             self.new_line()?;
         }
@@ -503,47 +848,241 @@ where
     T: Terminal,
 {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        self.print_prompt_with_input(prompt, None, cur_input)
+        self.print_prompt_with_input(prompt, None, cur_input, None, None)
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, OptionRow<'_, D>>,
+        matches: &[Vec<usize>],
+        aliases: &[Option<&str>],
+        disabled: &BTreeSet<usize>,
+    ) -> Result<()> {
+        for (idx, row) in page.content.iter().enumerate() {
+            let start = self.content_position();
+
             self.print_option_prefix(idx, &page)?;
 
             self.terminal.write(" ")?;
 
+            let option = match row {
+                OptionRow::Header(header) => {
+                    let header = Styled::new(*header)
+                        .with_style_sheet(self.render_config.option_group_header);
+                    self.terminal.write_styled(&header)?;
+                    self.new_line()?;
+                    continue;
+                }
+                OptionRow::Option(option) => option,
+            };
+
             if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
                 res?;
                 self.terminal.write(" ")?;
             }
 
-            self.print_option_value(idx, option, &page)?;
+            let option_matches = matches.get(idx).map(Vec::as_slice).unwrap_or_default();
+            let option_disabled = disabled.contains(&option.index);
+            self.print_option_value(
+                option,
+                page.cursor == Some(idx),
+                option_matches,
+                option_disabled,
+            )?;
+
+            if let Some(Some(alias)) = aliases.get(idx) {
+                self.terminal.write(" ")?;
+                self.terminal.write_styled(
+                    &Styled::new(format!("({alias})"))
+                        .with_style_sheet(self.render_config.option_alias),
+                )?;
+            }
+
+            self.new_line()?;
+            self.mark_option_area(start, option.index);
+        }
+
+        Ok(())
+    }
+
+    fn render_option_grid<D: Display>(
+        &mut self,
+        page: Page<'_, Vec<ListOption<D>>>,
+        cursor_column: usize,
+        column_width: usize,
+        disabled: &BTreeSet<usize>,
+    ) -> Result<()> {
+        for (idx, row) in page.content.iter().enumerate() {
+            let mut last_cell = None;
+
+            for (column, option) in row.iter().enumerate() {
+                if let Some((start, index)) = last_cell.take() {
+                    self.mark_option_area(start, index);
+                }
+                last_cell = Some((self.content_position(), option.index));
+
+                let highlighted = page.cursor == Some(idx) && column == cursor_column;
+
+                // scroll indicators are displayed in the first column only.
+                if highlighted || column == 0 {
+                    let prefix = if highlighted { page.cursor } else { None };
+                    self.print_option_prefix(
+                        idx,
+                        &Page {
+                            cursor: prefix,
+                            ..page
+                        },
+                    )?;
+                } else {
+                    self.terminal.write(" ")?;
+                }
+
+                self.terminal.write(" ")?;
+
+                if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                    res?;
+                    self.terminal.write(" ")?;
+                }
+
+                let option_disabled = disabled.contains(&option.index);
+                self.print_option_value(option, highlighted, &[], option_disabled)?;
+
+                if column + 1 < row.len() {
+                    let padding = column_width.saturating_sub(option.value.to_string().width());
+                    self.terminal.write(" ".repeat(padding + 2))?;
+                }
+            }
+
+            self.new_line()?;
+
+            if let Some((start, index)) = last_cell {
+                self.mark_option_area(start, index);
+            }
+        }
+
+        Ok(())
+    }
 
+    fn render_option_preview(&mut self, preview: &str, max_lines: usize) -> Result<()> {
+        // lines are truncated to fit in a single row each, keeping the height
+        // of the preview within the limit.
+        let available_width = usize::from(self.terminal_size.width).saturating_sub(3);
+
+        for line in preview.lines().take(max_lines) {
+            let mut width = 0;
+            let end = line
+                .grapheme_indices(true)
+                .find(|(_, grapheme)| {
+                    width += UnicodeWidthStr::width(*grapheme);
+                    width > available_width
+                })
+                .map(|(index, _)| index)
+                .unwrap_or(line.len());
+
+            self.terminal.write("  ")?;
+            self.terminal.write_styled(
+                &Styled::new(&line[..end]).with_style_sheet(self.render_config.option_preview),
+            )?;
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_inline_options<D: Display>(
+        &mut self,
+        prompt: &str,
+        options: &[ListOption<D>],
+        cursor: usize,
+        disabled: &BTreeSet<usize>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        for (idx, option) in options.iter().enumerate() {
+            self.terminal.write(if idx == 0 { " " } else { " / " })?;
+
+            let start = self.content_position();
+            self.print_option_value(option, idx == cursor, &[], disabled.contains(&option.index))?;
+            self.mark_option_area(start, option.index);
+        }
+
+        self.new_line()
+    }
+
+    fn render_option_description(&mut self, description: &str) -> Result<()> {
+        self.terminal.write("  ")?;
+
+        self.terminal.write_styled(
+            &Styled::new(description).with_style_sheet(self.render_config.option_description),
+        )?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
 }
 
 impl<'a, T> MultiSelectBackend for Backend<'a, T>
 where
     T: Terminal,
 {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        self.print_prompt_with_input(prompt, None, cur_input)
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: &Input,
+        selected_counter: Option<(usize, usize)>,
+    ) -> Result<()> {
+        let counter = selected_counter.map(|(selected, total)| {
+            Styled::new(format!("[{selected}/{total} selected]"))
+                .with_style_sheet(self.render_config.selected_counter)
+        });
+
+        self.print_prompt_with_input(prompt, None, cur_input, None, counter)
     }
 
     fn render_options<D: Display>(
         &mut self,
-        page: Page<'_, ListOption<D>>,
+        page: Page<'_, OptionRow<'_, D>>,
         checked: &BTreeSet<usize>,
+        disabled: &BTreeSet<usize>,
+        group_selections: &[Option<GroupSelection>],
     ) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
+        for (idx, row) in page.content.iter().enumerate() {
+            let start = self.content_position();
+
             self.print_option_prefix(idx, &page)?;
 
             self.terminal.write(" ")?;
 
+            let option = match row {
+                OptionRow::Header(header) => {
+                    let mut checkbox = match group_selections.get(idx) {
+                        Some(Some(GroupSelection::All)) => self.render_config.selected_checkbox,
+                        Some(Some(GroupSelection::Partial)) => {
+                            self.render_config.partially_selected_checkbox
+                        }
+                        _ => self.render_config.unselected_checkbox,
+                    };
+
+                    match (self.render_config.selected_option, page.cursor) {
+                        (Some(stylesheet), Some(cursor)) if cursor == idx => {
+                            checkbox.style = stylesheet;
+                        }
+                        _ => {}
+                    }
+
+                    self.terminal.write_styled(&checkbox)?;
+                    self.terminal.write(" ")?;
+
+                    let header = Styled::new(*header)
+                        .with_style_sheet(self.render_config.option_group_header);
+                    self.terminal.write_styled(&header)?;
+                    self.new_line()?;
+                    continue;
+                }
+                OptionRow::Option(option) => option,
+            };
+
             if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
                 res?;
                 self.terminal.write(" ")?;
@@ -563,13 +1102,29 @@ where
 
             self.terminal.write(" ")?;
 
-            self.print_option_value(idx, option, &page)?;
+            let option_disabled = disabled.contains(&option.index);
+            self.print_option_value(option, page.cursor == Some(idx), &[], option_disabled)?;
+
+            if option_disabled {
+                self.terminal.write(" ")?;
+                self.terminal
+                    .write_styled(&self.render_config.disabled_option_indicator)?;
+            }
 
             self.new_line()?;
+            self.mark_option_area(start, option.index);
         }
 
         Ok(())
     }
+
+    fn render_page_indicator(&mut self, first: usize, last: usize, total: usize) -> Result<()> {
+        let indicator = Styled::new(format!("items {first}–{last} of {total}"))
+            .with_style_sheet(self.render_config.page_indicator);
+
+        self.terminal.write_styled(&indicator)?;
+        self.new_line()
+    }
 }
 
 #[cfg(feature = "date")]
@@ -735,7 +1290,7 @@ where
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        self.print_prompt_with_input(prompt, default, cur_input, None, None)
     }
 }
 
@@ -749,18 +1304,52 @@ where
         Ok(())
     }
 
-    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        let masked_string: String = (0..cur_input.length())
-            .map(|_| self.render_config.password_mask)
+    fn render_prompt_with_masked_input(
+        &mut self,
+        prompt: &str,
+        cur_input: &Input,
+        mask: Option<char>,
+        revealed_grapheme: Option<usize>,
+    ) -> Result<()> {
+        let mask = mask.unwrap_or(self.render_config.password_mask);
+        let mut mask_buf = [0; 4];
+        let mask: &str = mask.encode_utf8(&mut mask_buf);
+
+        let masked_string: String = cur_input
+            .content()
+            .graphemes(true)
+            .enumerate()
+            .map(|(idx, grapheme)| match revealed_grapheme {
+                Some(revealed) if revealed == idx => grapheme,
+                _ => mask,
+            })
             .collect();
 
         let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
 
-        self.print_prompt_with_input(prompt, None, &masked_input)
+        self.print_prompt_with_input(prompt, None, &masked_input, None, None)
     }
 
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
-        self.print_prompt_with_input(prompt, None, cur_input)
+        self.print_prompt_with_input(prompt, None, cur_input, None, None)
+    }
+
+    fn render_password_strength(&mut self, strength: PasswordStrength) -> Result<()> {
+        let label = match strength {
+            PasswordStrength::Weak => self.render_config.password_strength.weak,
+            PasswordStrength::Fair => self.render_config.password_strength.fair,
+            PasswordStrength::Strong => self.render_config.password_strength.strong,
+        };
+
+        self.terminal.write("Strength: ")?;
+        self.terminal.write_styled(&label)?;
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn ring_bell(&mut self) -> Result<()> {
+        self.terminal.write('\u{7}')
     }
 }
 
@@ -773,18 +1362,3 @@ where
         let _unused = self.terminal.cursor_show();
     }
 }
-
-impl<'a, I, T> InputReader<I> for Backend<'a, T>
-where
-    T: Terminal,
-    I: Copy + Clone + PartialEq + Eq,
-{
-    fn next_action<C>(&mut self, config: &C) -> InquireResult<Option<Action<I>>>
-    where
-        I: InnerAction<Config = C>,
-    {
-        let key = self.read_key()?;
-        let action = Action::from_key(key, config);
-        Ok(action)
-    }
-}
@@ -18,6 +18,7 @@ pub enum Key {
     Enter,
     Backspace,
     Tab,
+    BackTab,
     Delete(KeyModifiers),
     Home,
     End,
@@ -28,6 +29,16 @@ pub enum Key {
     Left(KeyModifiers),
     Right(KeyModifiers),
     Char(char, KeyModifiers),
+    /// Left mouse button pressed at the given position of the terminal screen,
+    /// only reported when mouse capture is enabled.
+    Click {
+        column: u16,
+        row: u16,
+    },
+    ScrollUp,
+    ScrollDown,
+    /// The terminal was resized, so the prompt must be rendered again.
+    Resize,
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
@@ -2,13 +2,11 @@
 
 mod backend;
 mod color;
-mod input_reader;
 mod key;
 mod render_config;
 mod style;
 
 pub(crate) use backend::*;
-pub(crate) use input_reader::*;
 pub(crate) use key::*;
 
 pub use color::Color;
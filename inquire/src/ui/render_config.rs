@@ -1,6 +1,6 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -45,11 +45,35 @@ pub struct RenderConfig<'a> {
 
     /// Render configuration of placeholders.
     ///
-    /// Note: placeholders are displayed wrapped in parenthesis, e.g. (yes).
-    /// Non-styled space characters is added before the default value display
-    /// and after the default value, as separators.
+    /// Note: placeholders are displayed in the place of the text input while it
+    /// is empty, and are never returned as the answer of the prompt.
     pub placeholder: StyleSheet,
 
+    /// Render configuration of the length counter displayed to the right of
+    /// text inputs, when enabled.
+    ///
+    /// Note: the counter is displayed as the current input length, followed by
+    /// the maximum length when there is one, e.g. 12/80.
+    pub length_counter: StyleSheet,
+
+    /// Render configuration of the counter of selected options displayed to the
+    /// right of the filter input of multi-selection prompts, when enabled.
+    ///
+    /// Note: the counter is displayed as the amount of selected options, followed
+    /// by the maximum selection or the amount of options, e.g. [3/10 selected].
+    pub selected_counter: StyleSheet,
+
+    /// Render configuration of the page indicator displayed below the options
+    /// of multi-selection prompts, when they do not fit in a single page.
+    ///
+    /// Note: the indicator is displayed as the range of displayed options,
+    /// followed by the amount of options, e.g. items 8–14 of 33.
+    pub page_indicator: StyleSheet,
+
+    /// Render configuration of the inline suggestion displayed after the
+    /// cursor of text inputs, when enabled.
+    pub inline_suggestion: StyleSheet,
+
     /// Render configuration of help messages.
     ///
     /// Note: help messages are displayed wrapped in brackets, e.g. [Be careful!].
@@ -62,6 +86,9 @@ pub struct RenderConfig<'a> {
     /// [`text_input`](crate::ui::RenderConfig::text_input) configuration.
     pub password_mask: char,
 
+    /// Render configuration for the strength meter of password prompts.
+    pub password_strength: PasswordStrengthRenderConfig<'a>,
+
     /// Style sheet for text inputs.
     ///
     /// Note: a non-styled space character is added before the text input as
@@ -93,6 +120,11 @@ pub struct RenderConfig<'a> {
     /// Prefix for the option listed at the top of the page, when it is possible
     /// to scroll up.
     ///
+    /// Scroll indicators replace the prefix of the first and last options of the
+    /// page instead of taking rows of their own, so the page size is always the
+    /// number of options displayed. They are not rendered when all options fit
+    /// in a single page.
+    ///
     /// Note: a space character will be added to separate the prefix
     /// and the option value or the checkbox.
     pub scroll_up_prefix: Styled<&'a str>,
@@ -100,6 +132,9 @@ pub struct RenderConfig<'a> {
     /// Prefix for the option listed at the bottom of the page, when it is possible
     /// to scroll down.
     ///
+    /// See [`scroll_up_prefix`](Self::scroll_up_prefix) for how scroll indicators
+    /// are displayed.
+    ///
     /// Note: a space character will be added to separate the prefix
     /// and the option value or the checkbox.
     pub scroll_down_prefix: Styled<&'a str>,
@@ -118,6 +153,14 @@ pub struct RenderConfig<'a> {
     /// option value to the right.
     pub unselected_checkbox: Styled<&'a str>,
 
+    /// Checkbox of a group header in multi-select options, when only some
+    /// options of the group are selected.
+    ///
+    /// Note: a space character will be added to separate the checkbox
+    /// from a possible prefix, and to separate the checkbox from the
+    /// group header to the right.
+    pub partially_selected_checkbox: Styled<&'a str>,
+
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
@@ -134,6 +177,43 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet for options that can not be selected.
+    ///
+    /// Note: a non-styled space character is added before the option value as
+    /// a separator from the prefix.
+    pub disabled_option: StyleSheet,
+
+    /// Indicator displayed after the disabled options of multi-select prompts,
+    /// whose selection can not be changed.
+    ///
+    /// Note: a space character will be added to separate the indicator from
+    /// the option value.
+    pub disabled_option_indicator: Styled<&'a str>,
+
+    /// Style sheet for the headers of groups of options.
+    ///
+    /// Note: a non-styled space character is added before the header as
+    /// a separator from the prefix.
+    pub option_group_header: StyleSheet,
+
+    /// Style sheet for the description of the highlighted option, displayed
+    /// below the list of options.
+    pub option_description: StyleSheet,
+
+    /// Style sheet for the lines of the preview of the highlighted option,
+    /// displayed below the list of options.
+    pub option_preview: StyleSheet,
+
+    /// Style sheet for the alias displayed next to options that matched the
+    /// filter input through one of their aliases.
+    pub option_alias: StyleSheet,
+
+    /// Style sheet for the characters of options that match the filter input.
+    ///
+    /// It is applied on top of the option style: its colors replace the option
+    /// ones when set, and its attributes are added to the option ones.
+    pub filter_match: StyleSheet,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -157,20 +237,33 @@ impl<'a> RenderConfig<'a> {
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
+            length_counter: StyleSheet::empty(),
+            selected_counter: StyleSheet::empty(),
+            page_indicator: StyleSheet::empty(),
+            inline_suggestion: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
+            password_strength: PasswordStrengthRenderConfig::empty(),
             highlighted_option_prefix: Styled::new(">"),
             scroll_up_prefix: Styled::new("^"),
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
+            partially_selected_checkbox: Styled::new("[~]"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            disabled_option: StyleSheet::empty(),
+            disabled_option_indicator: Styled::new("🔒"),
+            option_group_header: StyleSheet::empty(),
+            option_description: StyleSheet::empty(),
+            option_preview: StyleSheet::empty(),
+            option_alias: StyleSheet::empty(),
+            filter_match: StyleSheet::empty(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -186,12 +279,17 @@ impl<'a> RenderConfig<'a> {
             prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
             answered_prompt_prefix: Styled::new(">").with_fg(Color::LightGreen),
             prompt: StyleSheet::empty(),
-            default_value: StyleSheet::empty(),
+            default_value: StyleSheet::new().with_fg(Color::DarkGrey),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
+            length_counter: StyleSheet::new().with_fg(Color::DarkGrey),
+            selected_counter: StyleSheet::new().with_fg(Color::DarkGrey),
+            page_indicator: StyleSheet::new().with_fg(Color::DarkGrey),
+            inline_suggestion: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::default_colored(),
             password_mask: '*',
+            password_strength: PasswordStrengthRenderConfig::default_colored(),
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
             highlighted_option_prefix: Styled::new(">").with_fg(Color::LightCyan),
@@ -199,9 +297,21 @@ impl<'a> RenderConfig<'a> {
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
             unselected_checkbox: Styled::new("[ ]"),
+            partially_selected_checkbox: Styled::new("[~]").with_fg(Color::LightGreen),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            disabled_option: StyleSheet::new().with_fg(Color::DarkGrey),
+            disabled_option_indicator: Styled::new("🔒").with_fg(Color::DarkGrey),
+            option_group_header: StyleSheet::new()
+                .with_fg(Color::LightMagenta)
+                .with_attr(Attributes::BOLD),
+            option_description: StyleSheet::new()
+                .with_fg(Color::DarkGrey)
+                .with_attr(Attributes::ITALIC),
+            option_preview: StyleSheet::new().with_fg(Color::Grey),
+            option_alias: StyleSheet::new().with_fg(Color::DarkGrey),
+            filter_match: StyleSheet::new().with_attr(Attributes::BOLD),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -229,12 +339,51 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for placeholders.
+    pub fn with_placeholder(mut self, placeholder: StyleSheet) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Sets the style sheet for the length counter of text inputs.
+    pub fn with_length_counter(mut self, length_counter: StyleSheet) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
+    /// Sets the style sheet for the counter of selected options of multi-selection prompts.
+    pub fn with_selected_counter(mut self, selected_counter: StyleSheet) -> Self {
+        self.selected_counter = selected_counter;
+        self
+    }
+
+    /// Sets the style sheet for the page indicator of multi-selection prompts.
+    pub fn with_page_indicator(mut self, page_indicator: StyleSheet) -> Self {
+        self.page_indicator = page_indicator;
+        self
+    }
+
+    /// Sets the style sheet for inline suggestions of text inputs.
+    pub fn with_inline_suggestion(mut self, inline_suggestion: StyleSheet) -> Self {
+        self.inline_suggestion = inline_suggestion;
+        self
+    }
+
     /// Sets the style sheet for help messages.
     pub fn with_help_message(mut self, help_message: StyleSheet) -> Self {
         self.help_message = help_message;
         self
     }
 
+    /// Sets the render configuration for the strength meter of password prompts.
+    pub fn with_password_strength(
+        mut self,
+        password_strength: PasswordStrengthRenderConfig<'a>,
+    ) -> Self {
+        self.password_strength = password_strength;
+        self
+    }
+
     /// Sets the style sheet for answers.
     pub fn with_answer(mut self, answer: StyleSheet) -> Self {
         self.answer = answer;
@@ -280,6 +429,15 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the styled component for checkboxes of partially selected groups.
+    pub fn with_partially_selected_checkbox(
+        mut self,
+        partially_selected_checkbox: Styled<&'a str>,
+    ) -> Self {
+        self.partially_selected_checkbox = partially_selected_checkbox;
+        self
+    }
+
     /// Sets the index prefix for option lists.
     pub fn with_option_index_prefix(mut self, index_prefix: IndexPrefix) -> Self {
         self.option_index_prefix = index_prefix;
@@ -298,6 +456,49 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for options that can not be selected.
+    pub fn with_disabled_option(mut self, disabled_option: StyleSheet) -> Self {
+        self.disabled_option = disabled_option;
+        self
+    }
+
+    /// Sets the indicator displayed after the disabled options of multi-select prompts.
+    pub fn with_disabled_option_indicator(mut self, indicator: Styled<&'a str>) -> Self {
+        self.disabled_option_indicator = indicator;
+        self
+    }
+
+    /// Sets the style sheet for the headers of groups of options.
+    pub fn with_option_group_header(mut self, option_group_header: StyleSheet) -> Self {
+        self.option_group_header = option_group_header;
+        self
+    }
+
+    /// Sets the style sheet for the description of the highlighted option.
+    pub fn with_option_description(mut self, option_description: StyleSheet) -> Self {
+        self.option_description = option_description;
+        self
+    }
+
+    /// Sets the style sheet for the lines of the preview of the highlighted option.
+    pub fn with_option_preview(mut self, option_preview: StyleSheet) -> Self {
+        self.option_preview = option_preview;
+        self
+    }
+
+    /// Sets the style sheet for the alias displayed next to options that matched
+    /// the filter input through it.
+    pub fn with_option_alias(mut self, option_alias: StyleSheet) -> Self {
+        self.option_alias = option_alias;
+        self
+    }
+
+    /// Sets the style sheet for the characters of options that match the filter input.
+    pub fn with_filter_match(mut self, filter_match: StyleSheet) -> Self {
+        self.filter_match = filter_match;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -429,6 +630,59 @@ impl<'a> ErrorMessageRenderConfig<'a> {
     }
 }
 
+/// Render configuration for the strength meter of password prompts.
+///
+/// The meter is rendered as the label of the current strength.
+#[derive(Copy, Clone, Debug)]
+pub struct PasswordStrengthRenderConfig<'a> {
+    /// Label of weak passwords.
+    pub weak: Styled<&'a str>,
+
+    /// Label of fair passwords.
+    pub fair: Styled<&'a str>,
+
+    /// Label of strong passwords.
+    pub strong: Styled<&'a str>,
+}
+
+impl<'a> PasswordStrengthRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            weak: Styled::new("Weak"),
+            fair: Styled::new("Fair"),
+            strong: Styled::new("Strong"),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            weak: Styled::new("Weak").with_fg(Color::LightRed),
+            fair: Styled::new("Fair").with_fg(Color::LightYellow),
+            strong: Styled::new("Strong").with_fg(Color::LightGreen),
+        }
+    }
+
+    /// Sets the label of weak passwords.
+    pub fn with_weak(mut self, weak: Styled<&'a str>) -> Self {
+        self.weak = weak;
+        self
+    }
+
+    /// Sets the label of fair passwords.
+    pub fn with_fair(mut self, fair: Styled<&'a str>) -> Self {
+        self.fair = fair;
+        self
+    }
+
+    /// Sets the label of strong passwords.
+    pub fn with_strong(mut self, strong: Styled<&'a str>) -> Self {
+        self.strong = strong;
+        self
+    }
+}
+
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
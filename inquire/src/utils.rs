@@ -2,6 +2,15 @@
 
 use std::fmt::Debug;
 
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
 pub struct Page<'a, T> {
     /// Whether this is the first page.
     pub first: bool,
@@ -12,6 +21,9 @@ pub struct Page<'a, T> {
     /// Content of the page.
     pub content: &'a [T],
 
+    /// Index of the first element of the page in the original list of choices.
+    pub start: usize,
+
     /// If a cursor exists on the original list, this is the index of the new cursor relative to the output list of choices, the page.
     pub cursor: Option<usize>,
 
@@ -63,6 +75,7 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
         first: start == 0,
         last: end == choices.len(),
         content: &choices[start..end],
+        start,
         cursor,
         total: choices.len(),
     }
@@ -84,6 +97,56 @@ where
     len
 }
 
+impl<'a, T> Debug for Page<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Page")
+            .field("first", &self.first)
+            .field("last", &self.last)
+            .field("content", &format!("({} elements)", &self.content.len()))
+            .field("start", &self.start)
+            .field("cursor", &self.cursor)
+            .field("total", &self.total)
+            .finish()
+    }
+}
+
+#[cfg(feature = "async")]
+struct ThreadWaker(Thread);
+
+#[cfg(feature = "async")]
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives the future to completion on the current thread, calling `on_pending`
+/// every time it is not ready yet. It is up to `on_pending` to wait for a while,
+/// e.g. by parking the thread, which is unparked as soon as the future is woken.
+///
+/// This lets prompts await futures from their synchronous loop regardless of the
+/// async runtime the future was created by, as long as the runtime drives its IO
+/// and timers in other threads.
+#[cfg(feature = "async")]
+pub fn block_on<F, E>(
+    mut future: Pin<Box<F>>,
+    mut on_pending: impl FnMut() -> Result<(), E>,
+) -> Result<F::Output, E>
+where
+    F: Future + ?Sized,
+{
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Ok(output);
+        }
+
+        on_pending()?;
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::bool_assert_comparison)]
@@ -154,6 +217,7 @@ mod test {
         let page = paginate(page_size, &choices, sel);
 
         assert_eq!(choices[0..4], page.content[..]);
+        assert_eq!(0, page.start);
         assert_eq!(Some(2), page.cursor);
         assert_eq!(true, page.first);
         assert_eq!(false, page.last);
@@ -186,6 +250,7 @@ mod test {
         let page = paginate(page_size, &choices, sel);
 
         assert_eq!(choices[2..4], page.content[..]);
+        assert_eq!(2, page.start);
         assert_eq!(Some(1), page.cursor);
         assert_eq!(false, page.first);
         assert_eq!(false, page.last);
@@ -218,6 +283,7 @@ mod test {
         let page = paginate(page_size, &choices, sel);
 
         assert_eq!(choices[3..6], page.content[..]);
+        assert_eq!(3, page.start);
         assert_eq!(Some(2), page.cursor);
         assert_eq!(false, page.first);
         assert_eq!(true, page.last);
@@ -240,15 +306,3 @@ mod test {
         assert_eq!(6, page.total);
     }
 }
-
-impl<'a, T> Debug for Page<'a, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Page")
-            .field("first", &self.first)
-            .field("last", &self.last)
-            .field("content", &format!("({} elements)", &self.content.len()))
-            .field("cursor", &self.cursor)
-            .field("total", &self.total)
-            .finish()
-    }
-}
@@ -14,6 +14,9 @@
 
 use dyn_clone::DynClone;
 
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+
 use crate::{error::CustomUserError, list_option::ListOption};
 
 /// Error message that is displayed to the users when their input is considered not
@@ -109,6 +112,72 @@ where
     }
 }
 
+impl StringValidator for Box<dyn StringValidator> {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        (**self).validate(input)
+    }
+}
+
+/// Future returned by [`AsyncStringValidator`]s.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<Validation, CustomUserError>>>>;
+
+/// Validator that receives a string slice as the input and returns a future, such as
+/// one that checks the input against a remote service. Supported by
+/// [`Password`](crate::Password) prompts.
+///
+/// The prompt awaits the future on its own thread, so the future must not rely on
+/// the runtime context of that thread, unless the `tokio` feature is enabled. In that
+/// case, the prompt enters the `tokio` runtime it is called from, e.g. from
+/// `spawn_blocking`, which must be multi-threaded to keep driving IO and timers while
+/// the prompt blocks. Futures of runtimes with a global reactor, such as `async-std`,
+/// work either way. Since the future can't borrow the input, it must copy whatever it
+/// needs from it.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{AsyncStringValidator, Validation};
+///
+/// let validator = |input: &str| {
+///     let input = input.to_owned();
+///     async move {
+///         // e.g. a request to a remote service
+///         match input.as_str() {
+///             "hunter2" => Ok(Validation::Invalid("This password has been leaked".into())),
+///             _ => Ok(Validation::Valid),
+///         }
+///     }
+/// };
+///
+/// let _future = validator.validate("hunter2");
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait AsyncStringValidator: DynClone {
+    /// Returns a future that resolves to whether the given input string is valid.
+    fn validate(&self, input: &str) -> ValidationFuture;
+}
+
+#[cfg(feature = "async")]
+impl Clone for Box<dyn AsyncStringValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<F, Fut> AsyncStringValidator for F
+where
+    F: Fn(&str) -> Fut + Clone,
+    Fut: Future<Output = Result<Validation, CustomUserError>> + 'static,
+{
+    fn validate(&self, input: &str) -> ValidationFuture {
+        Box::pin((self)(input))
+    }
+}
+
 /// Validator used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
@@ -702,6 +771,245 @@ macro_rules! length {
     };
 }
 
+/// Built-in validator that enforces a password policy: a minimum length, a minimum
+/// number of character classes and, optionally, not being one of the most common
+/// passwords.
+///
+/// The character classes are lowercase letters, uppercase letters, digits and
+/// symbols, the latter being any other character. The common passwords are checked
+/// against a built-in list of the 100 most common ones, ignoring case.
+///
+/// By default, passwords are required to have at least 8 characters and to not be
+/// common, with no character classes required. The error message names the first
+/// requirement the input fails to meet.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{PasswordPolicyValidator, StringValidator, Validation};
+///
+/// let validator = PasswordPolicyValidator::new().min_length(12).require_classes(3);
+/// assert_eq!(Validation::Valid, validator.validate("Correct-horse-battery")?);
+/// assert_eq!(
+///     Validation::Invalid("The password should have at least 12 characters".into()),
+///     validator.validate("Hunter2!")?,
+/// );
+/// assert_eq!(
+///     Validation::Invalid(
+///         "The password should contain at least 3 of: lowercase letters, uppercase letters, digits and symbols".into()
+///     ),
+///     validator.validate("correct horse battery")?,
+/// );
+///
+/// let validator = PasswordPolicyValidator::new();
+/// assert_eq!(
+///     Validation::Invalid("The password is too common".into()),
+///     validator.validate("Trustno1")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+///
+/// Like any other validator, it is attached to a prompt with `with_validator`:
+///
+/// ```no_run
+/// use inquire::{validator::PasswordPolicyValidator, Password};
+///
+/// let password = Password::new("New password:")
+///     .with_validator(PasswordPolicyValidator::new().min_length(12).require_classes(3))
+///     .prompt();
+/// ```
+#[derive(Clone)]
+pub struct PasswordPolicyValidator {
+    min_length: usize,
+    required_classes: usize,
+    deny_common_passwords: bool,
+}
+
+impl PasswordPolicyValidator {
+    /// Minimum length required by default.
+    pub const DEFAULT_MIN_LENGTH: usize = 8;
+
+    /// Create a new instance of this validator with the default policy.
+    pub fn new() -> Self {
+        Self {
+            min_length: Self::DEFAULT_MIN_LENGTH,
+            required_classes: 0,
+            deny_common_passwords: true,
+        }
+    }
+
+    /// Sets the minimum number of characters of the password.
+    pub fn min_length(mut self, length: usize) -> Self {
+        self.min_length = length;
+        self
+    }
+
+    /// Sets how many of the four character classes the password should contain:
+    /// lowercase letters, uppercase letters, digits and symbols.
+    pub fn require_classes(mut self, classes: usize) -> Self {
+        self.required_classes = classes;
+        self
+    }
+
+    /// Sets whether passwords from the built-in list of the 100 most common ones
+    /// are rejected. Defaults to `true`.
+    pub fn deny_common_passwords(mut self, deny: bool) -> Self {
+        self.deny_common_passwords = deny;
+        self
+    }
+
+    fn is_common(input: &str) -> bool {
+        let input = input.to_lowercase();
+        COMMON_PASSWORDS.contains(&input.as_str())
+    }
+}
+
+impl Default for PasswordPolicyValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StringValidator for PasswordPolicyValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        let message = if input.inquire_length() < self.min_length {
+            format!(
+                "The password should have at least {} characters",
+                self.min_length
+            )
+        } else if character_classes(input) < self.required_classes {
+            format!(
+                "The password should contain at least {} of: lowercase letters, uppercase letters, digits and symbols",
+                self.required_classes
+            )
+        } else if self.deny_common_passwords && Self::is_common(input) {
+            String::from("The password is too common")
+        } else {
+            return Ok(Validation::Valid);
+        };
+
+        Ok(Validation::Invalid(message.into()))
+    }
+}
+
+/// Counts how many character classes are present in the input: lowercase letters,
+/// uppercase letters, digits and others.
+pub(crate) fn character_classes(input: &str) -> usize {
+    [
+        input.chars().any(char::is_lowercase),
+        input.chars().any(char::is_uppercase),
+        input.chars().any(|c| c.is_ascii_digit()),
+        input.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .iter()
+    .filter(|present| **present)
+    .count()
+}
+
+/// The 100 most common passwords, in lowercase.
+const COMMON_PASSWORDS: [&str; 100] = [
+    "123456",
+    "password",
+    "12345678",
+    "qwerty",
+    "123456789",
+    "12345",
+    "1234",
+    "111111",
+    "1234567",
+    "dragon",
+    "123123",
+    "baseball",
+    "abc123",
+    "football",
+    "monkey",
+    "letmein",
+    "696969",
+    "shadow",
+    "master",
+    "666666",
+    "qwertyuiop",
+    "123321",
+    "mustang",
+    "1234567890",
+    "michael",
+    "654321",
+    "pussy",
+    "superman",
+    "1qaz2wsx",
+    "7777777",
+    "fuckyou",
+    "121212",
+    "000000",
+    "qazwsx",
+    "123qwe",
+    "killer",
+    "trustno1",
+    "jordan",
+    "jennifer",
+    "zxcvbnm",
+    "asdfgh",
+    "hunter",
+    "buster",
+    "soccer",
+    "harley",
+    "batman",
+    "andrew",
+    "tigger",
+    "sunshine",
+    "iloveyou",
+    "fuckme",
+    "2000",
+    "charlie",
+    "robert",
+    "thomas",
+    "hockey",
+    "ranger",
+    "daniel",
+    "starwars",
+    "klaster",
+    "112233",
+    "george",
+    "asshole",
+    "computer",
+    "michelle",
+    "jessica",
+    "pepper",
+    "1111",
+    "zxcvbn",
+    "555555",
+    "11111111",
+    "131313",
+    "freedom",
+    "777777",
+    "pass",
+    "fuck",
+    "maggie",
+    "159753",
+    "aaaaaa",
+    "ginger",
+    "princess",
+    "joshua",
+    "cheese",
+    "amanda",
+    "summer",
+    "love",
+    "ashley",
+    "6969",
+    "nicole",
+    "chelsea",
+    "biteme",
+    "matthew",
+    "access",
+    "yankees",
+    "987654321",
+    "dallas",
+    "austin",
+    "thunder",
+    "taylor",
+    "matrix",
+];
+
 #[cfg(test)]
 mod validators_test {
     use crate::{
@@ -709,7 +1017,7 @@ mod validators_test {
         list_option::ListOption,
         validator::{
             ExactLengthValidator, MaxLengthValidator, MinLengthValidator, MultiOptionValidator,
-            StringValidator, Validation,
+            PasswordPolicyValidator, StringValidator, Validation,
         },
     };
 
@@ -921,4 +1229,45 @@ mod validators_test {
 
         Ok(())
     }
+
+    #[test]
+    fn password_policy_reports_first_failed_requirement() -> Result<(), CustomUserError> {
+        let validator = PasswordPolicyValidator::new()
+            .min_length(10)
+            .require_classes(4);
+
+        assert_eq!(
+            Validation::Invalid("The password should have at least 10 characters".into()),
+            validator.validate("Sh0rt!")?
+        );
+        assert_eq!(
+            Validation::Invalid(
+                "The password should contain at least 4 of: lowercase letters, uppercase letters, digits and symbols".into()
+            ),
+            validator.validate("longer-password1")?
+        );
+        assert_eq!(Validation::Valid, validator.validate("Longer-Password1")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn password_policy_denies_common_passwords_ignoring_case() -> Result<(), CustomUserError> {
+        let validator = PasswordPolicyValidator::new().min_length(0);
+
+        assert!(matches!(
+            validator.validate("PASSWORD")?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate("1234")?,
+            Validation::Invalid(_)
+        ));
+        assert_eq!(Validation::Valid, validator.validate("password1234")?);
+
+        let validator = validator.deny_common_passwords(false);
+        assert_eq!(Validation::Valid, validator.validate("PASSWORD")?);
+
+        Ok(())
+    }
 }